@@ -0,0 +1,82 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::TypeFoldable;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, match_type, paths, span_lint_and_then};
+
+/// **What it does:** Checks for `Arc::new` calls whose value is not `Send` and `Sync`.
+///
+/// **Why is this bad?** `Arc<T>` is only `Send` and `Sync` if `T` is both `Send` and `Sync`,
+/// so such an `Arc` can't be shared between threads. Either the cheaper `Rc` does the job, or
+/// the value needs to be made thread safe, e.g. by wrapping it in a `Mutex`.
+///
+/// **Known problems:** Values whose type depends on generic parameters are not linted.
+///
+/// **Example:**
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::sync::Arc;
+/// let x = Arc::new(RefCell::new(42));
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// let x = Rc::new(RefCell::new(42));
+/// ```
+declare_clippy_lint! {
+    pub ARC_WITH_NON_SEND_SYNC,
+    complexity,
+    "using `Arc` with a type that does not implement `Send` and `Sync`"
+}
+
+pub struct ArcWithNonSendSync;
+
+impl LintPass for ArcWithNonSendSync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ARC_WITH_NON_SEND_SYNC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ArcWithNonSendSync {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if args.len() == 1;
+            if let ExprKind::Path(QPath::TypeRelative(_, ref method)) = func.node;
+            if method.ident.name == "new";
+            if match_type(cx, cx.tables.expr_ty(expr), &paths::ARC);
+            let ty = cx.tables.expr_ty(&args[0]);
+            if !ty.has_param_types();
+            if let Some(send_trait) = get_trait_def_id(cx, &paths::SEND);
+            if let Some(sync_trait) = get_trait_def_id(cx, &paths::SYNC);
+            let is_send = implements_trait(cx, ty, send_trait, &[]);
+            let is_sync = implements_trait(cx, ty, sync_trait, &[]);
+            if !is_send || !is_sync;
+            then {
+                let missing = match (is_send, is_sync) {
+                    (false, false) => "`Send` or `Sync`",
+                    (false, true) => "`Send`",
+                    _ => "`Sync`",
+                };
+                span_lint_and_then(
+                    cx,
+                    ARC_WITH_NON_SEND_SYNC,
+                    expr.span,
+                    "usage of an `Arc` that is not `Send` or `Sync`",
+                    |db| {
+                        db.note(&format!(
+                            "the `Arc` is not `Send` and `Sync` as `{}` is not {}",
+                            ty, missing
+                        ));
+                        db.help("consider using an `Rc` instead, or wrapping the value in a `Mutex` or `RwLock`");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use crate::utils::span_lint;
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{clip, sext, span_lint, unsext};
 use rustc::hir;
 use rustc::lint::*;
+use rustc::ty;
 use rustc::{declare_lint, lint_array};
+use syntax::ast;
 use syntax::codemap::Span;
 
 /// **What it does:** Checks for plain integer arithmetic.
@@ -10,7 +13,8 @@ use syntax::codemap::Span;
 /// In some applications one wants explicitly checked, wrapping or saturating
 /// arithmetic.
 ///
-/// **Known problems:** None.
+/// **Known problems:** None. Arithmetic that is evaluated at compile time
+/// without overflowing, like `1 + 2`, is not linted.
 ///
 /// **Example:**
 /// ```rust
@@ -56,7 +60,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Arithmetic {
             return;
         }
         match expr.node {
-            hir::ExprKind::Binary(ref op, ref l, ref r) => {
+            hir::ExprKind::Binary(ref op, ref l, ref r) | hir::ExprKind::AssignOp(ref op, ref l, ref r) => {
                 match op.node {
                     hir::BinOpKind::And
                     | hir::BinOpKind::Or
@@ -75,6 +79,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Arithmetic {
                 }
                 let (l_ty, r_ty) = (cx.tables.expr_ty(l), cx.tables.expr_ty(r));
                 if l_ty.is_integral() && r_ty.is_integral() {
+                    if is_safe_constant(cx, expr) {
+                        return;
+                    }
                     span_lint(cx, INTEGER_ARITHMETIC, expr.span, "integer arithmetic detected");
                     self.span = Some(expr.span);
                 } else if l_ty.is_floating_point() && r_ty.is_floating_point() {
@@ -85,6 +92,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Arithmetic {
             hir::ExprKind::Unary(hir::UnOp::UnNeg, ref arg) => {
                 let ty = cx.tables.expr_ty(arg);
                 if ty.is_integral() {
+                    if is_safe_constant(cx, expr) {
+                        return;
+                    }
                     span_lint(cx, INTEGER_ARITHMETIC, expr.span, "integer arithmetic detected");
                     self.span = Some(expr.span);
                 } else if ty.is_floating_point() {
@@ -102,3 +112,64 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Arithmetic {
         }
     }
 }
+
+/// Can this arithmetic be evaluated at compile time without overflow or division by zero? Then
+/// it can't panic at runtime. The constant evaluation works on 128 bit integers, so the
+/// operation is redone on the evaluated operands and its result is checked against the range of
+/// the expression's type.
+fn is_safe_constant<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) -> bool {
+    let int = |e: &hir::Expr| match constant_simple(cx, cx.tables, e) {
+        Some(Constant::Int(n)) => Some(n),
+        _ => None,
+    };
+    match (&expr.node, &cx.tables.expr_ty(expr).sty) {
+        (&hir::ExprKind::Binary(op, ref l, ref r), &ty::TyInt(ity)) => {
+            let (l, r) = match (int(l), int(r)) {
+                (Some(l), Some(r)) => (sext(cx.tcx, l, ity), sext(cx.tcx, r, ity)),
+                _ => return false,
+            };
+            let value = match op.node {
+                hir::BinOpKind::Add => l.checked_add(r),
+                hir::BinOpKind::Sub => l.checked_sub(r),
+                hir::BinOpKind::Mul => l.checked_mul(r),
+                hir::BinOpKind::Div => l.checked_div(r),
+                hir::BinOpKind::Rem => l.checked_rem(r),
+                _ => None,
+            };
+            value.map_or(false, |v| fits_int(cx, v, ity))
+        },
+        (&hir::ExprKind::Binary(op, ref l, ref r), &ty::TyUint(uty)) => {
+            let (l, r) = match (int(l), int(r)) {
+                (Some(l), Some(r)) => (l, r),
+                _ => return false,
+            };
+            let value = match op.node {
+                hir::BinOpKind::Add => l.checked_add(r),
+                hir::BinOpKind::Sub => l.checked_sub(r),
+                hir::BinOpKind::Mul => l.checked_mul(r),
+                hir::BinOpKind::Div => l.checked_div(r),
+                hir::BinOpKind::Rem => l.checked_rem(r),
+                _ => None,
+            };
+            value.map_or(false, |v| clip(cx.tcx, v, uty) == v)
+        },
+        (&hir::ExprKind::Unary(hir::UnOp::UnNeg, ref arg), &ty::TyInt(ity)) => match int(arg) {
+            // the literal of `-128i8` is out of range on its own
+            Some(n) if is_lit(arg) => fits_int(cx, -(n as i128), ity),
+            Some(n) => sext(cx.tcx, n, ity).checked_neg().map_or(false, |v| fits_int(cx, v, ity)),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+fn fits_int(cx: &LateContext, value: i128, ity: ast::IntTy) -> bool {
+    sext(cx.tcx, unsext(cx.tcx, value, ity), ity) == value
+}
+
+fn is_lit(expr: &hir::Expr) -> bool {
+    match expr.node {
+        hir::ExprKind::Lit(..) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,102 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::UintTy;
+
+use crate::utils::{get_parent_expr, in_external_macro, span_help_and_lint};
+
+/// **What it does:** Checks for usage of `as` conversions.
+///
+/// **Why is this bad?** `as` conversions will perform many kinds of
+/// conversions, including silently lossy conversions and dangerous coercions.
+/// There are cases when it makes sense to use `as`, so the lint is
+/// Allow by default.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// let a: u32;
+/// ...
+/// f(a as u16);
+/// ```
+///
+/// Usually better represents the semantics you expect:
+/// ```rust,ignore
+/// f(a.try_into()?);
+/// ```
+/// or
+/// ```rust,ignore
+/// f(a.try_into().expect("Unexpected u16 overflow in f"));
+/// ```
+///
+/// Pointer-to-pointer casts and `usize` casts of indices are allowed if the
+/// `as-conversions-exempt-ptr-and-index` option is set.
+declare_clippy_lint! {
+    pub AS_CONVERSIONS,
+    restriction,
+    "using a potentially dangerous silent `as` conversion"
+}
+
+pub struct AsConversions {
+    exempt_ptr_and_index: bool,
+}
+
+impl AsConversions {
+    pub fn new(exempt_ptr_and_index: bool) -> Self {
+        Self { exempt_ptr_and_index }
+    }
+}
+
+impl LintPass for AsConversions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(AS_CONVERSIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AsConversions {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_external_macro(cx, expr.span) {
+            return;
+        }
+
+        if let ExprKind::Cast(ref inner, _) = expr.node {
+            if self.exempt_ptr_and_index {
+                let from = cx.tables.expr_ty(inner);
+                let to = cx.tables.expr_ty(expr);
+                if is_ptr(from) && is_ptr(to) || is_index(cx, expr, to) {
+                    return;
+                }
+            }
+            span_help_and_lint(
+                cx,
+                AS_CONVERSIONS,
+                expr.span,
+                "using a potentially dangerous silent `as` conversion",
+                "consider using a safe wrapper for this conversion",
+            );
+        }
+    }
+}
+
+fn is_ptr(ty: Ty) -> bool {
+    match ty.sty {
+        ty::TyRawPtr(..) | ty::TyRef(..) => true,
+        _ => false,
+    }
+}
+
+/// Is this cast to `usize` used as the index of an indexing expression?
+fn is_index(cx: &LateContext, cast: &Expr, to: Ty) -> bool {
+    if to.sty != ty::TyUint(UintTy::Usize) {
+        return false;
+    }
+    match get_parent_expr(cx, cast) {
+        Some(&Expr {
+            node: ExprKind::Index(_, ref index),
+            ..
+        }) => index.id == cast.id,
+        _ => false,
+    }
+}
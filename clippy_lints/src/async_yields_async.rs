@@ -0,0 +1,142 @@
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::HashSet;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_macro, is_async_generator, paths, snippet, span_lint_and_then,
+};
+
+/// **What it does:** Checks for `async` blocks and closures whose value is itself a future that
+/// isn't awaited.
+///
+/// **Why is this bad?** Awaiting the outer future only produces the inner future, whose work
+/// silently never happens unless it is awaited as well. Usually an `await!` is missing, or the
+/// outer `async` is not needed at all.
+///
+/// **Known problems:** Only the final expression of the block or closure is checked, a future
+/// that is returned early with `return` is missed.
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn foo() {}
+///
+/// fn bar() {
+///     let x = async {
+///         foo()
+///     };
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn foo() {}
+///
+/// fn bar() {
+///     let x = async {
+///         await!(foo())
+///     };
+/// }
+/// ```
+declare_clippy_lint! {
+    pub ASYNC_YIELDS_ASYNC,
+    correctness,
+    "`async` blocks and closures that return a future without awaiting it"
+}
+
+#[derive(Default)]
+pub struct AsyncYieldsAsync {
+    /// the generators that are the bodies of `async fn`s
+    async_fn_bodies: HashSet<NodeId>,
+}
+
+impl LintPass for AsyncYieldsAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ASYNC_YIELDS_ASYNC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AsyncYieldsAsync {
+    fn check_fn(
+        &mut self,
+        _: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        let header = match kind {
+            FnKind::ItemFn(_, _, header, ..) => header,
+            FnKind::Method(_, sig, ..) => sig.header,
+            FnKind::Closure(..) => return,
+        };
+        if header.asyncness == IsAsync::Async {
+            let mut visitor = GeneratorVisitor { generator: None };
+            visitor.visit_expr(&body.value);
+            self.async_fn_bodies.extend(visitor.generator);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Closure(_, _, body_id, _, _) = expr.node;
+            if is_async_generator(cx, expr);
+            if !in_macro(expr.span) && !self.async_fn_bodies.contains(&expr.id);
+            let body = cx.tcx.hir.body(body_id);
+            if let Some(tail) = tail_expr(&body.value);
+            if !in_macro(tail.span);
+            if let Some(future_trait) = get_trait_def_id(cx, &paths::FUTURE_TRAIT);
+            if implements_trait(cx, cx.tables.expr_ty(tail), future_trait, &[]);
+            then {
+                span_lint_and_then(
+                    cx,
+                    ASYNC_YIELDS_ASYNC,
+                    tail.span,
+                    "an `async` construct yields a type which is itself awaitable",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            tail.span,
+                            "consider awaiting this value",
+                            format!("await!({})", snippet(cx, tail.span, "..")),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the expression that is the value of a generator body.
+fn tail_expr(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Block(ref block, _) => block.expr.as_ref().map(|expr| &**expr),
+        _ => Some(expr),
+    }
+}
+
+/// Finds the generator the body of an `async fn` is lowered to.
+struct GeneratorVisitor {
+    generator: Option<NodeId>,
+}
+
+impl<'tcx> Visitor<'tcx> for GeneratorVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Closure(.., Some(_)) => if self.generator.is_none() {
+                self.generator = Some(expr.id);
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,249 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_block, walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use syntax_pos::MultiSpan;
+
+use crate::utils::{is_expn_of, match_def_path, paths, span_lint_and_then};
+
+/// **What it does:** Checks for lock guards that are held across an `await!` point.
+///
+/// **Why is this bad?** While the guard is held, no other task can take the lock. If the
+/// executor runs a task that needs the lock on the same thread while the future is suspended,
+/// it deadlocks; otherwise the whole thread is blocked until the lock is available. Async-aware
+/// locks or dropping the guard before awaiting avoid this.
+///
+/// **Known problems:** Only guards bound with `let` are found, and they are only considered
+/// dropped explicitly by calling `drop` on the binding. The guard types are configured with
+/// the `await-holding-lock-guards` option, which defaults to the guards of `std::sync` and
+/// `lock_api` (used by `parking_lot`).
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn foo(x: &Mutex<u32>) {
+///     let guard = x.lock().unwrap();
+///     *guard += 1;
+///     await!(bar());
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn foo(x: &Mutex<u32>) {
+///     {
+///         let guard = x.lock().unwrap();
+///         *guard += 1;
+///     }
+///     await!(bar());
+/// }
+/// ```
+declare_clippy_lint! {
+    pub AWAIT_HOLDING_LOCK,
+    pedantic,
+    "holding a lock guard across an `await!` point"
+}
+
+/// **What it does:** Checks for `RefCell` references (`Ref` and `RefMut`) that are held across
+/// an `await!` point.
+///
+/// **Why is this bad?** The `RefCell` stays borrowed while the future is suspended. Any other
+/// task or callback that borrows it in the meantime panics at runtime.
+///
+/// **Known problems:** Only references bound with `let` are found, and they are only considered
+/// dropped explicitly by calling `drop` on the binding.
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn foo(x: &RefCell<u32>) {
+///     let mut y = x.borrow_mut();
+///     *y += 1;
+///     await!(bar());
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn foo(x: &RefCell<u32>) {
+///     {
+///         let mut y = x.borrow_mut();
+///         *y += 1;
+///     }
+///     await!(bar());
+/// }
+/// ```
+declare_clippy_lint! {
+    pub AWAIT_HOLDING_REFCELL_REF,
+    pedantic,
+    "holding a `RefCell` reference across an `await!` point"
+}
+
+pub struct AwaitHoldingLock {
+    guards: Vec<String>,
+}
+
+impl AwaitHoldingLock {
+    pub fn new(guards: Vec<String>) -> Self {
+        Self { guards }
+    }
+
+    /// Returns the kind of guard `ty` is, if it is a `RefCell` reference or one of the configured
+    /// lock guards.
+    fn guard_kind<'a>(&'a self, cx: &LateContext, ty: Ty) -> Option<GuardKind<'a>> {
+        if let ty::TyAdt(adt, _) = ty.sty {
+            if match_def_path(cx.tcx, adt.did, &paths::REFCELL_REF)
+                || match_def_path(cx.tcx, adt.did, &paths::REFCELL_REFMUT)
+            {
+                return Some(GuardKind::RefCellRef);
+            }
+            for guard in &self.guards {
+                let path: Vec<&str> = guard.split("::").collect();
+                if match_def_path(cx.tcx, adt.did, &path) {
+                    return path.last().map(|&name| GuardKind::Lock(name));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl LintPass for AwaitHoldingLock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AwaitHoldingLock {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Closure(_, _, body_id, _, Some(_)) = expr.node;
+            if let ty::TyGenerator(def_id, substs, _) = cx.tables.expr_ty(expr).sty;
+            if let ty::TyGeneratorWitness(interior) = substs.witness(def_id, cx.tcx).sty;
+            // the types that are live across a suspension point
+            if interior.skip_binder().iter().any(|ty| self.guard_kind(cx, ty).is_some());
+            then {
+                let mut visitor = GuardVisitor {
+                    cx,
+                    lint: self,
+                };
+                visitor.visit_body(cx.tcx.hir.body(body_id));
+            }
+        }
+    }
+}
+
+enum GuardKind<'a> {
+    /// a lock guard with the given type name
+    Lock(&'a str),
+    RefCellRef,
+}
+
+/// Looks for `let` bindings of guards in the blocks of a generator body, not entering nested
+/// closures.
+struct GuardVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    lint: &'a AwaitHoldingLock,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for GuardVisitor<'a, 'tcx> {
+    fn visit_block(&mut self, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(_, binding_id, _, _) = local.pat.node;
+                if let Some(kind) = self.lint.guard_kind(self.cx, self.cx.tables.pat_ty(&local.pat));
+                then {
+                    let mut awaits = AwaitVisitor { awaits: Vec::new() };
+                    let mut dropped = false;
+                    for stmt in &block.stmts[i + 1..] {
+                        if is_drop_of(self.cx, stmt, binding_id) {
+                            dropped = true;
+                            break;
+                        }
+                        awaits.visit_stmt(stmt);
+                    }
+                    if !dropped {
+                        if let Some(ref expr) = block.expr {
+                            awaits.visit_expr(expr);
+                        }
+                    }
+                    if !awaits.awaits.is_empty() {
+                        let (lint, msg, help, note) = match kind {
+                            GuardKind::Lock(name) => (
+                                AWAIT_HOLDING_LOCK,
+                                format!("this `{}` is held across an `await!` point", name),
+                                "consider using an async-aware lock type or ensuring the guard is dropped before \
+                                 calling `await!`",
+                                "these are all the `await!` points this lock is held through",
+                            ),
+                            GuardKind::RefCellRef => (
+                                AWAIT_HOLDING_REFCELL_REF,
+                                "this `RefCell` reference is held across an `await!` point".to_string(),
+                                "ensure the reference is dropped before calling `await!`",
+                                "these are all the `await!` points this reference is held through",
+                            ),
+                        };
+                        span_lint_and_then(self.cx, lint, local.pat.span, &msg, |db| {
+                            db.help(help);
+                            db.span_note(MultiSpan::from_spans(awaits.awaits), note);
+                        });
+                    }
+                }
+            }
+        }
+        walk_block(self, block);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Closure(..) = expr.node {
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Is this statement `drop(binding)`?
+fn is_drop_of(cx: &LateContext, stmt: &Stmt, binding_id: NodeId) -> bool {
+    if_chain! {
+        if let StmtKind::Semi(ref expr, _) = stmt.node;
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Def::Fn(def_id) = cx.tables.qpath_def(qpath, func.hir_id);
+        if match_def_path(cx.tcx, def_id, &paths::DROP);
+        if args.len() == 1;
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = args[0].node;
+        if let Def::Local(id) = path.def;
+        then {
+            return id == binding_id;
+        }
+    }
+    false
+}
+
+/// Collects the `await!` calls that suspend the generator, which expand to `yield`s.
+struct AwaitVisitor {
+    awaits: Vec<Span>,
+}
+
+impl<'tcx> Visitor<'tcx> for AwaitVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Yield(_) => self.awaits.push(is_expn_of(expr.span, "await").unwrap_or(expr.span)),
+            ExprKind::Closure(..) => (),
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
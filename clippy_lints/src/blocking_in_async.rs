@@ -0,0 +1,229 @@
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{
+    get_def_path, get_trait_def_id, implements_trait, in_external_macro, is_async_generator, match_def_path, match_type,
+    paths, snippet, span_help_and_lint, span_lint_and_then, walk_ptrs_ty,
+};
+
+/// **What it does:** Checks for calls to blocking functions inside `async fn`s and `async`
+/// blocks. By default these are `std::thread::sleep`, the file system and network functions of
+/// `std::fs` and `std::net` that do I/O, and the methods of `std::io::Read` and `std::io::Write`
+/// on a `File` or `TcpStream`. More paths can be added with the `blocking-in-async-paths`
+/// configuration option.
+///
+/// **Why is this bad?** A blocking call stalls the executor thread, so no other task can make
+/// progress on it until the call returns.
+///
+/// **Known problems:** Only the listed functions are known to block, blocking calls of other
+/// crates are missed unless they are configured. Calls in closures inside of `async` code are not
+/// linted, as they may be run elsewhere.
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn foo() {
+///     std::thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn foo() {
+///     await!(tokio::timer::Delay::new(Instant::now() + Duration::from_secs(1)));
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BLOCKING_IN_ASYNC,
+    pedantic,
+    "calls to blocking functions in `async` code"
+}
+
+/// **What it does:** Checks for calls to executor entry points like `block_on` inside
+/// `async fn`s and `async` blocks. The functions are set with the `block-on-paths`
+/// configuration option.
+///
+/// **Why is this bad?** Blocking on a future from inside of an executor deadlocks if the future
+/// needs the same executor thread to make progress, and some executors panic instead.
+///
+/// **Known problems:** Calls in closures inside of `async` code are not linted, even if the
+/// closure is called right away.
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn foo() -> u32 {
+///     futures::executor::block_on(bar())
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn foo() -> u32 {
+///     await!(bar())
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BLOCK_ON_IN_ASYNC,
+    correctness,
+    "calls to `block_on` in `async` code"
+}
+
+/// The functions that are considered blocking, by the path of their definition.
+const DEFAULT_BLOCKING_PATHS: [&str; 33] = [
+    "std::thread::sleep",
+    "std::fs::canonicalize",
+    "std::fs::copy",
+    "std::fs::create_dir",
+    "std::fs::create_dir_all",
+    "std::fs::hard_link",
+    "std::fs::metadata",
+    "std::fs::read",
+    "std::fs::read_dir",
+    "std::fs::read_link",
+    "std::fs::read_to_string",
+    "std::fs::remove_dir",
+    "std::fs::remove_dir_all",
+    "std::fs::remove_file",
+    "std::fs::rename",
+    "std::fs::set_permissions",
+    "std::fs::symlink_metadata",
+    "std::fs::write",
+    "std::fs::DirBuilder::create",
+    "std::fs::File::create",
+    "std::fs::File::open",
+    "std::fs::File::sync_all",
+    "std::fs::File::sync_data",
+    "std::fs::OpenOptions::open",
+    "std::net::tcp::TcpListener::accept",
+    "std::net::tcp::TcpListener::bind",
+    "std::net::tcp::TcpStream::connect",
+    "std::net::tcp::TcpStream::connect_timeout",
+    "std::net::udp::UdpSocket::bind",
+    "std::net::udp::UdpSocket::recv",
+    "std::net::udp::UdpSocket::recv_from",
+    "std::net::udp::UdpSocket::send",
+    "std::net::udp::UdpSocket::send_to",
+];
+
+/// The types whose `std::io::Read` and `std::io::Write` implementations block.
+const BLOCKING_IO_TYPES: [&[&str]; 2] = [&paths::FILE, &paths::TCP_STREAM];
+
+pub struct BlockingInAsync {
+    paths: Vec<Vec<String>>,
+    block_on_paths: Vec<String>,
+    /// for each enclosing closure, whether it is the generator of `async` code
+    closures: Vec<bool>,
+}
+
+impl BlockingInAsync {
+    pub fn new(extra_paths: Vec<String>, block_on_paths: Vec<String>) -> Self {
+        Self {
+            paths: DEFAULT_BLOCKING_PATHS
+                .iter()
+                .map(|&path| path.to_string())
+                .chain(extra_paths)
+                .map(|path| path.split("::").map(str::to_string).collect())
+                .collect(),
+            block_on_paths,
+            closures: Vec::new(),
+        }
+    }
+
+    fn is_blocking(&self, cx: &LateContext, def_id: DefId, receiver: Option<&Expr>) -> bool {
+        let def_path = get_def_path(cx.tcx, def_id);
+        if self
+            .paths
+            .iter()
+            .any(|path| path.len() <= def_path.len() && path.iter().zip(&def_path).all(|(a, b)| *a == **b))
+        {
+            return true;
+        }
+        // `Read` and `Write` methods, called on a file or a socket
+        match (cx.tcx.trait_of_item(def_id), receiver) {
+            (Some(trait_id), Some(receiver)) => {
+                let is_io_trait = match_def_path(cx.tcx, trait_id, &paths::IO_READ)
+                    || match_def_path(cx.tcx, trait_id, &paths::IO_WRITE);
+                let receiver_ty = walk_ptrs_ty(cx.tables.expr_ty(receiver));
+                is_io_trait && BLOCKING_IO_TYPES.iter().any(|path| match_type(cx, receiver_ty, path))
+            },
+            _ => false,
+        }
+    }
+}
+
+impl LintPass for BlockingInAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BLOCKING_IN_ASYNC, BLOCK_ON_IN_ASYNC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BlockingInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (def_id, args) = match expr.node {
+            ExprKind::Closure(..) => {
+                self.closures.push(is_async_generator(cx, expr));
+                return;
+            },
+            ExprKind::Call(ref func, ref args) => match func.node {
+                ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, func.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) => (def_id, args),
+                    _ => return,
+                },
+                _ => return,
+            },
+            ExprKind::MethodCall(_, _, ref args) => match cx.tables.type_dependent_defs().get(expr.hir_id) {
+                Some(def) => (def.def_id(), args),
+                None => return,
+            },
+            _ => return,
+        };
+        if self.closures.last() != Some(&true) || in_external_macro(cx, expr.span) {
+            return;
+        }
+        let path = cx.tcx.item_path_str(def_id);
+        if self.block_on_paths.contains(&path) {
+            span_lint_and_then(
+                cx,
+                BLOCK_ON_IN_ASYNC,
+                expr.span,
+                &format!("call to `{}` in `async` code", path),
+                |db| {
+                    if let Some(future) = future_arg(cx, args) {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "consider awaiting the future instead",
+                            format!("await!({})", snippet(cx, future.span, "..")),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        } else if self.is_blocking(cx, def_id, args.get(0)) {
+            span_help_and_lint(
+                cx,
+                BLOCKING_IN_ASYNC,
+                expr.span,
+                &format!("blocking call to `{}` in `async` code", path),
+                "consider using an async equivalent, or running the call on a thread pool for blocking tasks",
+            );
+        }
+    }
+
+    fn check_expr_post(&mut self, _: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Closure(..) = expr.node {
+            self.closures.pop();
+        }
+    }
+}
+
+/// The argument of a `block_on` call that is the future, e.g. the second one of
+/// `Runtime::block_on(&mut rt, future)`, or the last one if no argument is known to be a future.
+fn future_arg<'e>(cx: &LateContext, args: &'e [Expr]) -> Option<&'e Expr> {
+    let future_trait = get_trait_def_id(cx, &paths::FUTURE_TRAIT);
+    args.iter()
+        .find(|arg| future_trait.map_or(false, |id| implements_trait(cx, cx.tables.expr_ty(arg), id, &[])))
+        .or_else(|| args.last())
+}
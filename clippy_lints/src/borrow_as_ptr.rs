@@ -0,0 +1,114 @@
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use semver::Version;
+use crate::utils::{in_macro, meets_msrv, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for the usage of `&expr as *const T` or
+/// `&mut expr as *mut T`, and suggests using `ptr::addr_of` or
+/// `ptr::addr_of_mut` instead.
+///
+/// **Why is this bad?** This would be better expressed with `ptr::addr_of`,
+/// which does not create an intermediate reference. Creating a reference
+/// asserts that the place is aligned and initialized, which is undefined
+/// behavior if it is not the case (for example for fields of packed structs).
+///
+/// **Known problems:** `ptr::addr_of` is only available since Rust 1.51, the
+/// lint respects the `msrv` configuration option.
+///
+/// **Example:**
+/// ```rust
+/// let val = 1;
+/// let p = &val as *const i32;
+///
+/// let mut val_mut = 1;
+/// let p_mut = &mut val_mut as *mut i32;
+/// ```
+/// Use instead:
+/// ```rust
+/// let val = 1;
+/// let p = std::ptr::addr_of!(val);
+///
+/// let mut val_mut = 1;
+/// let p_mut = std::ptr::addr_of_mut!(val_mut);
+/// ```
+declare_clippy_lint! {
+    pub BORROW_AS_PTR,
+    pedantic,
+    "borrowing just to cast to a raw pointer"
+}
+
+/// The first version of Rust with `ptr::addr_of!` and `ptr::addr_of_mut!`.
+const ADDR_OF_MSRV: [u64; 3] = [1, 51, 0];
+
+pub struct BorrowAsPtr {
+    msrv: Option<Version>,
+}
+
+impl BorrowAsPtr {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for BorrowAsPtr {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BORROW_AS_PTR)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BorrowAsPtr {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if !meets_msrv(self.msrv.as_ref(), &ADDR_OF_MSRV) {
+            return;
+        }
+
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Cast(ref left_expr, ref ty) = expr.node;
+            if let TyKind::Ptr(ref ptr) = ty.node;
+            if let ExprKind::AddrOf(_, ref e) = left_expr.node;
+            if is_place(cx, e);
+            if let Some(snip) = snippet_opt(cx, e.span);
+            then {
+                // `&mut x as *const T` only needs `addr_of!`
+                let macro_name = match ptr.mutbl {
+                    MutImmutable => "addr_of",
+                    MutMutable => "addr_of_mut",
+                };
+
+                span_lint_and_then(
+                    cx,
+                    BORROW_AS_PTR,
+                    expr.span,
+                    "borrow as raw pointer",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "try",
+                            format!("std::ptr::{}!({})", macro_name, snip),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Is `expr` a place expression? Borrowing anything else borrows a temporary, which `addr_of!`
+/// doesn't do.
+fn is_place(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::Local(..) | Def::Upvar(..) | Def::Static(..) => true,
+            _ => false,
+        },
+        ExprKind::Field(ref base, _) | ExprKind::Index(ref base, _) => is_place(cx, base),
+        ExprKind::Unary(UnDeref, _) => true,
+        _ => false,
+    }
+}
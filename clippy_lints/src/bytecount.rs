@@ -12,10 +12,11 @@ use crate::utils::{contains_name, get_pat_name, match_type, paths, single_segmen
 /// **Why is this bad?** The [`bytecount`](https://crates.io/crates/bytecount)
 /// crate has methods to count your bytes faster, especially for large slices.
 ///
-/// **Known problems:** If you have predominantly small slices, the
-/// `bytecount::count(..)` method may actually be slower. However, if you can
-/// ensure that less than 2³²-1 matches arise, the `naive_count_32(..)` can be
-/// faster in those cases.
+/// **Known problems:** The suggestion needs the `bytecount` crate as a new
+/// dependency, so the lint is allow-by-default. If you have predominantly small
+/// slices, the `bytecount::count(..)` method may actually be slower. However,
+/// if you can ensure that less than 2³²-1 matches arise, the
+/// `naive_count_32(..)` can be faster in those cases.
 ///
 /// **Example:**
 ///
@@ -24,7 +25,7 @@ use crate::utils::{contains_name, get_pat_name, match_type, paths, single_segmen
 /// ```
 declare_clippy_lint! {
     pub NAIVE_BYTECOUNT,
-    perf,
+    pedantic,
     "use of naive `<slice>.filter(|&x| x == y).count()` to count byte values"
 }
 
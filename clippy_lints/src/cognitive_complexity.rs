@@ -0,0 +1,230 @@
+//! calculate cognitive complexity and warn about overly complex functions
+
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc::hir::*;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use syntax::ast::{Attribute, NodeId};
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_help_and_lint, LimitStack};
+
+/// **What it does:** Checks for methods with high cognitive complexity.
+///
+/// **Why is this bad?** Methods of high cognitive complexity tend to be hard to
+/// both read and maintain. Also LLVM will tend to optimize small methods better.
+///
+/// The complexity is computed by adding one for every `if`, `else`, `match`,
+/// loop, labeled `break`/`continue` and every sequence of alike `&&` or `||`
+/// operators. Constructs nested inside of `if`s, `match`es and loops
+/// additionally pay one for every level of nesting.
+///
+/// **Known problems:** Sometimes it's hard to find a way to reduce the
+/// complexity.
+///
+/// **Example:** No. You'll see it when you get the warning.
+declare_clippy_lint! {
+    pub COGNITIVE_COMPLEXITY,
+    complexity,
+    "functions that should be split up into multiple functions"
+}
+
+pub struct CognitiveComplexity {
+    limit: LimitStack,
+}
+
+impl CognitiveComplexity {
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit: LimitStack::new(limit),
+        }
+    }
+}
+
+impl LintPass for CognitiveComplexity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COGNITIVE_COMPLEXITY)
+    }
+}
+
+impl CognitiveComplexity {
+    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span) {
+        if in_macro(span) {
+            return;
+        }
+
+        let mut helper = CCHelper {
+            score: 0,
+            nesting: 0,
+            logical_op: None,
+        };
+        helper.visit_expr(&body.value);
+
+        if helper.score > self.limit.limit() {
+            span_help_and_lint(
+                cx,
+                COGNITIVE_COMPLEXITY,
+                span,
+                &format!("the function has a cognitive complexity of {}", helper.score),
+                "you could split it up into multiple smaller functions",
+            );
+        }
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CognitiveComplexity {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        _: intravisit::FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        let def_id = cx.tcx.hir.local_def_id(node_id);
+        if !cx.tcx.has_attr(def_id, "test") {
+            self.check(cx, body, span);
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit.push_attrs(cx.sess(), attrs, "cognitive_complexity");
+        // the attribute of the old `cyclomatic_complexity` lint is still honored
+        self.limit.push_attrs(cx.sess(), attrs, "cyclomatic_complexity");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit.pop_attrs(cx.sess(), attrs, "cyclomatic_complexity");
+        self.limit.pop_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+}
+
+struct CCHelper {
+    score: u64,
+    nesting: u64,
+    /// the logical operator of the enclosing expression, if any, so that chains
+    /// like `a && b && c` are only counted once
+    logical_op: Option<BinOpKind>,
+}
+
+impl CCHelper {
+    /// adds the cost of a construct that is subject to the nesting penalty
+    fn add_nested(&mut self) {
+        self.score += 1 + self.nesting;
+    }
+
+    fn visit_nested<'tcx>(&mut self, e: &'tcx Expr) {
+        self.nesting += 1;
+        self.visit_expr(e);
+        self.nesting -= 1;
+    }
+
+    fn visit_arms<'tcx>(&mut self, arms: &'tcx [Arm]) {
+        self.nesting += 1;
+        for arm in arms {
+            self.visit_arm(arm);
+        }
+        self.nesting -= 1;
+    }
+
+    /// `else` and `else if` branches cost one each, no matter how deeply the
+    /// `if` is nested
+    fn visit_else<'tcx>(&mut self, els: &'tcx Expr) {
+        self.score += 1;
+        match els.node {
+            ExprKind::If(ref cond, ref then, ref els) => {
+                self.visit_expr(cond);
+                self.visit_nested(then);
+                if let Some(ref els) = *els {
+                    self.visit_else(els);
+                }
+            },
+            ExprKind::Match(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause }) => {
+                self.visit_expr(scrutinee);
+                self.visit_if_let_arms(arms, contains_else_clause);
+            },
+            _ => self.visit_nested(els),
+        }
+    }
+
+    fn visit_if_let_arms<'tcx>(&mut self, arms: &'tcx [Arm], contains_else_clause: bool) {
+        self.visit_nested(&arms[0].body);
+        if contains_else_clause {
+            self.visit_else(&arms[1].body);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for CCHelper {
+    fn visit_expr(&mut self, e: &'tcx Expr) {
+        if in_macro(e.span) {
+            return;
+        }
+
+        let outer_op = self.logical_op.take();
+        match e.node {
+            ExprKind::If(ref cond, ref then, ref els) => {
+                self.add_nested();
+                self.visit_expr(cond);
+                self.visit_nested(then);
+                if let Some(ref els) = *els {
+                    self.visit_else(els);
+                }
+            },
+            ExprKind::Match(ref scrutinee, ref arms, ref source) => {
+                self.visit_expr(scrutinee);
+                match *source {
+                    MatchSource::Normal => {
+                        self.add_nested();
+                        self.visit_arms(arms);
+                    },
+                    MatchSource::IfLetDesugar { contains_else_clause } => {
+                        self.add_nested();
+                        self.visit_if_let_arms(arms, contains_else_clause);
+                    },
+                    // the loop itself has already been counted
+                    MatchSource::WhileLetDesugar | MatchSource::ForLoopDesugar | MatchSource::TryDesugar => {
+                        for arm in arms {
+                            self.visit_arm(arm);
+                        }
+                    },
+                }
+            },
+            ExprKind::While(ref cond, ref block, _) => {
+                self.add_nested();
+                self.visit_expr(cond);
+                self.nesting += 1;
+                self.visit_block(block);
+                self.nesting -= 1;
+            },
+            ExprKind::Loop(ref block, _, _) => {
+                self.add_nested();
+                self.nesting += 1;
+                self.visit_block(block);
+                self.nesting -= 1;
+            },
+            ExprKind::Break(ref destination, _) | ExprKind::Continue(ref destination) => {
+                if destination.label.is_some() {
+                    self.score += 1;
+                }
+                walk_expr(self, e);
+            },
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And || op.node == BinOpKind::Or => {
+                if outer_op != Some(op.node) {
+                    self.score += 1;
+                }
+                self.logical_op = Some(op.node);
+                self.visit_expr(lhs);
+                self.logical_op = Some(op.node);
+                self.visit_expr(rhs);
+                self.logical_op = None;
+            },
+            // closures are checked as functions of their own
+            ExprKind::Closure(..) => (),
+            _ => walk_expr(self, e),
+        }
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
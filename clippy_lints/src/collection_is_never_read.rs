@@ -0,0 +1,179 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use syntax_pos::MultiSpan;
+
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then};
+
+/// **What it does:** Checks for local collections that are only ever written to, e.g. pushed
+/// into or extended, but whose contents are never read.
+///
+/// **Why is this bad?** Filling a collection that nobody looks at is wasted work, and most
+/// likely the result of a refactoring that forgot to remove it, or a bug where the collection
+/// was meant to be used.
+///
+/// **Known problems:** Only the methods that add to, remove from or clear a collection are
+/// considered writes, and only if their result is discarded, as in `v.push(x);`. Any other use
+/// of the binding, including passing it to another function, counts as a read.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = Vec::new();
+/// v.push(1);
+/// v.push(2);
+/// // `v` is never read
+/// ```
+declare_clippy_lint! {
+    pub COLLECTION_IS_NEVER_READ,
+    nursery,
+    "a collection is never read"
+}
+
+const COLLECTIONS: [&[&str]; 9] = [
+    &paths::BINARY_HEAP,
+    &paths::BTREEMAP,
+    &paths::BTREESET,
+    &paths::HASHMAP,
+    &paths::HASHSET,
+    &paths::LINKED_LIST,
+    &paths::STRING,
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+];
+
+/// Methods that only change the contents of a collection.
+const WRITE_METHODS: [&str; 13] = [
+    "append",
+    "clear",
+    "extend",
+    "insert",
+    "push",
+    "push_back",
+    "push_front",
+    "push_str",
+    "reserve",
+    "reserve_exact",
+    "shrink_to_fit",
+    "truncate",
+    "extend_from_slice",
+];
+
+pub struct CollectionIsNeverRead;
+
+impl LintPass for CollectionIsNeverRead {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COLLECTION_IS_NEVER_READ)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CollectionIsNeverRead {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(_, binding_id, _, None) = local.pat.node;
+                let ty = cx.tables.pat_ty(&local.pat);
+                if COLLECTIONS.iter().any(|path| match_type(cx, ty, path));
+                then {
+                    let mut visitor = UsageVisitor {
+                        cx,
+                        binding_id,
+                        writes: Vec::new(),
+                        read: false,
+                    };
+                    for stmt in &block.stmts[i + 1..] {
+                        visitor.visit_stmt(stmt);
+                    }
+                    if let Some(ref expr) = block.expr {
+                        visitor.visit_expr(expr);
+                    }
+                    if !visitor.read && !visitor.writes.is_empty() {
+                        span_lint_and_then(
+                            cx,
+                            COLLECTION_IS_NEVER_READ,
+                            local.pat.span,
+                            "collection is never read",
+                            |db| {
+                                db.span_note(
+                                    MultiSpan::from_spans(visitor.writes),
+                                    "the collection is only written to here",
+                                );
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Classifies the uses of a local collection into writes and reads.
+struct UsageVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    binding_id: NodeId,
+    /// the spans of the writes to the collection
+    writes: Vec<Span>,
+    /// whether the collection is used in any other way
+    read: bool,
+}
+
+impl<'a, 'tcx> UsageVisitor<'a, 'tcx> {
+    fn is_binding(&self, expr: &Expr) -> bool {
+        if_chain! {
+            if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node;
+            if let Def::Local(id) | Def::Upvar(id, ..) = path.def;
+            then {
+                id == self.binding_id
+            } else {
+                false
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsageVisitor<'a, 'tcx> {
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt) {
+        if self.read {
+            return;
+        }
+        // the result of a write like `set.insert(x)` tells something about the contents
+        if_chain! {
+            if let StmtKind::Semi(ref expr, _) = stmt.node;
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if self.is_binding(&args[0]) && WRITE_METHODS.contains(&&*method.ident.as_str());
+            then {
+                self.writes.push(expr.span);
+                for arg in &args[1..] {
+                    self.visit_expr(arg);
+                }
+                return;
+            }
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.read {
+            return;
+        }
+        match expr.node {
+            ExprKind::Assign(ref lhs, ref rhs) if self.is_binding(lhs) => {
+                self.writes.push(expr.span);
+                self.visit_expr(rhs);
+            },
+            _ if self.is_binding(expr) => self.read = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
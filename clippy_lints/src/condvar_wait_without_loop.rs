@@ -0,0 +1,88 @@
+use if_chain::if_chain;
+use rustc::hir::map::Node::{NodeExpr, NodeImplItem, NodeItem, NodeTraitItem};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_external_macro, match_type, paths, span_help_and_lint, walk_ptrs_ty};
+
+/// **What it does:** Checks for calls to `Condvar::wait` and `Condvar::wait_timeout` that are not
+/// inside a loop.
+///
+/// **Why is this bad?** Condition variables are subject to spurious wakeups, so `wait` can return
+/// although nobody notified the condition variable. The predicate has to be checked again after
+/// every wakeup, which takes a `while` loop around the wait.
+///
+/// **Known problems:** Any enclosing loop is accepted, whether or not it checks the predicate.
+///
+/// **Example:**
+/// ```rust,ignore
+/// let mut started = lock.lock().unwrap();
+/// if !*started {
+///     started = cvar.wait(started).unwrap();
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// let mut started = lock.lock().unwrap();
+/// while !*started {
+///     started = cvar.wait(started).unwrap();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub CONDVAR_WAIT_WITHOUT_LOOP,
+    correctness,
+    "waiting on a `Condvar` without re-checking the condition in a loop"
+}
+
+pub struct CondvarWaitWithoutLoop;
+
+impl LintPass for CondvarWaitWithoutLoop {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CONDVAR_WAIT_WITHOUT_LOOP)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CondvarWaitWithoutLoop {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if method.ident.name == "wait" || method.ident.name == "wait_timeout";
+            if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0])), &paths::CONDVAR);
+            if !in_external_macro(cx, expr.span);
+            if !is_in_loop(cx, expr);
+            then {
+                span_help_and_lint(
+                    cx,
+                    CONDVAR_WAIT_WITHOUT_LOOP,
+                    expr.span,
+                    &format!("this `Condvar::{}` is not in a loop", method.ident.name),
+                    "the wait can wake up spuriously, consider re-checking the condition in a `while` loop around it",
+                );
+            }
+        }
+    }
+}
+
+/// Is `expr` inside a loop of the same function or closure?
+fn is_in_loop(cx: &LateContext, expr: &Expr) -> bool {
+    let map = &cx.tcx.hir;
+    let mut id = expr.id;
+    loop {
+        let parent = map.get_parent_node(id);
+        if parent == id {
+            return false;
+        }
+        match map.find(parent) {
+            Some(NodeExpr(parent_expr)) => match parent_expr.node {
+                ExprKind::Loop(..) | ExprKind::While(..) => return true,
+                ExprKind::Closure(..) => return false,
+                _ => (),
+            },
+            Some(NodeItem(_)) | Some(NodeImplItem(_)) | Some(NodeTraitItem(_)) => return false,
+            _ => (),
+        }
+        id = parent;
+    }
+}
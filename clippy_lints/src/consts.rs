@@ -447,10 +447,35 @@ pub fn miri_to_const<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, result: &ty::Const<'
                     let n = n as usize;
                     String::from_utf8(alloc.bytes[offset..(offset + n)].to_owned()).ok().map(Constant::Str)
                 },
+                ty::TySlice(elem) if elem == tcx.types.u8 => {
+                    let alloc = tcx
+                        .alloc_map
+                        .lock()
+                        .unwrap_memory(ptr.alloc_id);
+                    let offset = ptr.offset.bytes() as usize;
+                    let n = n as usize;
+                    Some(Constant::Binary(Rc::new(alloc.bytes[offset..(offset + n)].to_owned())))
+                },
                 _ => None,
             },
             _ => None,
         }
+        // references to byte arrays, e.g. byte string literals
+        ConstValue::Scalar(Scalar::Ptr(ptr)) => match result.ty.sty {
+            ty::TyRef(_, tam, _) => match tam.sty {
+                ty::TyArray(elem, n) if elem == tcx.types.u8 => {
+                    let alloc = tcx
+                        .alloc_map
+                        .lock()
+                        .unwrap_memory(ptr.alloc_id);
+                    let offset = ptr.offset.bytes() as usize;
+                    let n = n.assert_usize(tcx)? as usize;
+                    Some(Constant::Binary(Rc::new(alloc.bytes[offset..(offset + n)].to_owned())))
+                },
+                _ => None,
+            },
+            _ => None,
+        },
         // FIXME: implement other conversions
         _ => None,
     }
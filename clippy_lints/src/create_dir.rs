@@ -0,0 +1,64 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{match_def_path, opt_def_id, paths, resolve_node, snippet, span_lint_and_then};
+
+/// **What it does:** Checks usage of `std::fs::create_dir` and suggest using `std::fs::create_dir_all` instead.
+///
+/// **Why is this bad?** Sometimes `std::fs::create_dir` is mistakenly chosen over `std::fs::create_dir_all`,
+/// which doesn't fail if the parent directories are missing or the directory already exists.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// std::fs::create_dir("foo");
+/// ```
+/// Use instead:
+/// ```rust
+/// std::fs::create_dir_all("foo");
+/// ```
+declare_clippy_lint! {
+    pub CREATE_DIR,
+    restriction,
+    "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`"
+}
+
+pub struct CreateDir;
+
+impl LintPass for CreateDir {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CREATE_DIR)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CreateDir {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if let ExprKind::Path(ref path) = func.node;
+            if let Some(def_id) = opt_def_id(resolve_node(cx, path, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::STD_FS_CREATE_DIR);
+            then {
+                span_lint_and_then(
+                    cx,
+                    CREATE_DIR,
+                    expr.span,
+                    "calling `std::fs::create_dir` where there may be a better way",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "consider calling `std::fs::create_dir_all` instead",
+                            format!("std::fs::create_dir_all({})", snippet(cx, args[0].span, "..")),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use crate::utils::{in_macro, is_expn_of, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for usage of dbg!() macro.
+///
+/// **Why is this bad?** `dbg!` macro is intended as a debugging tool. It
+/// should not be in version control.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// // Bad
+/// dbg!(true)
+///
+/// // Good
+/// true
+/// ```
+declare_clippy_lint! {
+    pub DBG_MACRO,
+    restriction,
+    "`dbg!` macro is intended as a debugging tool"
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array![DBG_MACRO]
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        // `dbg!(expr)` expands to `match expr { tmp => { eprintln!(..); tmp } }`, this
+        // is the only expression that comes directly from the `dbg!` expansion and not
+        // from the nested macros
+        let is_dbg_expansion = expr.span
+            .ctxt()
+            .outer()
+            .expn_info()
+            .map_or(false, |info| info.format.name() == "dbg");
+        if !is_dbg_expansion {
+            return;
+        }
+        if let ExprKind::Match(ref matchee, _, MatchSource::Normal) = expr.node {
+            if let Some(span) = is_expn_of(expr.span, "dbg") {
+                if in_macro(span) {
+                    return;
+                }
+                span_lint_and_then(cx, DBG_MACRO, span, "`dbg!` macro is intended as a debugging tool", |db| {
+                    if let Some(sugg) = snippet_opt(cx, matchee.span) {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "ensure to avoid having uses of it in version control",
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, layout::LayoutOf};
+use rustc::{declare_lint, lint_array};
+use std::collections::{HashMap, HashSet};
+use syntax::ast::{Name, NodeId};
+use syntax::codemap::Span;
+
+use crate::utils::{get_parent_expr, in_macro, span_lint_node_and_then, walk_ptrs_ty};
+
+/// **What it does:** Checks for unions with more than one non-zero-sized field that don't have
+/// a `#[repr(C)]` attribute, and are read through another field than the one they are written
+/// to.
+///
+/// **Why is this bad?** Unions are mostly used to read a value as another type than it was
+/// written as. A `repr(Rust)` union makes no guarantees about the offsets of its fields though,
+/// so such a read could see any part of the written value.
+///
+/// **Known problems:** Fields are paired across the whole crate, not by the value they are
+/// accessed on, so a union that is written as `a` in one place and read as `b` in another is
+/// linted even if the two never meet. Writes through a pointer to a field, or with
+/// `std::ptr::write`, are not seen.
+///
+/// **Example:**
+/// ```rust
+/// union Foo {
+///     a: i32,
+///     b: u32,
+/// }
+///
+/// fn main() {
+///     let _x: u32 = unsafe {
+///         Foo { a: 0_i32 }.b // Undefined behaviour: `b` is allowed to be padding
+///     };
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// #[repr(C)]
+/// union Foo {
+///     a: i32,
+///     b: u32,
+/// }
+///
+/// fn main() {
+///     let _x: u32 = unsafe {
+///         Foo { a: 0_i32 }.b // Now defined behaviour, this is just an i32 -> u32 transmute
+///     };
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DEFAULT_UNION_REPRESENTATION,
+    restriction,
+    "unions without a `#[repr(C)]` attribute that are used for type punning"
+}
+
+#[derive(Default)]
+pub struct DefaultUnionRepresentation {
+    /// unions with the default representation and more than one non-zero-sized field
+    candidates: Vec<(DefId, NodeId, Span)>,
+    /// the fields of each union that are written to, by a union expression or an assignment
+    written: HashMap<DefId, HashSet<Name>>,
+    /// the fields of each union that are read from
+    read: HashMap<DefId, HashSet<Name>>,
+}
+
+impl LintPass for DefaultUnionRepresentation {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEFAULT_UNION_REPRESENTATION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultUnionRepresentation {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Union(..) = item.node {
+            if in_macro(item.span) {
+                return;
+            }
+            let def_id = cx.tcx.hir.local_def_id(item.id);
+            let adt = cx.tcx.adt_def(def_id);
+            if adt.repr.c() || adt.repr.transparent() {
+                return;
+            }
+            // a field of generic type might be zero-sized, but it can't be known here
+            let non_zst_fields = adt
+                .all_fields()
+                .filter(|field| {
+                    cx.layout_of(cx.tcx.type_of(field.did))
+                        .map_or(true, |layout| layout.size.bytes() > 0)
+                })
+                .count();
+            if non_zst_fields > 1 {
+                self.candidates.push((def_id, item.id, item.span));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Struct(_, ref fields, _) => if let Some(def_id) = union_def_id(cx.tables.expr_ty(expr)) {
+                let written = self.written.entry(def_id).or_insert_with(HashSet::new);
+                written.extend(fields.iter().map(|field| field.ident.name));
+            },
+            ExprKind::Field(ref base, ident) => if let Some(def_id) = union_def_id(cx.tables.expr_ty(base)) {
+                let is_assigned = match get_parent_expr(cx, expr) {
+                    Some(&Expr {
+                        node: ExprKind::Assign(ref lhs, _),
+                        ..
+                    }) => lhs.id == expr.id,
+                    _ => false,
+                };
+                let accesses = if is_assigned { &mut self.written } else { &mut self.read };
+                accesses.entry(def_id).or_insert_with(HashSet::new).insert(ident.name);
+            },
+            _ => (),
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(ref def_id, id, span) in &self.candidates {
+            let (written, read) = match (self.written.get(def_id), self.read.get(def_id)) {
+                (Some(written), Some(read)) => (written, read),
+                _ => continue,
+            };
+            if read.iter().any(|name| written.iter().any(|other| other != name)) {
+                span_lint_node_and_then(
+                    cx,
+                    DEFAULT_UNION_REPRESENTATION,
+                    id,
+                    span,
+                    "this union has the default representation",
+                    |db| {
+                        db.help("consider annotating the union with `#[repr(C)]` to explicitly specify memory layout");
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn union_def_id(ty: ty::Ty) -> Option<DefId> {
+    match walk_ptrs_ty(ty).sty {
+        ty::TyAdt(adt, _) if adt.is_union() => Some(adt.did),
+        _ => None,
+    }
+}
@@ -0,0 +1,70 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::codemap::Span;
+
+use crate::utils::{in_external_macro, match_type, paths, span_help_and_lint};
+
+/// **What it does:** Checks for `JoinHandle`s of spawned threads that are dropped right away,
+/// either as an expression statement or with `let _ = ..`.
+///
+/// **Why is this bad?** Dropping the handle detaches the thread, so nothing notices when it
+/// panics, and its result and errors are lost.
+///
+/// **Known problems:** Detaching a thread is sometimes intended. Binding the handle to a named
+/// variable like `_detached` documents that and silences the lint.
+///
+/// **Example:**
+/// ```rust
+/// std::thread::spawn(|| println!("hello"));
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let handle = std::thread::spawn(|| println!("hello"));
+/// handle.join().unwrap();
+/// ```
+declare_clippy_lint! {
+    pub DETACHED_THREAD,
+    pedantic,
+    "dropping the `JoinHandle` of a spawned thread right away"
+}
+
+pub struct DetachedThread;
+
+impl LintPass for DetachedThread {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DETACHED_THREAD)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DetachedThread {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        match stmt.node {
+            StmtKind::Semi(ref expr, _) => check_dropped(cx, expr, stmt.span),
+            StmtKind::Decl(ref decl, _) => if let DeclKind::Local(ref local) = decl.node {
+                if let (&PatKind::Wild, Some(ref init)) = (&local.pat.node, &local.init) {
+                    check_dropped(cx, init, stmt.span);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Lints `expr` if it is a call that returns a `JoinHandle`, which is dropped by the statement.
+fn check_dropped(cx: &LateContext, expr: &Expr, span: Span) {
+    let is_call = match expr.node {
+        ExprKind::Call(..) | ExprKind::MethodCall(..) => true,
+        _ => false,
+    };
+    if is_call && !in_external_macro(cx, span) && match_type(cx, cx.tables.expr_ty(expr), &paths::JOIN_HANDLE) {
+        span_help_and_lint(
+            cx,
+            DETACHED_THREAD,
+            span,
+            "the `JoinHandle` of this thread is dropped, detaching it",
+            "consider joining the thread, or binding the handle to a named variable to document that it is detached",
+        );
+    }
+}
@@ -5,7 +5,8 @@ use rustc::{declare_lint, lint_array};
 use syntax::ast;
 use syntax::codemap::{BytePos, Span};
 use syntax_pos::Pos;
-use crate::utils::span_lint;
+use syntax::visit::{walk_expr, Visitor};
+use crate::utils::{in_macro, is_expn_of, span_lint, span_note_and_lint};
 use url::Url;
 
 /// **What it does:** Checks for the presence of `_`, `::` or camel-case words
@@ -32,33 +33,339 @@ declare_clippy_lint! {
     "presence of `_`, `::` or camel-case outside backticks in documentation"
 }
 
+/// **What it does:** Checks the doc comments of publicly visible functions that
+/// return a `Result` type and warns if there is no `# Errors` section.
+///
+/// **Why is this bad?** Documenting the type of errors that can be returned from a
+/// function can help callers write code to handle the errors appropriately.
+///
+/// **Known problems:** The return type is only checked by its name, so aliases
+/// of `Result` that are named differently are not linted.
+///
+/// **Examples:**
+///
+/// Since the following function returns a `Result` it has an `# Errors` section in
+/// its doc comment:
+///
+/// ```rust
+/// # use std::io;
+/// /// # Errors
+/// ///
+/// /// Will return `Err` if `filename` does not exist or the user does not have
+/// /// permission to read it.
+/// pub fn read(filename: String) -> io::Result<String> {
+///     unimplemented!();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_ERRORS_DOC,
+    pedantic,
+    "`pub fn` returns `Result` without `# Errors` in doc comment"
+}
+
+/// **What it does:** Checks the doc comments of publicly visible functions that
+/// may panic and warns if there is no `# Panics` section.
+///
+/// **Why is this bad?** Documenting the scenarios in which panicking occurs
+/// can help callers who do not want to panic to avoid those situations.
+///
+/// **Known problems:** The body is only searched for `panic!` (and the macros
+/// expanding to it, like `assert!`), `unwrap`, `expect` and indexing, so calls
+/// to other panicking functions are missed. `debug_assert!`s are ignored if the
+/// `missing-panics-doc-ignore-debug-assert` option is set.
+///
+/// **Examples:**
+///
+/// Since the following function may panic it has a `# Panics` section in
+/// its doc comment:
+///
+/// ```rust
+/// /// # Panics
+/// ///
+/// /// Will panic if y is 0
+/// pub fn divide_by(x: i32, y: i32) -> i32 {
+///     if y == 0 {
+///         panic!("Cannot divide by 0")
+///     } else {
+///         x / y
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_PANICS_DOC,
+    pedantic,
+    "`pub fn` may panic without `# Panics` in doc comment"
+}
+
+/// **What it does:** Checks for the doc comments of publicly visible
+/// unsafe functions and traits and warns if there is no `# Safety` section.
+///
+/// **Why is this bad?** Unsafe functions and traits should document their
+/// safety preconditions, so that users can be sure they are using them safely.
+///
+/// **Known problems:** None.
+///
+/// **Examples:**
+/// ```rust
+///# type Universe = ();
+/// /// This function should really be documented
+/// pub unsafe fn start_apocalypse(u: &mut Universe) {
+///     unimplemented!();
+/// }
+/// ```
+///
+/// At least write a line about safety:
+///
+/// ```rust
+///# type Universe = ();
+/// /// # Safety
+/// ///
+/// /// This function should not be called before the horsemen are ready.
+/// pub unsafe fn start_apocalypse(u: &mut Universe) {
+///     unimplemented!();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_SAFETY_DOC,
+    style,
+    "`pub unsafe fn` without `# Safety` docs"
+}
+
+/// **What it does:** Checks for the doc comments of publicly visible
+/// safe functions and traits and warns if there is a `# Safety` section.
+///
+/// **Why is this bad?** Safe functions and traits are safe to use by definition,
+/// so a `# Safety` section is most likely left over from an earlier, unsafe
+/// version of the item and confuses the reader.
+///
+/// **Known problems:** None.
+///
+/// **Examples:**
+/// ```rust
+///# type Universe = ();
+/// /// # Safety
+/// ///
+/// /// This function should not be called before the horsemen are ready.
+/// pub fn start_apocalypse_but_safely(u: &mut Universe) {
+///     unimplemented!();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_SAFETY_DOC,
+    restriction,
+    "`pub fn` or `pub trait` with `# Safety` docs"
+}
+
 #[derive(Clone)]
 pub struct Doc {
     valid_idents: Vec<String>,
+    ignore_debug_assert: bool,
 }
 
 impl Doc {
-    pub fn new(valid_idents: Vec<String>) -> Self {
+    pub fn new(valid_idents: Vec<String>, ignore_debug_assert: bool) -> Self {
         Self {
             valid_idents,
+            ignore_debug_assert,
+        }
+    }
+
+    fn lint_for_missing_headers(
+        &self,
+        cx: &EarlyContext,
+        decl: &ast::FnDecl,
+        body: &ast::Block,
+        unsafety: ast::Unsafety,
+        span: Span,
+        headers: Option<DocHeaders>,
+    ) {
+        // undocumented items are the business of `missing_docs`
+        let headers = match headers {
+            Some(headers) => headers,
+            None => return,
+        };
+        if in_macro(span) {
+            return;
+        }
+        match (unsafety, headers.safety) {
+            (ast::Unsafety::Unsafe, false) => span_lint(
+                cx,
+                MISSING_SAFETY_DOC,
+                span,
+                "unsafe function's docs miss `# Safety` section",
+            ),
+            (ast::Unsafety::Normal, true) => span_lint(
+                cx,
+                UNNECESSARY_SAFETY_DOC,
+                span,
+                "safe function's docs have unnecessary `# Safety` section",
+            ),
+            _ => (),
+        }
+        if !headers.errors && returns_result(decl) {
+            span_lint(
+                cx,
+                MISSING_ERRORS_DOC,
+                span,
+                "docs for function returning `Result` missing `# Errors` section",
+            );
+        }
+        if !headers.panics {
+            let mut visitor = FindPanic {
+                ignore_debug_assert: self.ignore_debug_assert,
+                panic_span: None,
+            };
+            visitor.visit_block(body);
+            if let Some(panic_span) = visitor.panic_span {
+                span_note_and_lint(
+                    cx,
+                    MISSING_PANICS_DOC,
+                    span,
+                    "docs for function which may panic missing `# Panics` section",
+                    panic_span,
+                    "first possible panic found here",
+                );
+            }
         }
     }
 }
 
 impl LintPass for Doc {
     fn get_lints(&self) -> LintArray {
-        lint_array![DOC_MARKDOWN]
+        lint_array![
+            DOC_MARKDOWN,
+            MISSING_ERRORS_DOC,
+            MISSING_PANICS_DOC,
+            MISSING_SAFETY_DOC,
+            UNNECESSARY_SAFETY_DOC,
+        ]
     }
 }
 
 impl EarlyLintPass for Doc {
     fn check_crate(&mut self, cx: &EarlyContext, krate: &ast::Crate) {
-        check_attrs(cx, &self.valid_idents, &krate.attrs);
+        check_attrs(cx, &self.valid_idents, &krate.attrs, true);
     }
 
     fn check_item(&mut self, cx: &EarlyContext, item: &ast::Item) {
-        check_attrs(cx, &self.valid_idents, &item.attrs);
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs, true);
+        match item.node {
+            ast::ItemKind::Fn(ref decl, ref header, _, ref body) => if is_public(&item.vis) {
+                self.lint_for_missing_headers(cx, decl, body, header.unsafety, item.span, headers);
+            },
+            ast::ItemKind::Trait(_, unsafety, ..) => if is_public(&item.vis) {
+                lint_for_trait_headers(cx, unsafety, item.span, headers);
+            },
+            // trait implementations are documented by the trait
+            ast::ItemKind::Impl(_, _, _, _, None, _, ref impl_items) => for impl_item in impl_items {
+                if let ast::ImplItemKind::Method(ref sig, ref body) = impl_item.node {
+                    // only the headers are needed, `doc_markdown` doesn't check impl items
+                    let headers = check_attrs(cx, &self.valid_idents, &impl_item.attrs, false);
+                    if is_public(&impl_item.vis) {
+                        self.lint_for_missing_headers(
+                            cx,
+                            &sig.decl,
+                            body,
+                            sig.header.unsafety,
+                            impl_item.span,
+                            headers,
+                        );
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+fn is_public(vis: &ast::Visibility) -> bool {
+    match vis.node {
+        ast::VisibilityKind::Public => true,
+        _ => false,
+    }
+}
+
+fn lint_for_trait_headers(cx: &EarlyContext, unsafety: ast::Unsafety, span: Span, headers: Option<DocHeaders>) {
+    let headers = match headers {
+        Some(headers) => headers,
+        None => return,
+    };
+    if in_macro(span) {
+        return;
+    }
+    match (unsafety, headers.safety) {
+        (ast::Unsafety::Unsafe, false) => span_lint(
+            cx,
+            MISSING_SAFETY_DOC,
+            span,
+            "docs for unsafe trait missing `# Safety` section",
+        ),
+        (ast::Unsafety::Normal, true) => span_lint(
+            cx,
+            UNNECESSARY_SAFETY_DOC,
+            span,
+            "docs for safe trait have unnecessary `# Safety` section",
+        ),
+        _ => (),
+    }
+}
+
+/// Is the return type of this function named `Result`, e.g. `Result<T, E>` or
+/// `io::Result<T>`?
+fn returns_result(decl: &ast::FnDecl) -> bool {
+    if let ast::FunctionRetTy::Ty(ref ty) = decl.output {
+        if let ast::TyKind::Path(None, ref path) = ty.node {
+            return path.segments.last().map_or(false, |segment| segment.ident.name == "Result");
+        }
+    }
+    false
+}
+
+/// Finds the first expression of a function body that may panic.
+struct FindPanic {
+    ignore_debug_assert: bool,
+    panic_span: Option<Span>,
+}
+
+impl<'ast> Visitor<'ast> for FindPanic {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.panic_span.is_some() {
+            return;
+        }
+        if self.ignore_debug_assert && is_debug_assert(expr.span) {
+            return;
+        }
+        if is_expn_of(expr.span, "panic").is_some() {
+            self.panic_span = Some(expr.span.source_callsite());
+            return;
+        }
+        match expr.node {
+            ast::ExprKind::MethodCall(ref segment, _)
+                if segment.ident.name == "unwrap" || segment.ident.name == "expect" =>
+            {
+                self.panic_span = Some(expr.span);
+            },
+            ast::ExprKind::Index(..) => self.panic_span = Some(expr.span),
+            _ => walk_expr(self, expr),
+        }
     }
+
+    // nested items are checked on their own
+    fn visit_item(&mut self, _: &'ast ast::Item) {}
+}
+
+fn is_debug_assert(span: Span) -> bool {
+    ["debug_assert", "debug_assert_eq", "debug_assert_ne"]
+        .iter()
+        .any(|name| is_expn_of(span, name).is_some())
+}
+
+/// The sections of a doc comment that are required for some kinds of items.
+#[derive(Copy, Clone)]
+struct DocHeaders {
+    safety: bool,
+    errors: bool,
+    panics: bool,
 }
 
 struct Parser<'a> {
@@ -139,7 +446,15 @@ pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(
     panic!("not a doc-comment: {}", comment);
 }
 
-pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [ast::Attribute]) {
+/// Returns the sections of the documentation in `attrs`, or `None` if the item is not
+/// documented with sugared doc comments only. The documentation is linted for
+/// `doc_markdown` if `lint_markdown` is set.
+fn check_attrs<'a>(
+    cx: &EarlyContext,
+    valid_idents: &[String],
+    attrs: &'a [ast::Attribute],
+    lint_markdown: bool,
+) -> Option<DocHeaders> {
     let mut doc = String::new();
     let mut spans = vec![];
 
@@ -153,7 +468,7 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
             }
         } else if attr.name() == "doc" {
             // ignore mix of sugared and non-sugared doc
-            return;
+            return None;
         }
     }
 
@@ -164,25 +479,27 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
         current += offset_copy;
     }
 
-    if !doc.is_empty() {
-        let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
-        let parser = parser.coalesce(|x, y| {
-            use pulldown_cmark::Event::*;
-
-            let x_offset = x.0;
-            let y_offset = y.0;
-
-            match (x.1, y.1) {
-                (Text(x), Text(y)) => {
-                    let mut x = x.into_owned();
-                    x.push_str(&y);
-                    Ok((x_offset, Text(x.into())))
-                },
-                (x, y) => Err(((x_offset, x), (y_offset, y))),
-            }
-        });
-        check_doc(cx, valid_idents, parser, &spans);
+    if doc.is_empty() {
+        return None;
     }
+
+    let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
+    let parser = parser.coalesce(|x, y| {
+        use pulldown_cmark::Event::*;
+
+        let x_offset = x.0;
+        let y_offset = y.0;
+
+        match (x.1, y.1) {
+            (Text(x), Text(y)) => {
+                let mut x = x.into_owned();
+                x.push_str(&y);
+                Ok((x_offset, Text(x.into())))
+            },
+            (x, y) => Err(((x_offset, x), (y_offset, y))),
+        }
+    });
+    Some(check_doc(cx, valid_idents, parser, &spans, lint_markdown))
 }
 
 fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
@@ -190,12 +507,19 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
     valid_idents: &[String],
     docs: Events,
     spans: &[(usize, Span)],
-) {
+    lint_markdown: bool,
+) -> DocHeaders {
     use pulldown_cmark::Event::*;
     use pulldown_cmark::Tag::*;
 
+    let mut headers = DocHeaders {
+        safety: false,
+        errors: false,
+        panics: false,
+    };
     let mut in_code = false;
     let mut in_link = None;
+    let mut in_heading = false;
 
     for (offset, event) in docs {
         match event {
@@ -203,6 +527,8 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             End(CodeBlock(_)) | End(Code) => in_code = false,
             Start(Link(link, _)) => in_link = Some(link),
             End(Link(_, _)) => in_link = None,
+            Start(Header(_)) => in_heading = true,
+            End(Header(_)) => in_heading = false,
             Start(_tag) | End(_tag) => (),         // We don't care about other tags
             Html(_html) | InlineHtml(_html) => (), // HTML is weird, just ignore it
             SoftBreak | HardBreak => (),
@@ -214,7 +540,13 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
                     continue;
                 }
 
-                if !in_code {
+                if in_heading {
+                    headers.safety |= text.trim() == "Safety";
+                    headers.errors |= text.trim() == "Errors";
+                    headers.panics |= text.trim() == "Panics";
+                }
+
+                if !in_code && lint_markdown {
                     let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
                         Ok(o) => o,
                         Err(e) => e - 1,
@@ -230,6 +562,7 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             },
         }
     }
+    headers
 }
 
 fn check_text(cx: &EarlyContext, valid_idents: &[String], text: &str, span: Span) {
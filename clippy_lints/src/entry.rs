@@ -3,9 +3,10 @@ use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
+use rustc_errors::Applicability;
 use syntax::codemap::Span;
 use crate::utils::SpanlessEq;
-use crate::utils::{get_item_name, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
+use crate::utils::{get_item_name, match_type, paths, snippet, snippet_opt, span_lint_and_then, walk_ptrs_ty};
 
 /// **What it does:** Checks for uses of `contains_key` + `insert` on `HashMap`
 /// or `BTreeMap`.
@@ -26,6 +27,17 @@ use crate::utils::{get_item_name, match_type, paths, snippet, span_lint_and_then
 /// ```rust
 /// m.entry(k).or_insert(v);
 /// ```
+/// and if there is more code than the `insert`:
+/// ```rust
+/// if !m.contains_key(&k) { foo(); m.insert(k, v) } else { None };
+/// ```
+/// can be rewritten as:
+/// ```rust
+/// match m.entry(k) {
+///     Entry::Vacant(e) => { foo(); e.insert(v); None },
+///     Entry::Occupied(_) => { None },
+/// };
+/// ```
 declare_clippy_lint! {
     pub MAP_ENTRY,
     perf,
@@ -58,27 +70,49 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for HashMapLint {
 
                     let mut visitor = InsertVisitor {
                         cx,
-                        span: expr.span,
-                        ty,
                         map,
                         key,
                         sole_expr,
+                        insert: None,
                     };
 
                     walk_expr(&mut visitor, &**then_block);
+                    if let Some(insert) = visitor.insert {
+                        let entry = EntryIf {
+                            ty,
+                            map,
+                            key,
+                            insert,
+                            vacant: &**then_block,
+                            occupied: else_block.as_ref().map(|e| &**e),
+                            vacant_first: true,
+                        };
+                        entry.lint(cx, expr.span, sole_expr);
+                    }
                 }
             } else if let Some(ref else_block) = *else_block {
                 if let Some((ty, map, key)) = check_cond(cx, check) {
                     let mut visitor = InsertVisitor {
                         cx,
-                        span: expr.span,
-                        ty,
                         map,
                         key,
                         sole_expr: false,
+                        insert: None,
                     };
 
                     walk_expr(&mut visitor, else_block);
+                    if let Some(insert) = visitor.insert {
+                        let entry = EntryIf {
+                            ty,
+                            map,
+                            key,
+                            insert,
+                            vacant: &**else_block,
+                            occupied: Some(&**then_block),
+                            vacant_first: false,
+                        };
+                        entry.lint(cx, expr.span, false);
+                    }
                 }
             }
         }
@@ -113,17 +147,125 @@ fn check_cond<'a, 'tcx, 'b>(
     None
 }
 
+/// An `if` checking `contains_key`, with the `insert` call in one of its branches.
+struct EntryIf<'b> {
+    ty: &'static str,
+    map: &'b Expr,
+    key: &'b Expr,
+    /// the `insert` call
+    insert: &'b Expr,
+    /// the branch taken if the key is not in the map, which contains the `insert` call
+    vacant: &'b Expr,
+    /// the branch taken if the key is in the map
+    occupied: Option<&'b Expr>,
+    /// whether the `vacant` branch comes first in the `if`
+    vacant_first: bool,
+}
+
+impl<'b> EntryIf<'b> {
+    fn lint(&self, cx: &LateContext, span: Span, sole_expr: bool) {
+        let map = snippet(cx, self.map.span, "map");
+        let key = snippet(cx, self.key.span, "..");
+        span_lint_and_then(
+            cx,
+            MAP_ENTRY,
+            span,
+            &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty),
+            |db| {
+                let value = &self.insert_args()[2];
+                if sole_expr {
+                    let help = match value.node {
+                        ExprKind::Call(..) | ExprKind::MethodCall(..) => format!(
+                            "{}.entry({}).or_insert_with(|| {})",
+                            map,
+                            key,
+                            snippet(cx, value.span, "..")
+                        ),
+                        _ => format!("{}.entry({}).or_insert({})", map, key, snippet(cx, value.span, "..")),
+                    };
+
+                    db.span_suggestion(span, "consider using", help);
+                } else if let Some(vacant) = self.vacant_sugg(cx, value) {
+                    let entry = if self.ty == "BTreeMap" {
+                        "std::collections::btree_map::Entry"
+                    } else {
+                        "std::collections::hash_map::Entry"
+                    };
+                    let help = match self.occupied {
+                        None => format!("if let {}::Vacant(e) = {}.entry({}) {}", entry, map, key, vacant),
+                        Some(occupied) => {
+                            let vacant = format!("{}::Vacant(e) => {}", entry, vacant);
+                            let occupied = format!("{}::Occupied(_) => {}", entry, snippet(cx, occupied.span, ".."));
+                            let (first, second) = if self.vacant_first {
+                                (vacant, occupied)
+                            } else {
+                                (occupied, vacant)
+                            };
+                            format!("match {}.entry({}) {{ {}, {} }}", map, key, first, second)
+                        },
+                    };
+
+                    db.span_suggestion_with_applicability(span, "consider using", help, Applicability::MaybeIncorrect);
+                } else {
+                    db.span_suggestion(span, "consider using", format!("{}.entry({})", map, key));
+                }
+            },
+        );
+    }
+
+    fn insert_args(&self) -> &'b [Expr] {
+        match self.insert.node {
+            ExprKind::MethodCall(_, _, ref args) => args,
+            _ => unreachable!("the `insert` call is a method call"),
+        }
+    }
+
+    /// Rewrites the `vacant` branch to insert the value into the `VacantEntry` `e`. Only works if
+    /// the `insert` call is one of the statements or the tail of the branch.
+    fn vacant_sugg(&self, cx: &LateContext, value: &Expr) -> Option<String> {
+        let block = match self.vacant.node {
+            ExprKind::Block(ref block, _) => block,
+            _ => return None,
+        };
+        let is_stmt = block.stmts.iter().any(|stmt| match stmt.node {
+            StmtKind::Semi(ref expr, _) => expr.id == self.insert.id,
+            _ => false,
+        });
+        let is_tail = block.expr.as_ref().map_or(false, |expr| expr.id == self.insert.id);
+        let insert = if is_stmt {
+            format!("e.insert({})", snippet(cx, value.span, ".."))
+        } else if is_tail {
+            // the old value is always `None`
+            format!("e.insert({}); None", snippet(cx, value.span, ".."))
+        } else {
+            return None;
+        };
+
+        let (span, insert_span) = (self.vacant.span, self.insert.span);
+        if !span.contains(insert_span) {
+            return None;
+        }
+        let branch = snippet_opt(cx, span)?;
+        let lo = (insert_span.lo() - span.lo()).0 as usize;
+        let hi = (insert_span.hi() - span.lo()).0 as usize;
+        Some(format!("{}{}{}", branch.get(..lo)?, insert, branch.get(hi..)?))
+    }
+}
+
 struct InsertVisitor<'a, 'tcx: 'a, 'b> {
     cx: &'a LateContext<'a, 'tcx>,
-    span: Span,
-    ty: &'static str,
     map: &'b Expr,
     key: &'b Expr,
     sole_expr: bool,
+    /// the first matching `insert` call
+    insert: Option<&'tcx Expr>,
 }
 
 impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
     fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.insert.is_some() {
+            return;
+        }
         if_chain! {
             if let ExprKind::MethodCall(ref path, _, ref params) = expr.node;
             if params.len() == 3;
@@ -131,24 +273,8 @@ impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
             if get_item_name(self.cx, self.map) == get_item_name(self.cx, &params[0]);
             if SpanlessEq::new(self.cx).eq_expr(self.key, &params[1]);
             then {
-                span_lint_and_then(self.cx, MAP_ENTRY, self.span,
-                                   &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty), |db| {
-                    if self.sole_expr {
-                        let help = format!("{}.entry({}).or_insert({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."),
-                                           snippet(self.cx, params[2].span, ".."));
-
-                        db.span_suggestion(self.span, "consider using", help);
-                    }
-                    else {
-                        let help = format!("{}.entry({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."));
-
-                        db.span_suggestion(self.span, "consider using", help);
-                    }
-                });
+                self.insert = Some(expr);
+                return;
             }
         }
 
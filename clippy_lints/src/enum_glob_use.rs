@@ -2,16 +2,22 @@
 
 use rustc::hir::*;
 use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
 use syntax::ast::NodeId;
 use syntax::codemap::Span;
-use crate::utils::span_lint;
+use crate::utils::{snippet_opt, span_lint_and_then};
 
 /// **What it does:** Checks for `use Enum::*`.
 ///
 /// **Why is this bad?** It is usually better style to use the prefixed name of
-/// an enumeration variant, rather than importing variants.
+/// an enumeration variant, rather than importing variants. Glob imports of
+/// variants also silently bring new names into scope when variants are added.
+///
+/// Enums listed in the `enum-glob-use-whitelist` configuration option, by name
+/// or by path, are not linted.
 ///
 /// **Known problems:** Old-style enumerations that prefix the variants are
 /// still around.
@@ -26,7 +32,15 @@ declare_clippy_lint! {
     "use items that import all variants of an enum"
 }
 
-pub struct EnumGlobUse;
+pub struct EnumGlobUse {
+    whitelist: Vec<String>,
+}
+
+impl EnumGlobUse {
+    pub fn new(whitelist: Vec<String>) -> Self {
+        Self { whitelist }
+    }
+}
 
 impl LintPass for EnumGlobUse {
     fn get_lints(&self) -> LintArray {
@@ -49,14 +63,37 @@ impl EnumGlobUse {
             return; // re-exports are fine
         }
         if let ItemKind::Use(ref path, UseKind::Glob) = item.node {
-            if let Def::Enum(_) = path.def {
-                span_lint(
+            if let Def::Enum(def_id) = path.def {
+                if self.is_whitelisted(cx, def_id) {
+                    return;
+                }
+                span_lint_and_then(
                     cx,
                     ENUM_GLOB_USE,
                     item.span,
                     "don't use glob imports for enum variants",
+                    |db| {
+                        if let Some(snippet) = snippet_opt(cx, item.span) {
+                            if snippet.ends_with("::*;") {
+                                db.span_suggestion_with_applicability(
+                                    item.span,
+                                    "import the enum and qualify its variants instead",
+                                    format!("{};", &snippet[..snippet.len() - 4]),
+                                    Applicability::MaybeIncorrect,
+                                );
+                            }
+                        }
+                    },
                 );
             }
         }
     }
+
+    fn is_whitelisted(&self, cx: &LateContext, def_id: DefId) -> bool {
+        let path = cx.tcx.item_path_str(def_id);
+        let name = path.rsplit("::").next();
+        self.whitelist
+            .iter()
+            .any(|enum_name| *enum_name == path || Some(enum_name.as_str()) == name)
+    }
 }
@@ -57,7 +57,9 @@ declare_clippy_lint! {
 ///
 /// **Why is this bad?** It requires the user to type the module name twice.
 ///
-/// **Known problems:** None.
+/// **Known problems:** Some names are idiomatic despite repeating the module name, e.g.
+/// `TokenStream` in a `token` module. The rest of such names can be allowed with the
+/// `stutter-allowed-prefixes` and `stutter-allowed-suffixes` configuration options.
 ///
 /// **Example:**
 /// ```rust
@@ -104,13 +106,19 @@ declare_clippy_lint! {
 pub struct EnumVariantNames {
     modules: Vec<(LocalInternedString, String)>,
     threshold: u64,
+    /// the camel cased prefixes allowed in front of a module's name
+    allowed_prefixes: Vec<String>,
+    /// the camel cased suffixes allowed after a module's name
+    allowed_suffixes: Vec<String>,
 }
 
 impl EnumVariantNames {
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: u64, allowed_prefixes: Vec<String>, allowed_suffixes: Vec<String>) -> Self {
         Self {
             modules: Vec::new(),
             threshold,
+            allowed_prefixes: allowed_prefixes.iter().map(|prefix| to_camel_case(prefix)).collect(),
+            allowed_suffixes: allowed_suffixes.iter().map(|suffix| to_camel_case(suffix)).collect(),
         }
     }
 }
@@ -273,14 +281,18 @@ impl EarlyLintPass for EnumVariantNames {
                         };
 
                         if matching == nchars {
+                            let rest = &item_camel[mod_camel.len()..];
                             match item_camel.chars().nth(nchars) {
-                                Some(c) if is_word_beginning(c) =>
+                                Some(c) if is_word_beginning(c) && !self.allowed_suffixes.iter().any(|suffix| suffix == rest) =>
                                     span_lint(cx, STUTTER, item.span, "item name starts with its containing module's name"),
                                 _ => ()
                             }
                         }
                         if rmatching == nchars {
-                            span_lint(cx, STUTTER, item.span, "item name ends with its containing module's name");
+                            let rest = &item_camel[..item_camel.len() - mod_camel.len()];
+                            if !self.allowed_prefixes.iter().any(|prefix| prefix == rest) {
+                                span_lint(cx, STUTTER, item.span, "item name ends with its containing module's name");
+                            }
                         }
                     }
                 }
@@ -0,0 +1,185 @@
+use if_chain::if_chain;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for functions with too many `bool` (or `Option<bool>`)
+/// parameters.
+///
+/// **Why is this bad?** Calls like `draw(true, false, true)` are hard to read,
+/// and it is easy to mix up the arguments. Two-variant enums or a struct of
+/// named options make the call site self-documenting.
+///
+/// **Known problems:** Trait implementations can't change their signature, so
+/// they are not linted.
+///
+/// **Example:**
+/// ```rust,ignore
+/// fn f(is_round: bool, is_hot: bool, is_big: bool, is_red: bool) { ... }
+/// ```
+///
+/// Could be written as:
+///
+/// ```rust,ignore
+/// enum Shape { Round, Spiky }
+/// enum Temperature { Hot, Cold }
+/// enum Size { Big, Small }
+/// enum Color { Red, Blue }
+///
+/// fn f(shape: Shape, temperature: Temperature, size: Size, color: Color) { ... }
+/// ```
+declare_clippy_lint! {
+    pub FN_PARAMS_EXCESSIVE_BOOLS,
+    pedantic,
+    "using too many bools in function parameters"
+}
+
+/// **What it does:** Checks for structs with too many `bool` fields.
+///
+/// **Why is this bad?** Most of the time such structs encode a state machine,
+/// and many of the possible combinations of the fields are invalid states.
+/// An enum with one variant per state makes these unrepresentable.
+///
+/// **Known problems:** `#[repr(C)]` structs are not linted, as their layout is
+/// usually dictated by some foreign interface.
+///
+/// **Example:**
+/// ```rust
+/// struct S {
+///     is_pending: bool,
+///     is_processing: bool,
+///     is_finished: bool,
+///     is_failed: bool,
+/// }
+/// ```
+///
+/// Could be written as:
+///
+/// ```rust
+/// enum S {
+///     Pending,
+///     Processing,
+///     Finished,
+///     Failed,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub STRUCT_EXCESSIVE_BOOLS,
+    pedantic,
+    "using too many bools in a struct"
+}
+
+pub struct ExcessiveBools {
+    max_struct_bools: u64,
+    max_fn_params_bools: u64,
+}
+
+impl ExcessiveBools {
+    pub fn new(max_struct_bools: u64, max_fn_params_bools: u64) -> Self {
+        Self {
+            max_struct_bools,
+            max_fn_params_bools,
+        }
+    }
+
+    fn check_fn_decl(&self, cx: &EarlyContext, decl: &FnDecl, span: Span) {
+        if in_macro(span) {
+            return;
+        }
+
+        let bools = decl.inputs.iter().filter(|arg| is_bool_ty(&arg.ty)).count() as u64;
+        if bools > self.max_fn_params_bools {
+            span_help_and_lint(
+                cx,
+                FN_PARAMS_EXCESSIVE_BOOLS,
+                span,
+                &format!("more than {} bools in function parameters", self.max_fn_params_bools),
+                "consider refactoring bools into two-variant enums or a struct",
+            );
+        }
+    }
+}
+
+impl LintPass for ExcessiveBools {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STRUCT_EXCESSIVE_BOOLS, FN_PARAMS_EXCESSIVE_BOOLS)
+    }
+}
+
+impl EarlyLintPass for ExcessiveBools {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        match item.node {
+            ItemKind::Struct(ref variant_data, _) => {
+                if in_macro(item.span) || has_repr_c(&item.attrs) {
+                    return;
+                }
+
+                let bools = variant_data.fields().iter().filter(|field| is_bool(&field.ty)).count() as u64;
+                if bools > self.max_struct_bools {
+                    span_help_and_lint(
+                        cx,
+                        STRUCT_EXCESSIVE_BOOLS,
+                        item.span,
+                        &format!("more than {} bools in a struct", self.max_struct_bools),
+                        "consider using a state machine or refactoring bools into two-variant enums",
+                    );
+                }
+            },
+            ItemKind::Fn(ref decl, ..) => self.check_fn_decl(cx, decl, item.span),
+            ItemKind::Impl(_, _, _, _, None, _, ref items) => for item in items {
+                if let ImplItemKind::Method(ref sig, _) = item.node {
+                    self.check_fn_decl(cx, &sig.decl, item.span);
+                }
+            },
+            ItemKind::Trait(.., ref items) => for item in items {
+                if let TraitItemKind::Method(ref sig, _) = item.node {
+                    self.check_fn_decl(cx, &sig.decl, item.span);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+fn has_repr_c(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("repr")
+            && attr
+                .meta_item_list()
+                .map_or(false, |items| items.iter().any(|item| item.check_name("C")))
+    })
+}
+
+fn is_bool(ty: &Ty) -> bool {
+    if_chain! {
+        if let TyKind::Path(None, ref path) = ty.node;
+        if let Some(segment) = path.segments.last();
+        then {
+            return segment.ident.name == "bool" && segment.args.is_none();
+        }
+    }
+    false
+}
+
+/// Is this type `bool` or `Option<bool>`?
+fn is_bool_ty(ty: &Ty) -> bool {
+    if is_bool(ty) {
+        return true;
+    }
+    if_chain! {
+        if let TyKind::Path(None, ref path) = ty.node;
+        if let Some(segment) = path.segments.last();
+        if segment.ident.name == "Option";
+        if let Some(ref args) = segment.args;
+        if let GenericArgs::AngleBracketed(ref data) = **args;
+        if data.args.len() == 1;
+        if let GenericArg::Type(ref ty) = data.args[0];
+        then {
+            return is_bool(ty);
+        }
+    }
+    false
+}
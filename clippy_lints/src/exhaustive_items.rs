@@ -0,0 +1,122 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Attribute;
+use syntax::attr;
+
+use crate::utils::span_lint_and_then;
+use crate::utils::sugg::DiagnosticBuilderExt;
+
+/// **What it does:** Warns on any exported `enum`s that are not tagged `#[non_exhaustive]`
+///
+/// **Why is this bad?** Exhaustive enums are typically fine, but a project which does
+/// not wish to make a stability commitment around exported enums may wish to
+/// disable them by default.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// enum Foo {
+///     Bar,
+///     Baz
+/// }
+/// ```
+/// Use instead:
+/// ```rust,ignore
+/// #[non_exhaustive]
+/// enum Foo {
+///     Bar,
+///     Baz
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_ENUMS,
+    restriction,
+    "detects exported enums that have not been marked #[non_exhaustive]"
+}
+
+/// **What it does:** Warns on any exported `struct`s that are not tagged `#[non_exhaustive]`
+///
+/// **Why is this bad?** Exhaustive structs are typically fine, but a project which does
+/// not wish to make a stability commitment around exported structs may wish to
+/// disable them by default. Adding a field to a struct whose fields are all public is a
+/// breaking change, as downstream crates can construct and destructure it.
+///
+/// Structs with a private field, e.g. a private marker field, are not linted.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// struct Foo {
+///     bar: u8,
+///     baz: String,
+/// }
+/// ```
+/// Use instead:
+/// ```rust,ignore
+/// #[non_exhaustive]
+/// struct Foo {
+///     bar: u8,
+///     baz: String,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_STRUCTS,
+    restriction,
+    "detects exported structs that have not been marked #[non_exhaustive]"
+}
+
+pub struct ExhaustiveItems;
+
+impl LintPass for ExhaustiveItems {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExhaustiveItems {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if !cx.access_levels.is_exported(item.id) || attr::contains_name(&item.attrs, "non_exhaustive") {
+            return;
+        }
+        let (lint, msg, help) = match item.node {
+            // a hidden variant prevents downstream crates from matching exhaustively
+            ItemKind::Enum(ref def, _) if !def.variants.iter().any(|variant| is_doc_hidden(&variant.node.attrs)) => {
+                (EXHAUSTIVE_ENUMS, "exported enums should not be exhaustive", None)
+            },
+            // a private field prevents downstream crates from constructing the struct
+            ItemKind::Struct(ref data, _) if data.fields().iter().all(|field| field.vis.node.is_pub()) => (
+                EXHAUSTIVE_STRUCTS,
+                "exported structs should not be exhaustive",
+                Some("or add a private field to prevent construction outside of this crate"),
+            ),
+            _ => return,
+        };
+        span_lint_and_then(cx, lint, item.span, msg, |db| {
+            db.suggest_item_with_attr(
+                cx,
+                item.span,
+                "try adding #[non_exhaustive], which needs `#![feature(non_exhaustive)]`",
+                "#[non_exhaustive]",
+                Applicability::MaybeIncorrect,
+            );
+            if let Some(help) = help {
+                db.help(help);
+            }
+        });
+    }
+}
+
+fn is_doc_hidden(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("doc")
+            && attr
+                .meta_item_list()
+                .map_or(false, |items| attr::list_contains_name(&items, "hidden"))
+    })
+}
@@ -0,0 +1,63 @@
+use if_chain::if_chain;
+use rustc::hir::map::Node::NodeItem;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::CRATE_NODE_ID;
+
+use crate::utils::{match_def_path, opt_def_id, paths, resolve_node, span_help_and_lint};
+
+/// **What it does:** `exit()` terminates the program and doesn't provide a
+/// stack trace.
+///
+/// **Why is this bad?** Ideally a program is terminated by finishing
+/// the main function. `exit()` skips the destructors of everything that is
+/// still alive and makes the calling code impossible to test.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```ignore
+/// std::process::exit(0)
+/// ```
+declare_clippy_lint! {
+    pub EXIT,
+    restriction,
+    "`std::process::exit` is called, terminating the program"
+}
+
+pub struct Exit;
+
+impl LintPass for Exit {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Exit {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref path_expr, _) = e.node;
+            if let ExprKind::Path(ref path) = path_expr.node;
+            if let Some(def_id) = opt_def_id(resolve_node(cx, path, path_expr.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::EXIT);
+            then {
+                let parent = cx.tcx.hir.get_parent(e.id);
+                if let Some(NodeItem(&Item { name, node: ItemKind::Fn(..), .. })) = cx.tcx.hir.find(parent) {
+                    // If the next item up is a function we check if it is an entry point
+                    // and only then emit a linter warning
+                    if name == "main" && cx.tcx.hir.get_parent(parent) == CRATE_NODE_ID {
+                        return;
+                    }
+                }
+                span_help_and_lint(
+                    cx,
+                    EXIT,
+                    e.span,
+                    "usage of `process::exit`",
+                    "consider returning an error instead",
+                );
+            }
+        }
+    }
+}
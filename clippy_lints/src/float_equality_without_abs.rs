@@ -0,0 +1,96 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{in_macro, match_def_path, paths, snippet, span_lint_and_then};
+
+/// **What it does:** Checks for comparisons of the difference of two floats against an epsilon,
+/// like `(a - b) < f32::EPSILON`, that are missing the `.abs()` call.
+///
+/// **Why is this bad?** The comparison is meant to check that `a` and `b` are (almost) equal,
+/// but without `.abs()` it is also true for any `a` that is smaller than `b`, no matter how much.
+///
+/// **Known problems:** Besides `std::f32::EPSILON` and `std::f64::EPSILON`, any constant whose
+/// name ends with `EPSILON` is taken as an epsilon.
+///
+/// **Example:**
+///
+/// ```rust
+/// pub fn is_roughly_equal(a: f32, b: f32) -> bool {
+///     (a - b) < std::f32::EPSILON
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// pub fn is_roughly_equal(a: f32, b: f32) -> bool {
+///     (a - b).abs() < std::f32::EPSILON
+/// }
+/// ```
+declare_clippy_lint! {
+    pub FLOAT_EQUALITY_WITHOUT_ABS,
+    correctness,
+    "float equality check without `.abs()`"
+}
+
+pub struct FloatEqualityWithoutAbs;
+
+impl LintPass for FloatEqualityWithoutAbs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FLOAT_EQUALITY_WITHOUT_ABS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FloatEqualityWithoutAbs {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (diff, epsilon) = match expr.node {
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Lt => (lhs, rhs),
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Gt => (rhs, lhs),
+            _ => return,
+        };
+
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Binary(op, ref a, ref b) = diff.node;
+            if op.node == BinOpKind::Sub;
+            if cx.tables.expr_ty(a).is_floating_point() && cx.tables.expr_ty(b).is_floating_point();
+            if is_epsilon(cx, epsilon);
+            then {
+                span_lint_and_then(
+                    cx,
+                    FLOAT_EQUALITY_WITHOUT_ABS,
+                    expr.span,
+                    "float equality check without `.abs()`",
+                    |db| {
+                        // the span of `diff` includes the parentheses around it, if there are any
+                        let sub = snippet(cx, a.span.to(b.span), "..");
+                        db.span_suggestion_with_applicability(
+                            diff.span,
+                            "add `.abs()`",
+                            format!("({}).abs()", sub),
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Is `expr` the path of `std::f32::EPSILON`, `std::f64::EPSILON` or a constant whose name ends
+/// with `EPSILON`?
+fn is_epsilon(cx: &LateContext, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = expr.node;
+        if let Def::Const(def_id) | Def::AssociatedConst(def_id) = cx.tables.qpath_def(qpath, expr.hir_id);
+        then {
+            match_def_path(cx.tcx, def_id, &paths::F32_EPSILON)
+                || match_def_path(cx.tcx, def_id, &paths::F64_EPSILON)
+                || cx.tcx.item_name(def_id).as_str().ends_with("EPSILON")
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use std::f64::consts::E;
+use syntax::ast;
+
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::{self, Sugg};
+use crate::utils::{get_parent_expr, in_constant, in_macro, snippet, span_help_and_lint, span_lint_and_sugg};
+
+/// **What it does:** Looks for floating point expressions that lose precision compared to one of
+/// the methods of `f32` and `f64`.
+///
+/// **Why is this bad?** The naive expressions lose a lot of precision for arguments close to
+/// zero, e.g. `(1.0 + x).ln()` rounds `1.0 + x` before taking the logarithm. The dedicated
+/// methods are accurate over the whole range.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let a = 3f32;
+/// let _ = (1.0 + a).ln();
+/// let _ = a.exp() - 1.0;
+/// let _ = a.powf(0.5);
+/// ```
+///
+/// is better expressed as
+///
+/// ```rust
+/// let a = 3f32;
+/// let _ = a.ln_1p();
+/// let _ = a.exp_m1();
+/// let _ = a.sqrt();
+/// ```
+declare_clippy_lint! {
+    pub IMPRECISE_FLOPS,
+    nursery,
+    "usage of imprecise floating point operations"
+}
+
+/// **What it does:** Looks for floating point expressions that can be computed faster or more
+/// accurately with one of the methods of `f32` and `f64`.
+///
+/// **Why is this bad?** These methods are implemented with dedicated instructions or algorithms
+/// that avoid the intermediate rounding of the plain expression, e.g. `a.mul_add(b, c)` rounds
+/// once instead of twice.
+///
+/// **Known problems:** The faster methods are not always faster on every target. `mul_add` in
+/// particular is slower than `a * b + c` on targets without a fused multiply-add instruction.
+/// `x.powi(2)` is only linted if `x` is a path, as `x * x` evaluates it twice. There is no method
+/// for the reciprocal square root, so `1.0 / x.sqrt()` is linted without a suggestion.
+///
+/// **Example:**
+///
+/// ```rust
+/// let a = 3f32;
+/// let _ = a.powi(2);
+/// let _ = a.log(std::f32::consts::E);
+/// let _ = a * 2.0 + 4.0;
+/// ```
+///
+/// is better expressed as
+///
+/// ```rust
+/// let a = 3f32;
+/// let _ = a * a;
+/// let _ = a.ln();
+/// let _ = a.mul_add(2.0, 4.0);
+/// ```
+declare_clippy_lint! {
+    pub SUBOPTIMAL_FLOPS,
+    nursery,
+    "usage of sub-optimal floating point operations"
+}
+
+pub struct FloatingPointArithmetic;
+
+impl LintPass for FloatingPointArithmetic {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPRECISE_FLOPS, SUBOPTIMAL_FLOPS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FloatingPointArithmetic {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprKind::MethodCall(ref path, _, ref args) if cx.tables.expr_ty(&args[0]).is_floating_point() => {
+                match &*path.ident.as_str() {
+                    "ln" => check_ln1p(cx, expr, &args[0]),
+                    "log" if args.len() == 2 => check_log_base(cx, expr, args),
+                    "powf" if args.len() == 2 => check_powf(cx, expr, args),
+                    "powi" if args.len() == 2 => check_powi(cx, expr, args),
+                    _ => (),
+                }
+            },
+            ExprKind::Binary(ref op, ref lhs, ref rhs) if cx.tables.expr_ty(expr).is_floating_point() => match op.node {
+                BinOpKind::Add => check_mul_add(cx, expr, lhs, rhs),
+                BinOpKind::Sub => check_expm1(cx, expr, lhs, rhs),
+                BinOpKind::Div => check_rsqrt(cx, expr, lhs, rhs),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Is `expr` a float constant equal to `value`?
+#[allow(float_cmp)]
+fn is_float_const(cx: &LateContext, expr: &Expr, value: f64) -> bool {
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::F32(f), _)) => f == value as f32,
+        Some((Constant::F64(f), _)) => f == value,
+        _ => false,
+    }
+}
+
+/// The receiver of a suggested method call. An unsuffixed float literal gets the type suffix, as
+/// the type of `2.0` in `2.0.exp2()` would be ambiguous.
+fn method_receiver(cx: &LateContext, expr: &Expr) -> String {
+    if is_unsuffixed_float_lit(expr) {
+        let lit = format!("{}{}", snippet(cx, expr.span, ".."), cx.tables.expr_ty(expr));
+        if let ExprKind::Unary(..) = expr.node {
+            return format!("({})", lit);
+        }
+        return lit;
+    }
+    Sugg::hir(cx, expr, "..").maybe_par().to_string()
+}
+
+/// Is `expr` a float literal without a type suffix, possibly negated?
+fn is_unsuffixed_float_lit(expr: &Expr) -> bool {
+    let lit = match expr.node {
+        ExprKind::Unary(UnNeg, ref inner) => inner,
+        _ => expr,
+    };
+    match lit.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            ast::LitKind::FloatUnsuffixed(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn lint_flop(cx: &LateContext, lint: &'static Lint, expr: &Expr, msg: &str, sugg: String) {
+    // the float methods can't be called in constants
+    if in_constant(cx, expr.id) {
+        return;
+    }
+    span_lint_and_sugg(cx, lint, expr.span, msg, "consider using", sugg);
+}
+
+/// `x.log(2.0)`, `x.log(10.0)` and `x.log(E)`
+fn check_log_base(cx: &LateContext, expr: &Expr, args: &[Expr]) {
+    let method = if is_float_const(cx, &args[1], 2.0) {
+        "log2"
+    } else if is_float_const(cx, &args[1], 10.0) {
+        "log10"
+    } else if is_float_const(cx, &args[1], E) {
+        "ln"
+    } else {
+        return;
+    };
+    lint_flop(
+        cx,
+        SUBOPTIMAL_FLOPS,
+        expr,
+        "logarithm for bases 2, 10 and e can be computed more accurately",
+        format!("{}.{}()", snippet(cx, args[0].span, ".."), method),
+    );
+}
+
+/// `(1.0 + x).ln()` and `(x + 1.0).ln()`
+fn check_ln1p(cx: &LateContext, expr: &Expr, recv: &Expr) {
+    if let ExprKind::Binary(ref op, ref lhs, ref rhs) = recv.node {
+        if op.node != BinOpKind::Add {
+            return;
+        }
+        let x = if is_float_const(cx, lhs, 1.0) {
+            rhs
+        } else if is_float_const(cx, rhs, 1.0) {
+            lhs
+        } else {
+            return;
+        };
+        lint_flop(
+            cx,
+            IMPRECISE_FLOPS,
+            expr,
+            "ln(1 + x) can be computed more accurately",
+            format!("{}.ln_1p()", Sugg::hir(cx, x, "..").maybe_par()),
+        );
+    }
+}
+
+/// `2.0.powf(x)`, `E.powf(x)`, `x.powf(0.5)` and `x.powf(n)` with an integer `n`
+fn check_powf(cx: &LateContext, expr: &Expr, args: &[Expr]) {
+    let (recv, exp) = (&args[0], &args[1]);
+    let (lint, msg, sugg) = if is_float_const(cx, recv, 2.0) {
+        (
+            SUBOPTIMAL_FLOPS,
+            "exponent for bases 2 and e can be computed more accurately",
+            format!("{}.exp2()", method_receiver(cx, exp)),
+        )
+    } else if is_float_const(cx, recv, E) {
+        (
+            SUBOPTIMAL_FLOPS,
+            "exponent for bases 2 and e can be computed more accurately",
+            format!("{}.exp()", method_receiver(cx, exp)),
+        )
+    } else if is_float_const(cx, exp, 0.5) {
+        (
+            IMPRECISE_FLOPS,
+            "square-root of a number can be computed more accurately",
+            format!("{}.sqrt()", snippet(cx, recv.span, "..")),
+        )
+    } else {
+        let n = match constant(cx, cx.tables, exp) {
+            Some((Constant::F32(f), _)) => f64::from(f),
+            Some((Constant::F64(f), _)) => f,
+            _ => return,
+        };
+        if n.fract() != 0.0 || n < f64::from(i32::min_value()) || n > f64::from(i32::max_value()) {
+            return;
+        }
+        (
+            SUBOPTIMAL_FLOPS,
+            "exponentiation with integer powers can be computed more efficiently",
+            format!("{}.powi({})", snippet(cx, recv.span, ".."), n as i32),
+        )
+    };
+    lint_flop(cx, lint, expr, msg, sugg);
+}
+
+/// `x.powi(2)`, only for a path `x` that is cheap to repeat
+fn check_powi(cx: &LateContext, expr: &Expr, args: &[Expr]) {
+    if_chain! {
+        if let Some((Constant::Int(2), _)) = constant(cx, cx.tables, &args[1]);
+        if let ExprKind::Path(_) = args[0].node;
+        then {
+            let x = Sugg::hir(cx, &args[0], "..");
+            let square = sugg::make_binop(ast::BinOpKind::Mul, &x, &x);
+            // `y / x.powi(2)` must not become `y / x * x`
+            let needs_par = get_parent_expr(cx, expr).map_or(false, |parent| match parent.node {
+                ExprKind::Binary(..) | ExprKind::Unary(..) | ExprKind::Cast(..) | ExprKind::MethodCall(..) => true,
+                _ => false,
+            });
+            let square = if needs_par { square.maybe_par() } else { square };
+            lint_flop(
+                cx,
+                SUBOPTIMAL_FLOPS,
+                expr,
+                "square can be computed more efficiently",
+                square.to_string(),
+            );
+        }
+    }
+}
+
+/// `x.exp() - 1.0`
+fn check_expm1(cx: &LateContext, expr: &Expr, lhs: &Expr, rhs: &Expr) {
+    if let ExprKind::MethodCall(ref path, _, ref args) = lhs.node {
+        if path.ident.name == "exp"
+            && args.len() == 1
+            && cx.tables.expr_ty(&args[0]).is_floating_point()
+            && is_float_const(cx, rhs, 1.0)
+        {
+            lint_flop(
+                cx,
+                IMPRECISE_FLOPS,
+                expr,
+                "(e.pow(x) - 1) can be computed more accurately",
+                format!("{}.exp_m1()", snippet(cx, args[0].span, "..")),
+            );
+        }
+    }
+}
+
+/// `a * b + c` and `c + a * b`
+fn check_mul_add(cx: &LateContext, expr: &Expr, lhs: &Expr, rhs: &Expr) {
+    let (mul, add) = match (&lhs.node, &rhs.node) {
+        (&ExprKind::Binary(ref op, ..), _) if op.node == BinOpKind::Mul => (lhs, rhs),
+        (_, &ExprKind::Binary(ref op, ..)) if op.node == BinOpKind::Mul => (rhs, lhs),
+        _ => return,
+    };
+    if let ExprKind::Binary(_, ref a, ref b) = mul.node {
+        if !cx.tables.expr_ty(a).is_floating_point() || !cx.tables.expr_ty(b).is_floating_point() {
+            return;
+        }
+        // prefer a receiver that needs no type suffix
+        let (a, b) = if is_unsuffixed_float_lit(a) && !is_unsuffixed_float_lit(b) {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        lint_flop(
+            cx,
+            SUBOPTIMAL_FLOPS,
+            expr,
+            "multiply and add expressions can be calculated more efficiently and accurately",
+            format!(
+                "{}.mul_add({}, {})",
+                method_receiver(cx, a),
+                Sugg::hir(cx, b, ".."),
+                Sugg::hir(cx, add, "..")
+            ),
+        );
+    }
+}
+
+/// `1.0 / x.sqrt()`
+fn check_rsqrt(cx: &LateContext, expr: &Expr, lhs: &Expr, rhs: &Expr) {
+    if_chain! {
+        if is_float_const(cx, lhs, 1.0);
+        if let ExprKind::MethodCall(ref path, _, ref args) = rhs.node;
+        if path.ident.name == "sqrt" && args.len() == 1;
+        if cx.tables.expr_ty(&args[0]).is_floating_point();
+        if !in_constant(cx, expr.id);
+        then {
+            span_help_and_lint(
+                cx,
+                SUBOPTIMAL_FLOPS,
+                expr.span,
+                "reciprocal square root computed with a square root and a division",
+                "if full precision is not needed, an approximation of the reciprocal square root is faster",
+            );
+        }
+    }
+}
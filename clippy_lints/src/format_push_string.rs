@@ -0,0 +1,73 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_macro, is_expn_of, match_type, paths, span_help_and_lint, walk_ptrs_ty};
+
+/// **What it does:** Detects cases where the result of a `format!` call is
+/// appended to an existing `String`.
+///
+/// **Why is this bad?** Introduces an extra, avoidable heap allocation.
+/// Formatting into the `String` with `write!` appends to the existing buffer.
+///
+/// **Known problems:** `write!` needs `std::fmt::Write` to be imported, and
+/// returns a `fmt::Result` which has to be handled, even though writing to a
+/// `String` can't fail.
+///
+/// **Example:**
+/// ```rust
+/// let mut s = String::new();
+/// s += &format!("0x{:X}", 1024);
+/// s.push_str(&format!("0x{:X}", 1024));
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::fmt::Write;
+/// let mut s = String::new();
+/// let _ = write!(s, "0x{:X}", 1024);
+/// ```
+declare_clippy_lint! {
+    pub FORMAT_PUSH_STRING,
+    pedantic,
+    "`format!(..)` appended to existing `String`"
+}
+
+pub struct FormatPushString;
+
+impl LintPass for FormatPushString {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FORMAT_PUSH_STRING)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FormatPushString {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (string, arg) = match expr.node {
+            ExprKind::MethodCall(ref method, _, ref args) if method.ident.name == "push_str" && args.len() == 2 => {
+                (&args[0], &args[1])
+            },
+            ExprKind::AssignOp(op, ref lhs, ref rhs) if op.node == BinOpKind::Add => (&**lhs, &**rhs),
+            _ => return,
+        };
+        if in_macro(expr.span) || !match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(string)), &paths::STRING) {
+            return;
+        }
+        if is_format(arg) {
+            span_help_and_lint(
+                cx,
+                FORMAT_PUSH_STRING,
+                expr.span,
+                "`format!(..)` appended to existing `String`",
+                "consider using `write!` to avoid the extra allocation",
+            );
+        }
+    }
+}
+
+/// Is `expr` a (borrowed) `format!` call?
+fn is_format(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::AddrOf(_, ref inner) => is_format(inner),
+        _ => is_expn_of(expr.span, "format").map_or(false, |span| !in_macro(span)),
+    }
+}
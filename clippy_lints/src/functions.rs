@@ -5,11 +5,13 @@ use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
 use rustc::ty;
 use rustc::hir::def::Def;
+use rustc_errors::Applicability;
 use std::collections::HashSet;
 use syntax::ast;
 use rustc_target::spec::abi::Abi;
 use syntax::codemap::Span;
-use crate::utils::{iter_input_pats, span_lint, type_is_unsafe_function};
+use crate::utils::{in_external_macro, iter_input_pats, snippet, snippet_opt, span_help_and_lint, span_lint,
+                   span_lint_and_then, type_is_unsafe_function};
 
 /// **What it does:** Checks for functions with too many parameters.
 ///
@@ -56,22 +58,157 @@ declare_clippy_lint! {
     "public functions dereferencing raw pointer arguments but not marked `unsafe`"
 }
 
+/// **What it does:** Checks for functions with a large amount of lines.
+///
+/// **Why is this bad?** Functions with a lot of lines are harder to understand
+/// due to having to look at a larger amount of code to understand what the
+/// function is doing. Consider splitting the body of the function into
+/// multiple functions.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn im_too_long() {
+///     println!("");
+///     // ... 100 more LoC
+///     println!("");
+/// }
+/// ```
+declare_clippy_lint! {
+    pub TOO_MANY_LINES,
+    pedantic,
+    "functions with too many lines"
+}
+
+/// **What it does:** Checks for public functions that have no
+/// `#[must_use]` attribute, but return something not already covered by
+/// `#[must_use]`, have no mutable arg and mutate no statics.
+///
+/// **Why is this bad?** Not bad at all, this lint just shows places where
+/// you could add the attribute.
+///
+/// **Known problems:** The lint only checks the arguments for mutable
+/// types without looking if they are actually changed. On the other hand,
+/// it also ignores a broad range of potentially interesting side effects,
+/// because we cannot decide whether the programmer intends the function to
+/// be called for the side effect or the result. Expect many false
+/// positives. At least we don't lint if the result type is unit or already
+/// `#[must_use]`.
+///
+/// **Example:**
+/// ```rust
+/// // this could be annotated with `#[must_use]`.
+/// pub fn id<T>(t: T) -> T { t }
+/// ```
+declare_clippy_lint! {
+    pub MUST_USE_CANDIDATE,
+    pedantic,
+    "function or method that could take a `#[must_use]` attribute"
+}
+
+/// **What it does:** Checks for public methods that take `self` and return
+/// `Self` by value (e.g. builder-style methods), but are not marked
+/// `#[must_use]`.
+///
+/// **Why is this bad?** Such methods usually don't mutate `self` but return
+/// a modified copy, so calling them without using the result is almost
+/// always a mistake.
+///
+/// **Known problems:** Trait implementations are not linted, as the attribute
+/// would have to be added to the trait definition.
+///
+/// **Example:**
+/// ```rust
+/// pub struct Bar;
+///
+/// impl Bar {
+///     // Bad
+///     pub fn bar(self) -> Self {
+///         Self
+///     }
+///
+///     // Good
+///     #[must_use]
+///     pub fn foo(self) -> Self {
+///         Self
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub RETURN_SELF_NOT_MUST_USE,
+    pedantic,
+    "missing `#[must_use]` annotation on a method returning `Self`"
+}
+
+/// **What it does:** Checks for a `#[must_use]` attribute on
+/// unit-returning functions and methods.
+///
+/// **Why is this bad?** Unit values are useless. The attribute is likely
+/// a remnant of a refactoring that removed the return type.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[must_use]
+/// fn useless() { }
+/// ```
+declare_clippy_lint! {
+    pub MUST_USE_UNIT,
+    style,
+    "`#[must_use]` attribute on a unit-returning function / method"
+}
+
+/// **What it does:** Checks for a `#[must_use]` attribute without
+/// further information on functions and methods that return a type already
+/// marked as `#[must_use]`.
+///
+/// **Why is this bad?** The attribute isn't needed. Not using the result
+/// will already be reported. Alternatively, one can add some text to the
+/// attribute to improve the lint message.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[must_use]
+/// fn double_must_use() -> Result<(), ()> {
+///     unimplemented!();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DOUBLE_MUST_USE,
+    style,
+    "`#[must_use]` attribute on a `#[must_use]`-returning function / method"
+}
+
 #[derive(Copy, Clone)]
 pub struct Functions {
     threshold: u64,
+    max_lines: u64,
 }
 
 impl Functions {
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: u64, max_lines: u64) -> Self {
         Self {
             threshold,
+            max_lines,
         }
     }
 }
 
 impl LintPass for Functions {
     fn get_lints(&self) -> LintArray {
-        lint_array!(TOO_MANY_ARGUMENTS, NOT_UNSAFE_PTR_ARG_DEREF)
+        lint_array!(
+            TOO_MANY_ARGUMENTS,
+            TOO_MANY_LINES,
+            NOT_UNSAFE_PTR_ARG_DEREF,
+            MUST_USE_CANDIDATE,
+            MUST_USE_UNIT,
+            DOUBLE_MUST_USE,
+            RETURN_SELF_NOT_MUST_USE,
+        )
     }
 }
 
@@ -110,6 +247,46 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
         }
 
         self.check_raw_ptr(cx, unsafety, decl, body, nodeid);
+        self.check_line_number(cx, span, body);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
+        if let hir::ItemKind::Fn(ref decl, _, _, body_id) = item.node {
+            if let Some(attr) = must_use_attr(&item.attrs) {
+                check_needless_must_use(cx, decl, item.span, item.id, attr);
+                return;
+            }
+            if cx.access_levels.is_exported(item.id) {
+                check_must_use_candidate(
+                    cx,
+                    decl,
+                    cx.tcx.hir.body(body_id),
+                    item.span,
+                    item.id,
+                    "this function could have a `#[must_use]` attribute",
+                );
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
+        if let hir::ImplItemKind::Method(ref sig, body_id) = item.node {
+            if let Some(attr) = must_use_attr(&item.attrs) {
+                check_needless_must_use(cx, &sig.decl, item.span, item.id, attr);
+                return;
+            }
+            if cx.access_levels.is_exported(item.id) && !is_trait_impl_item(cx, item.id) {
+                check_must_use_candidate(
+                    cx,
+                    &sig.decl,
+                    cx.tcx.hir.body(body_id),
+                    item.span,
+                    item.id,
+                    "this method could have a `#[must_use]` attribute",
+                );
+                check_return_self_not_must_use(cx, &sig.decl, item.span, item.id);
+            }
+        }
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
@@ -119,9 +296,25 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
                 self.check_arg_number(cx, &sig.decl, item.span);
             }
 
+            let attr = must_use_attr(&item.attrs);
+            if let Some(attr) = attr {
+                check_needless_must_use(cx, &sig.decl, item.span, item.id, attr);
+            }
+
             if let hir::TraitMethod::Provided(eid) = *eid {
                 let body = cx.tcx.hir.body(eid);
                 self.check_raw_ptr(cx, sig.header.unsafety, &sig.decl, body, item.id);
+
+                if attr.is_none() && cx.access_levels.is_exported(item.id) {
+                    check_must_use_candidate(
+                        cx,
+                        &sig.decl,
+                        body,
+                        item.span,
+                        item.id,
+                        "this method could have a `#[must_use]` attribute",
+                    );
+                }
             }
         }
     }
@@ -140,6 +333,65 @@ impl<'a, 'tcx> Functions {
         }
     }
 
+    fn check_line_number(self, cx: &LateContext, span: Span, body: &'tcx hir::Body) {
+        if in_external_macro(cx, span) {
+            return;
+        }
+
+        let code_snippet = snippet(cx, body.value.span, "..");
+        let mut line_count: u64 = 0;
+        let mut in_comment = false;
+        let mut code_in_line;
+
+        // skip the surrounding braces of the function body
+        let start_brace_idx = code_snippet.find('{').map_or(0, |i| i + 1);
+        let end_brace_idx = code_snippet.rfind('}').unwrap_or_else(|| code_snippet.len());
+        let function_lines = code_snippet[start_brace_idx..end_brace_idx].lines();
+
+        for mut line in function_lines {
+            code_in_line = false;
+            loop {
+                line = line.trim_left();
+                if line.is_empty() {
+                    break;
+                }
+                if in_comment {
+                    match line.find("*/") {
+                        Some(i) => {
+                            line = &line[i + 2..];
+                            in_comment = false;
+                            continue;
+                        },
+                        None => break,
+                    }
+                } else {
+                    let multi_idx = line.find("/*").unwrap_or_else(|| line.len());
+                    let single_idx = line.find("//").unwrap_or_else(|| line.len());
+                    code_in_line |= multi_idx > 0 && single_idx > 0;
+                    // implies `multi_idx` is below `line.len()`
+                    if multi_idx < single_idx {
+                        line = &line[multi_idx + 2..];
+                        in_comment = true;
+                        continue;
+                    }
+                    break;
+                }
+            }
+            if code_in_line {
+                line_count += 1;
+            }
+        }
+
+        if line_count > self.max_lines {
+            span_lint(
+                cx,
+                TOO_MANY_LINES,
+                span,
+                &format!("this function has a large number of lines ({}/{})", line_count, self.max_lines),
+            );
+        }
+    }
+
     fn check_raw_ptr(
         self,
         cx: &LateContext<'a, 'tcx>,
@@ -232,3 +484,208 @@ impl<'a, 'tcx: 'a> DerefVisitor<'a, 'tcx> {
         }
     }
 }
+
+fn must_use_attr(attrs: &[ast::Attribute]) -> Option<&ast::Attribute> {
+    attrs.iter().find(|attr| attr.check_name("must_use"))
+}
+
+fn is_trait_impl_item(cx: &LateContext, id: ast::NodeId) -> bool {
+    use rustc::hir::map::Node::*;
+
+    if let Some(NodeItem(item)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(id)) {
+        matches!(item.node, hir::ItemKind::Impl(_, _, _, _, Some(_), _, _))
+    } else {
+        false
+    }
+}
+
+fn check_needless_must_use(
+    cx: &LateContext,
+    decl: &hir::FnDecl,
+    item_span: Span,
+    item_id: ast::NodeId,
+    attr: &ast::Attribute,
+) {
+    if in_external_macro(cx, item_span) {
+        return;
+    }
+
+    let sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(item_id));
+    let ret_ty = sig.skip_binder().output();
+    let fn_span = item_span.with_hi(decl.output.span().hi());
+    if is_unit(ret_ty) {
+        span_lint_and_then(
+            cx,
+            MUST_USE_UNIT,
+            fn_span,
+            "this unit-returning function has a `#[must_use]` attribute",
+            |db| {
+                db.span_suggestion_with_applicability(
+                    attr.span,
+                    "remove the attribute",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    } else if attr.value_str().is_none() && is_must_use_ty(cx, ret_ty) {
+        span_help_and_lint(
+            cx,
+            DOUBLE_MUST_USE,
+            fn_span,
+            "this function has an empty `#[must_use]` attribute, but returns a type already marked as `#[must_use]`",
+            "either add some descriptive text or remove the attribute",
+        );
+    }
+}
+
+fn check_must_use_candidate<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    decl: &'tcx hir::FnDecl,
+    body: &'tcx hir::Body,
+    item_span: Span,
+    item_id: ast::NodeId,
+    msg: &str,
+) {
+    if in_external_macro(cx, item_span) {
+        return;
+    }
+
+    let sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(item_id));
+    let sig = sig.skip_binder();
+    if is_unit_or_never(sig.output())
+        || is_must_use_ty(cx, sig.output())
+        || sig.inputs().iter().any(|ty| is_mutable_ty(ty))
+        || mutates_static(cx, body)
+    {
+        return;
+    }
+
+    let fn_span = item_span.with_hi(decl.output.span().hi());
+    span_lint_and_then(cx, MUST_USE_CANDIDATE, fn_span, msg, |db| {
+        if let Some(snippet) = snippet_opt(cx, fn_span) {
+            db.span_suggestion_with_applicability(
+                fn_span,
+                "add the attribute",
+                format!("#[must_use] {}", snippet),
+                Applicability::MachineApplicable,
+            );
+        }
+    });
+}
+
+fn check_return_self_not_must_use(cx: &LateContext, decl: &hir::FnDecl, item_span: Span, item_id: ast::NodeId) {
+    if in_external_macro(cx, item_span) {
+        return;
+    }
+
+    let def_id = cx.tcx.hir.local_def_id(item_id);
+    if !cx.tcx.associated_item(def_id).method_has_self_argument {
+        return;
+    }
+
+    let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(cx.tcx.hir.get_parent(item_id)));
+    let ret_ty = cx.tcx.fn_sig(def_id).skip_binder().output();
+    if ret_ty == self_ty && !is_must_use_ty(cx, ret_ty) {
+        span_help_and_lint(
+            cx,
+            RETURN_SELF_NOT_MUST_USE,
+            item_span.with_hi(decl.output.span().hi()),
+            "missing `#[must_use]` attribute on a method returning `Self`",
+            "consider adding the `#[must_use]` attribute to the method or directly to the `Self` type",
+        );
+    }
+}
+
+fn is_unit(ty: ty::Ty) -> bool {
+    match ty.sty {
+        ty::TyTuple(slice) => slice.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_unit_or_never(ty: ty::Ty) -> bool {
+    match ty.sty {
+        ty::TyTuple(slice) => slice.is_empty(),
+        ty::TyNever => true,
+        _ => false,
+    }
+}
+
+fn is_must_use_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>) -> bool {
+    match ty.sty {
+        ty::TyAdt(adt, _) => cx.tcx.has_attr(adt.did, "must_use"),
+        ty::TyForeign(did) => cx.tcx.has_attr(did, "must_use"),
+        ty::TySlice(ty) | ty::TyArray(ty, _) | ty::TyRawPtr(ty::TypeAndMut { ty, .. }) | ty::TyRef(_, ty, _) => {
+            // for the Array case we don't need to care for the len == 0 case
+            // because we don't want to lint functions returning empty arrays
+            is_must_use_ty(cx, ty)
+        },
+        ty::TyTuple(slice) => slice.iter().any(|ty| is_must_use_ty(cx, ty)),
+        _ => false,
+    }
+}
+
+/// Does the type contain a mutable reference or pointer?
+fn is_mutable_ty(ty: ty::Ty) -> bool {
+    ty.walk().any(|t| match t.sty {
+        ty::TyRef(_, _, hir::MutMutable) | ty::TyRawPtr(ty::TypeAndMut { mutbl: hir::MutMutable, .. }) => true,
+        _ => false,
+    })
+}
+
+/// Does the body use a `static mut` or mutate anything but its own locals?
+fn mutates_static<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, body: &'tcx hir::Body) -> bool {
+    let mut v = StaticMutVisitor {
+        cx,
+        tables: cx.tcx.body_tables(body.id()),
+        mutates_static: false,
+    };
+    intravisit::walk_expr(&mut v, &body.value);
+    v.mutates_static
+}
+
+struct StaticMutVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'a ty::TypeckTables<'tcx>,
+    mutates_static: bool,
+}
+
+impl<'a, 'tcx> hir::intravisit::Visitor<'tcx> for StaticMutVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.mutates_static {
+            return;
+        }
+        match expr.node {
+            hir::ExprKind::Assign(ref target, _)
+            | hir::ExprKind::AssignOp(_, ref target, _)
+            | hir::ExprKind::AddrOf(hir::MutMutable, ref target) => {
+                self.mutates_static |= is_mutated_static(target);
+            },
+            hir::ExprKind::Call(_, ref args) | hir::ExprKind::MethodCall(_, _, ref args) => {
+                // catches `&mut self` receivers that were borrowed automatically
+                self.mutates_static |= args
+                    .iter()
+                    .any(|arg| is_mutable_ty(self.tables.expr_ty_adjusted(arg)) && is_mutated_static(arg));
+            },
+            hir::ExprKind::InlineAsm(..) => self.mutates_static = true,
+            _ => (),
+        }
+
+        hir::intravisit::walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> intravisit::NestedVisitorMap<'this, 'tcx> {
+        intravisit::NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
+
+/// Is the expression a place that lives outside of the function (i.e. is
+/// rooted in a `static`)?
+fn is_mutated_static(expr: &hir::Expr) -> bool {
+    match expr.node {
+        hir::ExprKind::Path(hir::QPath::Resolved(_, ref path)) => !matches!(path.def, Def::Local(_) | Def::Upvar(..)),
+        hir::ExprKind::Path(_) => true,
+        hir::ExprKind::Field(ref base, _) | hir::ExprKind::Index(ref base, _) => is_mutated_static(base),
+        _ => false,
+    }
+}
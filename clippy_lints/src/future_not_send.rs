@@ -0,0 +1,156 @@
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, paths, return_ty, same_tys,
+                   span_lint_and_then};
+
+/// **What it does:** Checks for public `async fn`s whose returned future doesn't implement
+/// `Send`.
+///
+/// **Why is this bad?** Multi-threaded executors require spawned futures to be `Send`. The
+/// compiler only reports a future that isn't `Send` where it is spawned, which may be far away
+/// from the value that causes it, or even in another crate.
+///
+/// **Known problems:** Only the arguments of the function and the values bound with `let` in its
+/// body are pointed out as the cause.
+///
+/// **Example:**
+/// ```rust,ignore
+/// pub async fn foo(x: Rc<u32>) -> u32 {
+///     await!(bar());
+///     *x
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// pub async fn foo(x: Arc<u32>) -> u32 {
+///     await!(bar());
+///     *x
+/// }
+/// ```
+declare_clippy_lint! {
+    pub FUTURE_NOT_SEND,
+    nursery,
+    "public `async fn`s whose future is not `Send`"
+}
+
+pub struct FutureNotSend;
+
+impl LintPass for FutureNotSend {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FUTURE_NOT_SEND)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FutureNotSend {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        let header = match kind {
+            FnKind::ItemFn(_, _, header, ..) => header,
+            FnKind::Method(_, sig, ..) => sig.header,
+            FnKind::Closure(..) => return,
+        };
+        if header.asyncness != IsAsync::Async || in_macro(span) || !cx.access_levels.is_exported(node_id) {
+            return;
+        }
+        let send_trait = match get_trait_def_id(cx, &paths::SEND) {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        let is_send = |ty: Ty<'tcx>| implements_trait(cx, ty, send_trait, &[]);
+        if is_send(return_ty(cx, node_id)) {
+            return;
+        }
+
+        let mut visitor = GeneratorVisitor {
+            cx,
+            interior: None,
+            locals: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+        let is_held = |ty: Ty<'tcx>| {
+            visitor
+                .interior
+                .map_or(false, |interior| interior.iter().any(|&held| same_tys(cx, ty, held)))
+        };
+
+        span_lint_and_then(
+            cx,
+            FUTURE_NOT_SEND,
+            span.with_hi(decl.output.span().hi()),
+            "future cannot be sent between threads safely",
+            |db| {
+                // the arguments are moved into the future
+                for arg in &body.arguments {
+                    let ty = cx.tables.pat_ty(&arg.pat);
+                    if !is_send(ty) {
+                        db.span_note(arg.pat.span, &format!("captured value of type `{}` is not `Send`", ty));
+                    }
+                }
+                for local in &visitor.locals {
+                    let ty = cx.tables.pat_ty(&local.pat);
+                    if !is_send(ty) && is_held(ty) {
+                        db.span_note(
+                            local.pat.span,
+                            &format!("value of type `{}` is held across an `await!` point and is not `Send`", ty),
+                        );
+                    }
+                }
+            },
+        );
+    }
+}
+
+/// Finds the generator the body of an `async fn` is lowered to, and collects the `let`
+/// bindings in it. Nested closures and `async` blocks are not searched.
+struct GeneratorVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// the types that are live across a suspension point of the generator
+    interior: Option<&'tcx ty::Slice<Ty<'tcx>>>,
+    locals: Vec<&'tcx Local>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for GeneratorVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Closure(_, _, body_id, _, movability) = expr.node {
+            if_chain! {
+                if self.interior.is_none();
+                if movability.is_some();
+                if let ty::TyGenerator(def_id, substs, _) = self.cx.tables.expr_ty(expr).sty;
+                if let ty::TyGeneratorWitness(interior) = substs.witness(def_id, self.cx.tcx).sty;
+                then {
+                    self.interior = Some(*interior.skip_binder());
+                    let body = self.cx.tcx.hir.body(body_id);
+                    self.visit_expr(&body.value);
+                }
+            }
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_local(&mut self, local: &'tcx Local) {
+        self.locals.push(local);
+        if let Some(ref init) = local.init {
+            self.visit_expr(init);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
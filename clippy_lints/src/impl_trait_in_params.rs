@@ -0,0 +1,133 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::codemap::Span;
+use syntax::visit::{walk_ty, Visitor};
+use syntax_pos::BytePos;
+
+use crate::utils::{in_macro, multispan_sugg_with_applicability, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Lints when `impl Trait` is being used in a function's parameters.
+///
+/// **Why is this bad?** Turbofish syntax (`::<>`) cannot be used when `impl Trait` is being
+/// used, so callers of public functions can't name the type, and changing the parameter to a
+/// named generic later is a breaking change.
+///
+/// **Known problems:** Only public functions and methods are linted.
+///
+/// **Example:**
+/// ```rust
+/// trait MyTrait {}
+/// pub fn foo(x: impl MyTrait) {
+///     // [...]
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// trait MyTrait {}
+/// pub fn foo<T: MyTrait>(x: T) {
+///     // [...]
+/// }
+/// ```
+declare_clippy_lint! {
+    pub IMPL_TRAIT_IN_PARAMS,
+    restriction,
+    "`impl Trait` is used in the function's parameters"
+}
+
+pub struct ImplTraitInParams;
+
+impl LintPass for ImplTraitInParams {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPL_TRAIT_IN_PARAMS)
+    }
+}
+
+impl EarlyLintPass for ImplTraitInParams {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if let ItemKind::Fn(ref decl, _, ref generics, _) = item.node {
+            if item.vis.node.is_pub() && !in_macro(item.span) {
+                check_fn_decl(cx, item.ident, decl, generics);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext, item: &ImplItem) {
+        // methods of trait implementations are never `pub`
+        if let ImplItemKind::Method(ref sig, _) = item.node {
+            if item.vis.node.is_pub() && !in_macro(item.span) {
+                check_fn_decl(cx, item.ident, &sig.decl, &item.generics);
+            }
+        }
+    }
+}
+
+fn check_fn_decl(cx: &EarlyContext, ident: Ident, decl: &FnDecl, generics: &Generics) {
+    let mut visitor = ImplTraitVisitor { spans: Vec::new() };
+    for arg in &decl.inputs {
+        visitor.visit_ty(&arg.ty);
+    }
+    if let Some(&first) = visitor.spans.first() {
+        span_lint_and_then(
+            cx,
+            IMPL_TRAIT_IN_PARAMS,
+            first,
+            "`impl Trait` used as a function parameter",
+            |db| {
+                if let Some(sugg) = suggestion(cx, ident, generics, &visitor.spans) {
+                    multispan_sugg_with_applicability(
+                        db,
+                        "add a type parameter".to_string(),
+                        // the new names may already be used by items in scope
+                        Applicability::MaybeIncorrect,
+                        sugg,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Replaces each `impl Trait` with a new type parameter with the same bounds.
+fn suggestion(cx: &EarlyContext, ident: Ident, generics: &Generics, spans: &[Span]) -> Option<Vec<(Span, String)>> {
+    let mut names = (0..).map(|i| if i == 0 { "T".to_string() } else { format!("T{}", i) }).filter(|name| {
+        !generics.params.iter().any(|param| param.ident.name == name.as_str())
+    });
+
+    let mut params = Vec::new();
+    let mut sugg = Vec::new();
+    for &span in spans {
+        let snippet = snippet_opt(cx, span)?;
+        let bounds = snippet.trim_left_matches("impl").trim();
+        let name = names.next()?;
+        params.push(format!("{}: {}", name, bounds));
+        sugg.push((span, name));
+    }
+
+    let params = params.join(", ");
+    if generics.params.is_empty() {
+        sugg.push((ident.span.shrink_to_hi(), format!("<{}>", params)));
+    } else {
+        // insert the new parameters before the closing `>`
+        let hi = generics.span.hi() - BytePos(1);
+        sugg.push((generics.span.with_lo(hi).with_hi(hi), format!(", {}", params)));
+    }
+    Some(sugg)
+}
+
+/// Collects the spans of the `impl Trait` types.
+struct ImplTraitVisitor {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visitor<'a> for ImplTraitVisitor {
+    fn visit_ty(&mut self, ty: &'a Ty) {
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.spans.push(ty.span);
+        } else {
+            walk_ty(self, ty);
+        }
+    }
+}
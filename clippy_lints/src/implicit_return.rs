@@ -0,0 +1,136 @@
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for missing return statements at the end of a block.
+///
+/// **Why is this bad?** Actually omitting the return keyword is idiomatic Rust code. Programmers
+/// coming from other languages might prefer the expressiveness of `return`. It's possible to miss
+/// the last returning statement because the only difference is a missing `;`. Especially in bigger
+/// code with multiple return paths having a `return` keyword makes it easier to find the
+/// corresponding statements.
+///
+/// **Known problems:** Expressions that come from macro expansions are not linted.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(x: usize) -> usize {
+///     x
+/// }
+/// ```
+/// add return
+/// ```rust
+/// fn foo(x: usize) -> usize {
+///     return x;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub IMPLICIT_RETURN,
+    restriction,
+    "use a return statement like `return expr` instead of an expression"
+}
+
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPLICIT_RETURN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        // functions returning `()` have nothing to return
+        if let FunctionRetTy::DefaultReturn(_) = decl.output {
+            return;
+        }
+        if in_macro(span) {
+            return;
+        }
+        expr_match(cx, &body.value);
+    }
+}
+
+fn lint(cx: &LateContext, outer_span: Span, inner_span: Span, msg: &str) {
+    span_lint_and_then(cx, IMPLICIT_RETURN, outer_span, "missing return statement", |db| {
+        if let Some(snippet) = snippet_opt(cx, inner_span) {
+            db.span_suggestion_with_applicability(
+                outer_span,
+                msg,
+                format!("return {}", snippet),
+                Applicability::MachineApplicable,
+            );
+        }
+    });
+}
+
+/// Lints the expressions that make up the value of `expr`.
+fn expr_match(cx: &LateContext, expr: &Expr) {
+    if in_macro(expr.span) {
+        return;
+    }
+    match expr.node {
+        ExprKind::Block(ref block, _) => if let Some(ref expr) = block.expr {
+            expr_match(cx, expr);
+        },
+        ExprKind::If(_, ref then, Some(ref els)) => {
+            expr_match(cx, then);
+            expr_match(cx, els);
+        },
+        ExprKind::Match(_, ref arms, MatchSource::Normal)
+        | ExprKind::Match(_, ref arms, MatchSource::IfLetDesugar { .. }) => for arm in arms {
+            expr_match(cx, &arm.body);
+        },
+        // the value of a loop is given by its `break`s
+        ExprKind::Loop(ref block, _, LoopSource::Loop) => {
+            let mut visitor = BreakVisitor {
+                loop_id: expr.id,
+                breaks: Vec::new(),
+            };
+            visitor.visit_block(block);
+            for (break_span, value_span) in visitor.breaks {
+                lint(cx, break_span, value_span, "change `break` to `return` as shown");
+            }
+        },
+        ExprKind::Ret(..) => (),
+        _ => lint(cx, expr.span, expr.span, "add `return` as shown"),
+    }
+}
+
+/// Collects the `break`s with a value of one loop.
+struct BreakVisitor {
+    loop_id: NodeId,
+    breaks: Vec<(Span, Span)>,
+}
+
+impl<'tcx> Visitor<'tcx> for BreakVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Break(ref destination, Some(ref value)) = expr.node {
+            if destination.target_id == Ok(self.loop_id) && !in_macro(expr.span) {
+                self.breaks.push((expr.span, value.span));
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -6,12 +6,14 @@ use rustc::{declare_lint, lint_array};
 use std::collections::HashMap;
 use std::default::Default;
 use syntax_pos::Span;
+use crate::utils::in_macro;
 
 /// **What it does:** Checks for multiple inherent implementations of a struct
 ///
 /// **Why is this bad?** Splitting the implementation of a type makes the code harder to navigate.
 ///
-/// **Known problems:** None.
+/// **Known problems:** Implementations generated by macros are not linted, as they often
+/// can't be merged with the other implementations.
 ///
 /// **Example:**
 /// ```rust
@@ -57,6 +59,9 @@ impl LintPass for Pass {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_item(&mut self, _: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
         if let ItemKind::Impl(_, _, _, ref generics, None, _, _) = item.node {
             // Remember for each inherent implementation encoutered its span and generics
             self.impls
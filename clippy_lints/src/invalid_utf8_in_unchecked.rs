@@ -0,0 +1,119 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+
+use crate::consts::{constant, Constant};
+use crate::utils::{higher, match_def_path, opt_def_id, paths, span_lint};
+
+/// **What it does:** Checks for `std::str::from_utf8_unchecked` and
+/// `String::from_utf8_unchecked` calls with a constant argument that is not valid UTF-8.
+///
+/// **Why is this bad?** Creating a `str` or a `String` that isn't valid UTF-8 is undefined
+/// behavior.
+///
+/// **Known problems:** Only arguments that can be evaluated as constants are checked, i.e. byte
+/// string literals, arrays of literals, `vec!`s of them and constants.
+///
+/// **Example:**
+/// ```rust
+/// unsafe {
+///     std::str::from_utf8_unchecked(b"cl\x82ippy");
+/// }
+/// ```
+declare_clippy_lint! {
+    pub INVALID_UTF8_IN_UNCHECKED,
+    correctness,
+    "using a non UTF-8 literal in `std::str::from_utf8_unchecked`"
+}
+
+pub struct InvalidUtf8InUnchecked;
+
+impl LintPass for InvalidUtf8InUnchecked {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INVALID_UTF8_IN_UNCHECKED)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for InvalidUtf8InUnchecked {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if args.len() == 1;
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::STR_FROM_UTF8_UNCHECKED)
+                || match_def_path(cx.tcx, def_id, &paths::STRING_FROM_UTF8_UNCHECKED);
+            if let Some(bytes) = constant_bytes(cx, &args[0]);
+            if !bytes.is_utf8();
+            then {
+                span_lint(
+                    cx,
+                    INVALID_UTF8_IN_UNCHECKED,
+                    expr.span,
+                    "non UTF-8 literal in `from_utf8_unchecked`",
+                );
+            }
+        }
+    }
+}
+
+/// Constant bytes. Repeats are kept as they are, as their length is up to the user.
+enum Bytes {
+    List(Vec<u8>),
+    Repeat(u8, u128),
+}
+
+impl Bytes {
+    fn is_utf8(&self) -> bool {
+        match *self {
+            Bytes::List(ref bytes) => std::str::from_utf8(bytes).is_ok(),
+            // a repeated byte is valid on its own, or not at all
+            Bytes::Repeat(byte, len) => len == 0 || byte < 0x80,
+        }
+    }
+}
+
+/// Evaluates `expr` to bytes, looking through `&`, `vec!` and `to_vec()`.
+fn constant_bytes(cx: &LateContext, expr: &Expr) -> Option<Bytes> {
+    if let Some(vec_args) = higher::vec_macro(cx, expr) {
+        return match vec_args {
+            higher::VecArgs::Vec(elems) => elems
+                .iter()
+                .map(|elem| constant_byte(cx, elem))
+                .collect::<Option<_>>()
+                .map(Bytes::List),
+            higher::VecArgs::Repeat(elem, len) => match constant(cx, cx.tables, len) {
+                Some((Constant::Int(len), _)) => Some(Bytes::Repeat(constant_byte(cx, elem)?, len)),
+                _ => None,
+            },
+        };
+    }
+    match expr.node {
+        ExprKind::AddrOf(_, ref inner) => constant_bytes(cx, inner),
+        ExprKind::MethodCall(ref method, _, ref args) if method.ident.name == "to_vec" => constant_bytes(cx, &args[0]),
+        _ => match constant(cx, cx.tables, expr)?.0 {
+            Constant::Binary(bytes) => Some(Bytes::List((*bytes).clone())),
+            Constant::Vec(elems) => elems
+                .into_iter()
+                .map(|elem| match elem {
+                    Constant::Int(byte) => Some(byte as u8),
+                    _ => None,
+                })
+                .collect::<Option<_>>()
+                .map(Bytes::List),
+            Constant::Repeat(elem, len) => match *elem {
+                Constant::Int(byte) => Some(Bytes::Repeat(byte as u8, u128::from(len))),
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+}
+
+fn constant_byte(cx: &LateContext, expr: &Expr) -> Option<u8> {
+    match constant(cx, cx.tables, expr) {
+        Some((Constant::Int(byte), _)) => Some(byte as u8),
+        _ => None,
+    }
+}
@@ -0,0 +1,89 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{in_macro, is_direct_expn_of, snippet, span_lint_and_then};
+
+/// **What it does:** Checks for large futures that are awaited with `await!`.
+///
+/// **Why is this bad?** The state of an awaited future becomes part of the state of the awaiting
+/// future, so large futures make every future that awaits them large as well, and are copied
+/// around on the stack when they are moved. Boxing them with `PinBox::new` keeps the awaiting
+/// future small.
+///
+/// **Known problems:** The size is only known for futures whose type doesn't depend on generic
+/// parameters. The threshold is set with the `future-size-threshold` configuration option.
+///
+/// **Example:**
+/// ```rust,ignore
+/// async fn large_future(_x: [u8; 16 * 1024]) {}
+///
+/// pub async fn trigger() {
+///     await!(large_future([0u8; 16 * 1024]));
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// async fn large_future(_x: [u8; 16 * 1024]) {}
+///
+/// pub async fn trigger() {
+///     await!(std::boxed::PinBox::new(large_future([0u8; 16 * 1024])));
+/// }
+/// ```
+declare_clippy_lint! {
+    pub LARGE_FUTURES,
+    pedantic,
+    "large futures that are awaited without being boxed"
+}
+
+pub struct LargeFutures {
+    future_size_threshold: u64,
+}
+
+impl LargeFutures {
+    pub fn new(future_size_threshold: u64) -> Self {
+        Self { future_size_threshold }
+    }
+}
+
+impl LintPass for LargeFutures {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_FUTURES)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeFutures {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        // `await!` binds the awaited future to a local before polling it
+        if_chain! {
+            if is_direct_expn_of(local.span, "await").is_some();
+            if let Some(ref future) = local.init;
+            if !in_macro(future.span);
+            let ty = cx.tables.expr_ty(future);
+            // the layouts of `async fn`s' futures are only known once their types are revealed
+            if let Ok(layout) = cx.tcx.layout_of(cx.param_env.with_reveal_all().and(ty));
+            let size = layout.size.bytes();
+            if size > self.future_size_threshold;
+            then {
+                span_lint_and_then(
+                    cx,
+                    LARGE_FUTURES,
+                    future.span,
+                    &format!("large future with a size of {} bytes", size),
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            future.span,
+                            "consider `PinBox::new` on it",
+                            format!("std::boxed::PinBox::new({})", snippet(cx, future.span, "..")),
+                            // `PinBox` needs `#![feature(pin)]`
+                            Applicability::MaybeIncorrect,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
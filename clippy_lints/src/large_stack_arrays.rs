@@ -0,0 +1,74 @@
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::ty::layout::LayoutOf;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_constant, in_macro, snippet, span_help_and_lint};
+
+/// **What it does:** Checks for local arrays that may be too large.
+///
+/// **Why is this bad?** Large local arrays may cause stack overflow, especially in debug builds
+/// and deep call chains.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// let a = [0u32; 1_000_000];
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// let a = vec![0u32; 1_000_000].into_boxed_slice();
+/// ```
+declare_clippy_lint! {
+    pub LARGE_STACK_ARRAYS,
+    pedantic,
+    "allocating large arrays on stack may cause stack overflow"
+}
+
+pub struct LargeStackArrays {
+    maximum_allowed_size: u64,
+}
+
+impl LargeStackArrays {
+    pub fn new(maximum_allowed_size: u64) -> Self {
+        Self { maximum_allowed_size }
+    }
+}
+
+impl LintPass for LargeStackArrays {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_STACK_ARRAYS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeStackArrays {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if matches!(expr.node, ExprKind::Repeat(..) | ExprKind::Array(..));
+            if !in_macro(expr.span);
+            let ty = cx.tables.expr_ty(expr);
+            if let ty::TyArray(..) = ty.sty;
+            if let Ok(layout) = cx.layout_of(ty);
+            if layout.size.bytes() > self.maximum_allowed_size;
+            // constants and statics don't live on the stack
+            if !in_constant(cx, expr.id);
+            then {
+                span_help_and_lint(
+                    cx,
+                    LARGE_STACK_ARRAYS,
+                    expr.span,
+                    &format!("allocating a local array larger than {} bytes", self.maximum_allowed_size),
+                    &format!(
+                        "consider allocating on the heap with `vec!{}.into_boxed_slice()`",
+                        snippet(cx, expr.span, "[...]")
+                    ),
+                );
+            }
+        }
+    }
+}
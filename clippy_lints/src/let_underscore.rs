@@ -0,0 +1,95 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_external_macro, match_def_path, paths, span_help_and_lint};
+
+/// **What it does:** Checks for `let _ = <expr>` where the value is or contains a lock guard of
+/// `std::sync` or `parking_lot`, e.g. `let _ = mutex.lock();`.
+///
+/// **Why is this bad?** Unlike a named binding, a temporary guard is dropped at the end of the
+/// statement, releasing the lock right away instead of holding it for the rest of the scope.
+/// And `let _ = guard;` doesn't move out of `guard` at all, so the lock stays held.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// let _ = mutex.lock();
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// let _lock = mutex.lock();
+/// ```
+declare_clippy_lint! {
+    pub LET_UNDERSCORE_LOCK,
+    correctness,
+    "non-binding `let` on a synchronization lock"
+}
+
+const GUARDS: [&[&str]; 6] = [
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+    &paths::PARKING_LOT_MUTEX_GUARD,
+    &paths::PARKING_LOT_RWLOCK_READ_GUARD,
+    &paths::PARKING_LOT_RWLOCK_WRITE_GUARD,
+];
+
+pub struct LetUnderscore;
+
+impl LintPass for LetUnderscore {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LET_UNDERSCORE_LOCK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LetUnderscore {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if_chain! {
+            if !in_external_macro(cx, local.span);
+            if let PatKind::Wild = local.pat.node;
+            if let Some(ref init) = local.init;
+            if contains_guard(cx, cx.tables.expr_ty(init));
+            then {
+                // `let _ = place;` neither moves nor drops the place
+                let is_local = match init.node {
+                    ExprKind::Path(QPath::Resolved(None, ref path)) => match path.def {
+                        Def::Local(..) | Def::Upvar(..) => true,
+                        _ => false,
+                    },
+                    _ => false,
+                };
+                if is_local {
+                    span_help_and_lint(
+                        cx,
+                        LET_UNDERSCORE_LOCK,
+                        local.span,
+                        "non-binding `let` on a lock guard doesn't drop it",
+                        "consider dropping the guard explicitly with `std::mem::drop`",
+                    );
+                } else {
+                    span_help_and_lint(
+                        cx,
+                        LET_UNDERSCORE_LOCK,
+                        local.span,
+                        "non-binding `let` on a synchronization lock",
+                        "consider using an underscore-prefixed named binding or dropping explicitly with \
+                         `std::mem::drop`",
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn contains_guard(cx: &LateContext, ty: Ty) -> bool {
+    ty.walk().any(|inner| match inner.sty {
+        ty::TyAdt(adt, _) => GUARDS.iter().any(|path| match_def_path(cx.tcx, adt.did, path)),
+        _ => false,
+    })
+}
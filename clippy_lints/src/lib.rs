@@ -57,20 +57,32 @@ pub mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 pub mod approx_const;
+pub mod arc_with_non_send_sync;
 pub mod arithmetic;
+pub mod as_conversions;
 pub mod assign_ops;
+pub mod async_yields_async;
 pub mod attrs;
+pub mod await_holding_lock;
 pub mod bit_mask;
 pub mod blacklisted_name;
 pub mod block_in_if_condition;
+pub mod blocking_in_async;
 pub mod booleans;
+pub mod borrow_as_ptr;
 pub mod bytecount;
+pub mod cognitive_complexity;
 pub mod collapsible_if;
+pub mod collection_is_never_read;
+pub mod condvar_wait_without_loop;
 pub mod const_static_lifetime;
 pub mod copies;
-pub mod cyclomatic_complexity;
+pub mod create_dir;
+pub mod dbg_macro;
 pub mod default_trait_access;
+pub mod default_union_representation;
 pub mod derive;
+pub mod detached_thread;
 pub mod doc;
 pub mod double_comparison;
 pub mod double_parens;
@@ -87,16 +99,25 @@ pub mod erasing_op;
 pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
+pub mod excessive_bools;
 pub mod excessive_precision;
+pub mod exhaustive_items;
+pub mod exit;
 pub mod explicit_write;
 pub mod fallible_impl_from;
+pub mod float_equality_without_abs;
+pub mod floating_point_arithmetic;
 pub mod format;
+pub mod format_push_string;
 pub mod formatting;
 pub mod functions;
+pub mod future_not_send;
 pub mod identity_conversion;
 pub mod identity_op;
 pub mod if_let_redundant_pattern_matching;
 pub mod if_not_else;
+pub mod impl_trait_in_params;
+pub mod implicit_return;
 pub mod indexing_slicing;
 pub mod infallible_destructuring_match;
 pub mod infinite_iter;
@@ -104,24 +125,38 @@ pub mod inherent_impl;
 pub mod inline_fn_without_body;
 pub mod int_plus_one;
 pub mod invalid_ref;
+pub mod invalid_utf8_in_unchecked;
 pub mod items_after_statements;
 pub mod large_enum_variant;
+pub mod large_futures;
+pub mod large_stack_arrays;
 pub mod len_zero;
 pub mod let_if_seq;
+pub mod let_underscore;
 pub mod lifetimes;
 pub mod literal_representation;
 pub mod loops;
+pub mod macro_use;
+pub mod manual_bits;
+pub mod manual_clamp;
+pub mod manual_rem_euclid;
+pub mod manual_slice_size_calculation;
 pub mod map_clone;
 pub mod map_unit_fn;
 pub mod matches;
 pub mod mem_forget;
 pub mod methods;
+pub mod min_ident_chars;
 pub mod minmax;
 pub mod misc;
 pub mod misc_early;
+pub mod missing_const_for_fn;
 pub mod missing_doc;
 pub mod missing_inline;
+pub mod module_style;
 pub mod multiple_crate_versions;
+pub mod multiple_unsafe_ops_per_block;
+pub mod mut_key;
 pub mod mut_mut;
 pub mod mut_reference;
 pub mod mutex_atomic;
@@ -137,36 +172,62 @@ pub mod new_without_default;
 pub mod no_effect;
 pub mod non_copy_const;
 pub mod non_expressive_names;
+pub mod non_send_fields_in_send_ty;
 pub mod ok_if_let;
 pub mod open_options;
 pub mod overflow_check_conditional;
+pub mod panic_in_result_fn;
 pub mod panic_unimplemented;
+pub mod partial_pub_fields;
 pub mod partialeq_ne_impl;
+pub mod pass_by_ref_or_value;
 pub mod precedence;
 pub mod ptr;
+pub mod ptr_eq;
+pub mod pub_use;
 pub mod question_mark;
 pub mod ranges;
+pub mod readonly_write_lock;
+pub mod redundant_clone;
 pub mod redundant_field_names;
+pub mod redundant_pub_crate;
 pub mod reference;
 pub mod regex;
 pub mod replace_consts;
 pub mod returns;
+pub mod same_name_method;
+pub mod semicolon_if_nothing_returned;
 pub mod serde_api;
 pub mod shadow;
+pub mod single_component_path_imports;
+pub mod stable_sort_primitive;
 pub mod strings;
 pub mod suspicious_trait_impl;
 pub mod swap;
+pub mod swap_ptr_to_ref;
 pub mod temporary_assignment;
+pub mod tests_outside_test_module;
 pub mod transmute;
-pub mod trivially_copy_pass_by_ref;
+pub mod transmuting_null;
 pub mod types;
+pub mod undocumented_unsafe_blocks;
 pub mod unicode;
+pub mod uninit_assumed_init;
+pub mod uninit_vec;
+pub mod unnecessary_box_returns;
+pub mod unnecessary_self_imports;
+pub mod unnecessary_sort_by;
+pub mod unnecessary_wraps;
 pub mod unsafe_removed_from_name;
+pub mod unused_async;
 pub mod unused_io_amount;
 pub mod unused_label;
+pub mod unused_self;
 pub mod unwrap;
+pub mod unwrap_in_result;
 pub mod use_self;
 pub mod vec;
+pub mod wildcard_imports;
 pub mod write;
 pub mod zero_div_zero;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
@@ -221,6 +282,24 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         }
     };
 
+    let msrv = conf.msrv.as_ref().and_then(|s| {
+        utils::parse_msrv(s).or_else(|| {
+            reg.sess.struct_err(&format!("error reading Clippy's configuration file. `{}` is not a valid Rust version", s)).emit();
+            None
+        })
+    });
+
+    let cognitive_complexity_threshold = conf.cyclomatic_complexity_threshold.map_or(
+        conf.cognitive_complexity_threshold,
+        |threshold| {
+            reg.sess.struct_warn(
+                "the `cyclomatic-complexity-threshold` configuration option is deprecated, \
+                 use `cognitive-complexity-threshold` instead",
+            ).emit();
+            threshold
+        },
+    );
+
     let mut store = reg.sess.lint_store.borrow_mut();
     store.register_removed(
         "should_assert_eq",
@@ -242,20 +321,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         "unstable_as_mut_slice",
         "`Vec::as_mut_slice` has been stabilized in 1.7",
     );
-    store.register_removed(
-        "str_to_string",
-        "using `str::to_string` is common even today and specialization will likely happen soon",
-    );
-    store.register_removed(
-        "string_to_string",
-        "using `string::to_string` is common even today and specialization will likely happen soon",
-    );
     store.register_removed(
         "misaligned_transmute",
         "this lint has been split into cast_ptr_alignment and transmute_ptr_to_ptr",
     );
     // end deprecated lints, do not remove this comment, it’s used in `update_lints`
 
+    store.register_renamed("cyclomatic_complexity", "cognitive_complexity");
+
     reg.register_late_lint_pass(box serde_api::Serde);
     reg.register_early_lint_pass(box utils::internal_lints::Clippy);
     reg.register_late_lint_pass(box utils::internal_lints::LintWithoutLintPass::default());
@@ -264,8 +337,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box types::TypePass);
     reg.register_late_lint_pass(box booleans::NonminimalBool);
     reg.register_late_lint_pass(box eq_op::EqOp);
-    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold));
-    reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse);
+    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(
+        conf.enum_variant_name_threshold,
+        conf.stutter_allowed_prefixes,
+        conf.stutter_allowed_suffixes,
+    ));
+    reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse::new(conf.enum_glob_use_whitelist));
     reg.register_late_lint_pass(box enum_clike::UnportableVariant);
     reg.register_late_lint_pass(box excessive_precision::ExcessivePrecision);
     reg.register_late_lint_pass(box bit_mask::BitMask::new(conf.verbose_bit_mask_threshold));
@@ -289,7 +366,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box unicode::Unicode);
     reg.register_late_lint_pass(box strings::StringAdd);
     reg.register_early_lint_pass(box returns::ReturnPass);
-    reg.register_late_lint_pass(box methods::Pass);
+    reg.register_late_lint_pass(box methods::Pass::new(msrv.clone()));
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
     reg.register_late_lint_pass(box types::UnitCmp);
@@ -297,7 +374,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box lifetimes::LifetimePass);
     reg.register_late_lint_pass(box entry::HashMapLint);
     reg.register_late_lint_pass(box ranges::Pass);
-    reg.register_late_lint_pass(box types::CastPass);
+    reg.register_late_lint_pass(box types::CastPass::new(msrv.clone()));
     reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
     reg.register_late_lint_pass(box matches::MatchPass);
     reg.register_late_lint_pass(box minmax::MinMaxPass);
@@ -312,7 +389,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box temporary_assignment::Pass);
     reg.register_late_lint_pass(box transmute::Transmute);
     reg.register_late_lint_pass(
-        box cyclomatic_complexity::CyclomaticComplexity::new(conf.cyclomatic_complexity_threshold)
+        box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold)
     );
     reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
     reg.register_early_lint_pass(box misc_early::MiscEarly);
@@ -341,8 +418,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box unused_label::UnusedLabel);
     reg.register_late_lint_pass(box new_without_default::NewWithoutDefault);
     reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(conf.blacklisted_names));
-    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold));
-    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents));
+    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold, conf.too_many_lines_threshold));
+    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents, conf.missing_panics_doc_ignore_debug_assert));
     reg.register_late_lint_pass(box neg_multiply::NegMultiply);
     reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
     reg.register_late_lint_pass(box mem_forget::MemForget);
@@ -362,8 +439,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
     reg.register_late_lint_pass(box explicit_write::Pass);
     reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
-    reg.register_late_lint_pass(box trivially_copy_pass_by_ref::TriviallyCopyPassByRef::new(
+    reg.register_late_lint_pass(box pass_by_ref_or_value::PassByRefOrValue::new(
             conf.trivial_copy_size_limit,
+            conf.pass_by_value_size_limit,
             &reg.sess.target,
     ));
     reg.register_early_lint_pass(box literal_representation::LiteralDigitGrouping);
@@ -395,12 +473,95 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box default_trait_access::DefaultTraitAccess);
     reg.register_late_lint_pass(box indexing_slicing::IndexingSlicing);
     reg.register_late_lint_pass(box non_copy_const::NonCopyConst);
+    reg.register_late_lint_pass(box borrow_as_ptr::BorrowAsPtr::new(msrv.clone()));
+    reg.register_early_lint_pass(box excessive_bools::ExcessiveBools::new(
+        conf.max_struct_bools,
+        conf.max_fn_params_bools,
+    ));
+    reg.register_late_lint_pass(box unused_self::UnusedSelf);
+    reg.register_late_lint_pass(box unnecessary_wraps::UnnecessaryWraps::default());
+    reg.register_late_lint_pass(box missing_const_for_fn::MissingConstForFn::new(msrv.clone()));
+    reg.register_late_lint_pass(box unused_async::UnusedAsync);
+    reg.register_late_lint_pass(box dbg_macro::Pass);
+    reg.register_late_lint_pass(box panic_in_result_fn::PanicInResultFn);
+    reg.register_late_lint_pass(box unwrap_in_result::UnwrapInResult::new(conf.allow_unwrap_in_tests));
+    reg.register_late_lint_pass(box as_conversions::AsConversions::new(conf.as_conversions_exempt_ptr_and_index));
+    reg.register_late_lint_pass(box exit::Exit);
+    reg.register_late_lint_pass(box create_dir::CreateDir);
+    reg.register_late_lint_pass(box strings::StrToString);
+    reg.register_late_lint_pass(box strings::StringToString);
+    reg.register_late_lint_pass(box implicit_return::Pass);
+    reg.register_late_lint_pass(box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
+    reg.register_late_lint_pass(box wildcard_imports::WildcardImports::new(conf.warn_on_all_wildcard_imports));
+    reg.register_late_lint_pass(box macro_use::MacroUseImports::default());
+    reg.register_early_lint_pass(box single_component_path_imports::SingleComponentPathImports);
+    reg.register_early_lint_pass(box unnecessary_self_imports::UnnecessarySelfImports);
+    reg.register_late_lint_pass(box redundant_pub_crate::RedundantPubCrate::default());
+    reg.register_early_lint_pass(box pub_use::PubUse::new(conf.pub_use_allowed_modules));
+    reg.register_early_lint_pass(box module_style::ModStyle);
+    reg.register_late_lint_pass(box exhaustive_items::ExhaustiveItems);
+    reg.register_early_lint_pass(box partial_pub_fields::PartialPubFields);
+    reg.register_late_lint_pass(box same_name_method::SameNameMethod::default());
+    reg.register_late_lint_pass(box tests_outside_test_module::TestsOutsideTestModule);
+    reg.register_early_lint_pass(box impl_trait_in_params::ImplTraitInParams);
+    reg.register_early_lint_pass(box min_ident_chars::MinIdentChars::new(
+        conf.allowed_idents_below_min_chars,
+        conf.min_ident_chars_threshold,
+    ));
+    reg.register_late_lint_pass(box await_holding_lock::AwaitHoldingLock::new(conf.await_holding_lock_guards));
+    reg.register_late_lint_pass(box future_not_send::FutureNotSend);
+    reg.register_late_lint_pass(box async_yields_async::AsyncYieldsAsync::default());
+    reg.register_late_lint_pass(box large_futures::LargeFutures::new(conf.future_size_threshold));
+    reg.register_late_lint_pass(box arc_with_non_send_sync::ArcWithNonSendSync);
+    reg.register_late_lint_pass(box non_send_fields_in_send_ty::NonSendFieldsInSendTy);
+    reg.register_late_lint_pass(box readonly_write_lock::ReadonlyWriteLock);
+    reg.register_late_lint_pass(box let_underscore::LetUnderscore);
+    reg.register_late_lint_pass(box blocking_in_async::BlockingInAsync::new(
+        conf.blocking_in_async_paths,
+        conf.block_on_paths,
+    ));
+    reg.register_late_lint_pass(box detached_thread::DetachedThread);
+    reg.register_late_lint_pass(box condvar_wait_without_loop::CondvarWaitWithoutLoop);
+    reg.register_late_lint_pass(box undocumented_unsafe_blocks::UndocumentedUnsafeBlocks::default());
+    reg.register_late_lint_pass(box uninit_vec::UninitVec);
+    reg.register_late_lint_pass(box uninit_assumed_init::UninitAssumedInit);
+    reg.register_late_lint_pass(box invalid_utf8_in_unchecked::InvalidUtf8InUnchecked);
+    reg.register_late_lint_pass(box multiple_unsafe_ops_per_block::MultipleUnsafeOpsPerBlock);
+    reg.register_late_lint_pass(box ptr_eq::PtrEq);
+    reg.register_late_lint_pass(box transmuting_null::TransmutingNull);
+    reg.register_late_lint_pass(box manual_slice_size_calculation::ManualSliceSizeCalculation);
+    reg.register_late_lint_pass(box default_union_representation::DefaultUnionRepresentation::default());
+    reg.register_late_lint_pass(box swap_ptr_to_ref::SwapPtrToRef);
+    reg.register_late_lint_pass(box redundant_clone::RedundantClone);
+    reg.register_late_lint_pass(box stable_sort_primitive::StableSortPrimitive);
+    reg.register_late_lint_pass(box unnecessary_sort_by::UnnecessarySortBy);
+    reg.register_late_lint_pass(box large_stack_arrays::LargeStackArrays::new(conf.array_size_threshold));
+    reg.register_late_lint_pass(box format_push_string::FormatPushString);
+    reg.register_late_lint_pass(box collection_is_never_read::CollectionIsNeverRead);
+    reg.register_late_lint_pass(box mut_key::MutableKeyType::new(conf.ignore_interior_mutability));
+    reg.register_late_lint_pass(box unnecessary_box_returns::UnnecessaryBoxReturns::default());
+    reg.register_late_lint_pass(box floating_point_arithmetic::FloatingPointArithmetic);
+    reg.register_late_lint_pass(box float_equality_without_abs::FloatEqualityWithoutAbs);
+    reg.register_late_lint_pass(box manual_clamp::ManualClamp::new(msrv.clone()));
+    reg.register_late_lint_pass(box manual_rem_euclid::ManualRemEuclid::new(msrv.clone()));
+    reg.register_late_lint_pass(box manual_bits::ManualBits::new(msrv.clone()));
 
     reg.register_lint_group("clippy_restriction", vec![
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
+        as_conversions::AS_CONVERSIONS,
         assign_ops::ASSIGN_OPS,
+        create_dir::CREATE_DIR,
+        dbg_macro::DBG_MACRO,
+        default_union_representation::DEFAULT_UNION_REPRESENTATION,
+        doc::UNNECESSARY_SAFETY_DOC,
         else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        exhaustive_items::EXHAUSTIVE_ENUMS,
+        exhaustive_items::EXHAUSTIVE_STRUCTS,
+        exit::EXIT,
+        impl_trait_in_params::IMPL_TRAIT_IN_PARAMS,
+        implicit_return::IMPLICIT_RETURN,
+        indexing_slicing::INDEXING_SLICING,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
         literal_representation::DECIMAL_LITERAL_REPRESENTATION,
         mem_forget::MEM_FORGET,
@@ -408,32 +569,65 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::OPTION_UNWRAP_USED,
         methods::RESULT_UNWRAP_USED,
         methods::WRONG_PUB_SELF_CONVENTION,
+        min_ident_chars::MIN_IDENT_CHARS,
         misc::FLOAT_CMP_CONST,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        module_style::MOD_MODULE_FILES,
+        module_style::SELF_NAMED_MODULE_FILES,
+        multiple_unsafe_ops_per_block::MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+        panic_in_result_fn::PANIC_IN_RESULT_FN,
+        panic_unimplemented::TODO,
         panic_unimplemented::UNIMPLEMENTED,
+        partial_pub_fields::PARTIAL_PUB_FIELDS,
+        pub_use::PUB_USE,
+        same_name_method::SAME_NAME_METHOD,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         shadow::SHADOW_UNRELATED,
+        strings::STR_TO_STRING,
         strings::STRING_ADD,
+        strings::STRING_SLICE,
+        strings::STRING_TO_STRING,
+        tests_outside_test_module::TESTS_OUTSIDE_TEST_MODULE,
+        undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS,
+        undocumented_unsafe_blocks::UNNECESSARY_SAFETY_COMMENT,
+        unnecessary_self_imports::UNNECESSARY_SELF_IMPORTS,
+        unwrap_in_result::UNWRAP_IN_RESULT,
         write::PRINT_STDOUT,
         write::USE_DEBUG,
     ]);
 
     reg.register_lint_group("clippy_pedantic", vec![
         attrs::INLINE_ALWAYS,
+        await_holding_lock::AWAIT_HOLDING_LOCK,
+        await_holding_lock::AWAIT_HOLDING_REFCELL_REF,
+        blocking_in_async::BLOCKING_IN_ASYNC,
+        borrow_as_ptr::BORROW_AS_PTR,
+        bytecount::NAIVE_BYTECOUNT,
         copies::MATCH_SAME_ARMS,
         default_trait_access::DEFAULT_TRAIT_ACCESS,
         derive::EXPL_IMPL_CLONE_ON_COPY,
+        detached_thread::DETACHED_THREAD,
         doc::DOC_MARKDOWN,
+        doc::MISSING_ERRORS_DOC,
+        doc::MISSING_PANICS_DOC,
         empty_enum::EMPTY_ENUM,
         enum_glob_use::ENUM_GLOB_USE,
         enum_variants::PUB_ENUM_VARIANT_NAMES,
         enum_variants::STUTTER,
+        excessive_bools::FN_PARAMS_EXCESSIVE_BOOLS,
+        excessive_bools::STRUCT_EXCESSIVE_BOOLS,
+        format_push_string::FORMAT_PUSH_STRING,
+        functions::MUST_USE_CANDIDATE,
+        functions::RETURN_SELF_NOT_MUST_USE,
+        functions::TOO_MANY_LINES,
         if_not_else::IF_NOT_ELSE,
-        indexing_slicing::INDEXING_SLICING,
         infinite_iter::MAYBE_INFINITE_ITER,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        large_futures::LARGE_FUTURES,
+        large_stack_arrays::LARGE_STACK_ARRAYS,
+        macro_use::MACRO_USE_IMPORTS,
         matches::SINGLE_MATCH_ELSE,
         methods::FILTER_MAP,
         methods::OPTION_MAP_UNWRAP_OR,
@@ -444,7 +638,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         mut_mut::MUT_MUT,
         needless_continue::NEEDLESS_CONTINUE,
         non_expressive_names::SIMILAR_NAMES,
+        pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
         replace_consts::REPLACE_CONSTS,
+        semicolon_if_nothing_returned::SEMICOLON_IF_NOTHING_RETURNED,
+        stable_sort_primitive::STABLE_SORT_PRIMITIVE,
         strings::STRING_ADD_ASSIGN,
         types::CAST_POSSIBLE_TRUNCATION,
         types::CAST_POSSIBLE_WRAP,
@@ -452,9 +649,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::CAST_SIGN_LOSS,
         types::INVALID_UPCAST_COMPARISONS,
         types::LINKEDLIST,
+        types::ZERO_SIZED_MAP_VALUES,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
+        unnecessary_box_returns::UNNECESSARY_BOX_RETURNS,
+        unnecessary_wraps::UNNECESSARY_WRAPS,
+        unused_async::UNUSED_ASYNC,
+        unused_self::UNUSED_SELF,
         use_self::USE_SELF,
+        wildcard_imports::WILDCARD_IMPORTS,
     ]);
 
     reg.register_lint_group("clippy_internal", vec![
@@ -464,8 +667,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
 
     reg.register_lint_group("clippy", vec![
         approx_const::APPROX_CONSTANT,
+        arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC,
         assign_ops::ASSIGN_OP_PATTERN,
         assign_ops::MISREFACTORED_ASSIGN_OP,
+        async_yields_async::ASYNC_YIELDS_ASYNC,
         attrs::DEPRECATED_SEMVER,
         attrs::USELESS_ATTRIBUTE,
         bit_mask::BAD_BIT_MASK,
@@ -474,15 +679,17 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         blacklisted_name::BLACKLISTED_NAME,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
+        blocking_in_async::BLOCK_ON_IN_ASYNC,
         booleans::LOGIC_BUG,
         booleans::NONMINIMAL_BOOL,
-        bytecount::NAIVE_BYTECOUNT,
+        cognitive_complexity::COGNITIVE_COMPLEXITY,
         collapsible_if::COLLAPSIBLE_IF,
+        condvar_wait_without_loop::CONDVAR_WAIT_WITHOUT_LOOP,
         const_static_lifetime::CONST_STATIC_LIFETIME,
         copies::IF_SAME_THEN_ELSE,
         copies::IFS_SAME_COND,
-        cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
         derive::DERIVE_HASH_XOR_EQ,
+        doc::MISSING_SAFETY_DOC,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         drop_forget_ref::DROP_COPY,
@@ -503,10 +710,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         eval_order_dependence::EVAL_ORDER_DEPENDENCE,
         excessive_precision::EXCESSIVE_PRECISION,
         explicit_write::EXPLICIT_WRITE,
+        float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS,
         format::USELESS_FORMAT,
         formatting::POSSIBLE_MISSING_COMMA,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
+        functions::DOUBLE_MUST_USE,
+        functions::MUST_USE_UNIT,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
         functions::TOO_MANY_ARGUMENTS,
         identity_conversion::IDENTITY_CONVERSION,
@@ -518,15 +728,18 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         int_plus_one::INT_PLUS_ONE,
         invalid_ref::INVALID_REF,
+        invalid_utf8_in_unchecked::INVALID_UTF8_IN_UNCHECKED,
         large_enum_variant::LARGE_ENUM_VARIANT,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
+        let_underscore::LET_UNDERSCORE_LOCK,
         lifetimes::EXTRA_UNUSED_LIFETIMES,
         lifetimes::NEEDLESS_LIFETIMES,
         literal_representation::INCONSISTENT_DIGIT_GROUPING,
         literal_representation::LARGE_DIGIT_GROUPS,
         literal_representation::UNREADABLE_LITERAL,
+        loops::BUSY_WAIT,
         loops::EMPTY_LOOP,
         loops::EXPLICIT_COUNTER_LOOP,
         loops::EXPLICIT_INTO_ITER_LOOP,
@@ -536,6 +749,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::FOR_LOOP_OVER_RESULT,
         loops::ITER_NEXT_LOOP,
         loops::MANUAL_MEMCPY,
+        loops::MANUAL_RECV_LOOP,
         loops::MUT_RANGE_BOUND,
         loops::NEEDLESS_RANGE_LOOP,
         loops::NEVER_LOOP,
@@ -544,6 +758,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::WHILE_IMMUTABLE_CONDITION,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_bits::MANUAL_BITS,
+        manual_clamp::MANUAL_CLAMP,
+        manual_rem_euclid::MANUAL_REM_EUCLID,
+        manual_slice_size_calculation::MANUAL_SLICE_SIZE_CALCULATION,
         map_clone::MAP_CLONE,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
@@ -557,7 +775,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
+        methods::DRAIN_COLLECT,
         methods::EXPECT_FUN_CALL,
+        methods::EXTEND_WITH_SINGLE_ELEMENT,
         methods::FILTER_NEXT,
         methods::GET_UNWRAP,
         methods::ITER_CLONED_COLLECT,
@@ -575,6 +795,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::UNNECESSARY_FOLD,
         methods::USELESS_ASREF,
         methods::WRONG_SELF_CONVENTION,
+        methods::ZST_OFFSET,
         minmax::MIN_MAX,
         misc::CMP_NAN,
         misc::CMP_OWNED,
@@ -591,6 +812,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         misc_early::REDUNDANT_CLOSURE_CALL,
         misc_early::UNNEEDED_FIELD_PATTERN,
         misc_early::ZERO_PREFIXED_LITERAL,
+        mut_key::MUTABLE_KEY_TYPE,
         mut_reference::UNNECESSARY_MUT_PASSED,
         mutex_atomic::MUTEX_ATOMIC,
         needless_bool::BOOL_COMPARISON,
@@ -613,10 +835,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
         panic_unimplemented::PANIC_PARAMS,
         partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         precedence::PRECEDENCE,
         ptr::CMP_NULL,
         ptr::MUT_FROM_REF,
         ptr::PTR_ARG,
+        ptr_eq::PTR_EQ,
         question_mark::QUESTION_MARK,
         ranges::ITERATOR_STEP_BY_ZERO,
         ranges::RANGE_MINUS_ONE,
@@ -630,11 +854,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
         serde_api::SERDE_API_MISUSE,
+        single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
         strings::STRING_LIT_AS_BYTES,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
+        swap_ptr_to_ref::SWAP_PTR_TO_REF,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
         transmute::CROSSPOINTER_TRANSMUTE,
         transmute::TRANSMUTE_BYTES_TO_STR,
@@ -643,14 +869,19 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         transmute::TRANSMUTE_INT_TO_FLOAT,
         transmute::TRANSMUTE_PTR_TO_PTR,
         transmute::TRANSMUTE_PTR_TO_REF,
+        transmute::UNSOUND_COLLECTION_TRANSMUTE,
         transmute::USELESS_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
-        trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
+        transmuting_null::TRANSMUTING_NULL,
         types::ABSURD_EXTREME_COMPARISONS,
         types::BORROWED_BOX,
         types::BOX_VEC,
+        types::CAST_ABS_TO_UNSIGNED,
+        types::CAST_ENUM_CONSTRUCTOR,
+        types::CAST_ENUM_TRUNCATION,
         types::CAST_LOSSLESS,
         types::CAST_PTR_ALIGNMENT,
+        types::CAST_SLICE_DIFFERENT_SIZES,
         types::CHAR_LIT_AS_U8,
         types::FN_TO_NUMERIC_CAST,
         types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
@@ -662,6 +893,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::UNIT_CMP,
         types::UNNECESSARY_CAST,
         unicode::ZERO_WIDTH_SPACE,
+        uninit_assumed_init::UNINIT_ASSUMED_INIT,
+        uninit_vec::UNINIT_VEC,
+        unnecessary_sort_by::UNNECESSARY_SORT_BY,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
@@ -683,6 +917,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
         collapsible_if::COLLAPSIBLE_IF,
         const_static_lifetime::CONST_STATIC_LIFETIME,
+        doc::MISSING_SAFETY_DOC,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
         eq_op::OP_REF,
@@ -690,6 +925,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         excessive_precision::EXCESSIVE_PRECISION,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
+        functions::DOUBLE_MUST_USE,
+        functions::MUST_USE_UNIT,
         if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         len_zero::LEN_WITHOUT_IS_EMPTY,
@@ -702,8 +939,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::EXPLICIT_INTO_ITER_LOOP,
         loops::EXPLICIT_ITER_LOOP,
         loops::FOR_KV_MAP,
+        loops::MANUAL_RECV_LOOP,
         loops::NEEDLESS_RANGE_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_bits::MANUAL_BITS,
         map_clone::MAP_CLONE,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
@@ -711,6 +950,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         matches::MATCH_WILD_ERR_ARM,
         matches::SINGLE_MATCH,
         methods::CHARS_LAST_CMP,
+        methods::EXTEND_WITH_SINGLE_ELEMENT,
         methods::GET_UNWRAP,
         methods::ITER_CLONED_COLLECT,
         methods::ITER_SKIP_NEXT,
@@ -740,6 +980,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         panic_unimplemented::PANIC_PARAMS,
         ptr::CMP_NULL,
         ptr::PTR_ARG,
+        ptr_eq::PTR_EQ,
         question_mark::QUESTION_MARK,
         ranges::RANGE_MINUS_ONE,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
@@ -747,7 +988,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         regex::TRIVIAL_REGEX,
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
+        single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS,
         strings::STRING_LIT_AS_BYTES,
+        swap_ptr_to_ref::SWAP_PTR_TO_REF,
+        types::CAST_ABS_TO_UNSIGNED,
         types::FN_TO_NUMERIC_CAST,
         types::IMPLICIT_HASHER,
         types::LET_UNIT_VALUE,
@@ -761,9 +1005,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     ]);
 
     reg.register_lint_group("clippy_complexity", vec![
+        arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC,
         assign_ops::MISREFACTORED_ASSIGN_OP,
         booleans::NONMINIMAL_BOOL,
-        cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        cognitive_complexity::COGNITIVE_COMPLEXITY,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         duration_subsec::DURATION_SUBSEC,
@@ -780,6 +1025,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::EXPLICIT_COUNTER_LOOP,
         loops::MUT_RANGE_BOUND,
         loops::WHILE_LET_LOOP,
+        manual_clamp::MANUAL_CLAMP,
+        manual_rem_euclid::MANUAL_REM_EUCLID,
+        manual_slice_size_calculation::MANUAL_SLICE_SIZE_CALCULATION,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
         matches::MATCH_AS_REF,
@@ -821,17 +1069,21 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::TYPE_COMPLEXITY,
         types::UNIT_ARG,
         types::UNNECESSARY_CAST,
+        unnecessary_sort_by::UNNECESSARY_SORT_BY,
         unused_label::UNUSED_LABEL,
         zero_div_zero::ZERO_DIVIDED_BY_ZERO,
     ]);
 
     reg.register_lint_group("clippy_correctness", vec![
         approx_const::APPROX_CONSTANT,
+        async_yields_async::ASYNC_YIELDS_ASYNC,
         attrs::DEPRECATED_SEMVER,
         attrs::USELESS_ATTRIBUTE,
         bit_mask::BAD_BIT_MASK,
         bit_mask::INEFFECTIVE_BIT_MASK,
+        blocking_in_async::BLOCK_ON_IN_ASYNC,
         booleans::LOGIC_BUG,
+        condvar_wait_without_loop::CONDVAR_WAIT_WITHOUT_LOOP,
         copies::IF_SAME_THEN_ELSE,
         copies::IFS_SAME_COND,
         derive::DERIVE_HASH_XOR_EQ,
@@ -842,12 +1094,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
         eq_op::EQ_OP,
         erasing_op::ERASING_OP,
+        float_equality_without_abs::FLOAT_EQUALITY_WITHOUT_ABS,
         formatting::POSSIBLE_MISSING_COMMA,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infinite_iter::INFINITE_ITER,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         invalid_ref::INVALID_REF,
+        invalid_utf8_in_unchecked::INVALID_UTF8_IN_UNCHECKED,
+        let_underscore::LET_UNDERSCORE_LOCK,
         loops::FOR_LOOP_OVER_OPTION,
         loops::FOR_LOOP_OVER_RESULT,
         loops::ITER_NEXT_LOOP,
@@ -856,10 +1111,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::WHILE_IMMUTABLE_CONDITION,
         methods::CLONE_DOUBLE_REF,
         methods::TEMPORARY_CSTRING_AS_PTR,
+        methods::ZST_OFFSET,
         minmax::MIN_MAX,
         misc::CMP_NAN,
         misc::FLOAT_CMP,
         misc::MODULO_ONE,
+        mut_key::MUTABLE_KEY_TYPE,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
         open_options::NONSENSICAL_OPEN_OPTIONS,
@@ -870,29 +1127,37 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
+        transmute::UNSOUND_COLLECTION_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
+        transmuting_null::TRANSMUTING_NULL,
         types::ABSURD_EXTREME_COMPARISONS,
+        types::CAST_ENUM_CONSTRUCTOR,
+        types::CAST_ENUM_TRUNCATION,
         types::CAST_PTR_ALIGNMENT,
+        types::CAST_SLICE_DIFFERENT_SIZES,
         types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         types::UNIT_CMP,
         unicode::ZERO_WIDTH_SPACE,
+        uninit_assumed_init::UNINIT_ASSUMED_INIT,
+        uninit_vec::UNINIT_VEC,
         unused_io_amount::UNUSED_IO_AMOUNT,
     ]);
 
     reg.register_lint_group("clippy_perf", vec![
-        bytecount::NAIVE_BYTECOUNT,
         entry::MAP_ENTRY,
         escape::BOXED_LOCAL,
         large_enum_variant::LARGE_ENUM_VARIANT,
+        loops::BUSY_WAIT,
         loops::MANUAL_MEMCPY,
         loops::UNUSED_COLLECT,
+        methods::DRAIN_COLLECT,
         methods::EXPECT_FUN_CALL,
         methods::ITER_NTH,
         methods::OR_FUN_CALL,
         methods::SINGLE_CHAR_PATTERN,
         misc::CMP_OWNED,
         mutex_atomic::MUTEX_ATOMIC,
-        trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
+        pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         types::BOX_VEC,
         vec::USELESS_VEC,
     ]);
@@ -903,10 +1168,21 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
 
     reg.register_lint_group("clippy_nursery", vec![
         attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
+        collection_is_never_read::COLLECTION_IS_NEVER_READ,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
+        floating_point_arithmetic::IMPRECISE_FLOPS,
+        floating_point_arithmetic::SUBOPTIMAL_FLOPS,
+        future_not_send::FUTURE_NOT_SEND,
+        missing_const_for_fn::MISSING_CONST_FOR_FN,
         mutex_atomic::MUTEX_INTEGER,
         needless_borrow::NEEDLESS_BORROW,
+        non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY,
         ranges::RANGE_PLUS_ONE,
+        readonly_write_lock::READONLY_WRITE_LOCK,
+        redundant_clone::REDUNDANT_CLONE,
+        redundant_pub_crate::REDUNDANT_PUB_CRATE,
+        transmute::TRANSMUTE_UNDEFINED_REPR,
+        types::AS_PTR_CAST_MUT,
         unwrap::PANICKING_UNWRAP,
         unwrap::UNNECESSARY_UNWRAP,
     ]);
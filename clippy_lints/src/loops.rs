@@ -24,8 +24,9 @@ use crate::utils::usage::mutated_variables;
 use crate::consts::{constant, Constant};
 
 use crate::utils::{get_enclosing_block, get_parent_expr, higher, in_external_macro, is_integer_literal, is_refutable,
-            last_path_segment, match_trait_method, match_type, match_var, multispan_sugg, snippet, snippet_opt,
-            span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, SpanlessEq};
+            last_path_segment, match_trait_method, match_type, match_var, method_chain_args, multispan_sugg, snippet,
+            snippet_opt,
+            span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, walk_ptrs_ty, SpanlessEq};
 use crate::utils::paths;
 
 /// **What it does:** Checks for for-loops that manually copy items between
@@ -378,6 +379,62 @@ declare_clippy_lint! {
     "variables used within while expression are not mutated in the body"
 }
 
+/// **What it does:** Checks for loops that wait for a `try_lock`, `try_read`, `try_write` or
+/// `try_recv` to succeed, or for an `AtomicBool`, without calling anything to back off.
+///
+/// **Why is this bad?** Such a loop keeps a core fully busy while waiting. At the very least
+/// `std::sync::atomic::spin_loop_hint()` tells the processor that the thread is spinning, and
+/// `std::thread::yield_now()` or a short sleep lets other threads run.
+///
+/// **Known problems:** Only `while` loops whose condition is a failed poll, and `loop`s that
+/// start with a `break` on a successful poll are linted. Loops that do other work between the
+/// polls are not linted.
+///
+/// **Example:**
+/// ```rust,ignore
+/// while !ready.load(Ordering::Acquire) {}
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// while !ready.load(Ordering::Acquire) {
+///     std::sync::atomic::spin_loop_hint();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BUSY_WAIT,
+    perf,
+    "busy-wait loops that poll without backing off"
+}
+
+/// **What it does:** Checks for loops that receive from a `std::sync::mpsc::Receiver` with
+/// `while let Ok(..) = rx.recv()` or with a `let` of `rx.recv().unwrap()` at the start of a `loop`.
+///
+/// **Why is this bad?** A `for` loop over the receiver is shorter, and it ends cleanly when all
+/// senders are disconnected, where unwrapping the result of `recv` panics.
+///
+/// **Known problems:** Replacing `unwrap` changes the behavior when the senders disconnect.
+///
+/// **Example:**
+/// ```rust,ignore
+/// loop {
+///     let msg = rx.recv().unwrap();
+///     println!("{}", msg);
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// for msg in &rx {
+///     println!("{}", msg);
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_RECV_LOOP,
+    style,
+    "receiving from a channel in a loop instead of iterating over the receiver"
+}
+
 #[derive(Copy, Clone)]
 pub struct Pass;
 
@@ -401,6 +458,8 @@ impl LintPass for Pass {
             NEVER_LOOP,
             MUT_RANGE_BOUND,
             WHILE_IMMUTABLE_CONDITION,
+            BUSY_WAIT,
+            MANUAL_RECV_LOOP,
         )
     }
 }
@@ -510,6 +569,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         if let ExprKind::While(ref cond, _, _) = expr.node {
             check_infinite_loop(cx, cond, expr);
         }
+
+        check_busy_wait(cx, expr);
+        check_manual_recv_loop(cx, expr);
     }
 
     fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
@@ -1736,6 +1798,165 @@ impl<'a, 'tcx> Visitor<'tcx> for VarVisitor<'a, 'tcx> {
     }
 }
 
+/// Methods that poll for something without blocking, with the type they are methods of.
+const POLLING_METHODS: [(&str, &[&str]); 8] = [
+    ("try_lock", &paths::MUTEX),
+    ("try_read", &paths::RWLOCK),
+    ("try_write", &paths::RWLOCK),
+    ("try_recv", &paths::RECEIVER),
+    ("load", &paths::ATOMIC_BOOL),
+    ("compare_and_swap", &paths::ATOMIC_BOOL),
+    ("compare_exchange", &paths::ATOMIC_BOOL),
+    ("compare_exchange_weak", &paths::ATOMIC_BOOL),
+];
+/// Methods that only inspect the result of a poll.
+const INSPECTING_METHODS: [&str; 4] = ["is_ok", "is_err", "is_some", "is_none"];
+
+fn check_busy_wait<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+    // `while !poll() {}` or `loop { if poll() { break } }`, but not a loop that goes on as long as
+    // the poll succeeds, like `while rx.try_recv().is_ok() {}`, which does something every time
+    let waits = match expr.node {
+        ExprKind::While(ref cond, ..) => is_poll_outcome(cx, cond, false),
+        ExprKind::Loop(ref block, _, LoopSource::Loop) => match extract_first_expr(block).map(|e| &e.node) {
+            Some(&ExprKind::If(ref cond, ref then, None)) => {
+                is_simple_break_expr(then) && is_poll_outcome(cx, cond, true)
+            },
+            _ => false,
+        },
+        _ => false,
+    };
+    if !waits || in_external_macro(cx, expr.span) {
+        return;
+    }
+    let mut visitor = BusyWaitVisitor {
+        cx,
+        other_calls: false,
+    };
+    walk_expr(&mut visitor, expr);
+    if !visitor.other_calls {
+        span_help_and_lint(
+            cx,
+            BUSY_WAIT,
+            expr.span,
+            "busy-wait loop without backing off",
+            "consider calling `std::sync::atomic::spin_loop_hint()` or `std::thread::yield_now()` in the loop",
+        );
+    }
+}
+
+/// Is `expr` a call of one of the `POLLING_METHODS`?
+fn is_poll(cx: &LateContext, expr: &Expr) -> bool {
+    if let ExprKind::MethodCall(ref method, _, ref args) = expr.node {
+        let recv_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+        POLLING_METHODS
+            .iter()
+            .any(|&(name, path)| method.ident.name == name && match_type(cx, recv_ty, path))
+    } else {
+        false
+    }
+}
+
+/// Checks whether `cond` is true when a poll succeeded, or when it failed if `succeeded` is false.
+/// An `AtomicBool` says nothing about success, so a flag checked either way counts.
+fn is_poll_outcome(cx: &LateContext, cond: &Expr, succeeded: bool) -> bool {
+    match cond.node {
+        ExprKind::Unary(UnNot, ref inner) => is_poll_outcome(cx, inner, !succeeded),
+        ExprKind::MethodCall(ref method, _, ref args) if INSPECTING_METHODS.contains(&&*method.ident.as_str()) => {
+            let is_success = method.ident.name == "is_ok" || method.ident.name == "is_some";
+            is_success == succeeded && is_poll(cx, &args[0])
+        },
+        _ => is_poll(cx, cond),
+    }
+}
+
+/// Checks whether all the calls in a loop are polls, looking into nested loops but not closures.
+struct BusyWaitVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    other_calls: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BusyWaitVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::MethodCall(ref method, ..) => {
+                if !is_poll(self.cx, expr) && !INSPECTING_METHODS.contains(&&*method.ident.as_str()) {
+                    self.other_calls = true;
+                }
+            },
+            ExprKind::Call(..) => self.other_calls = true,
+            _ => (),
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+fn check_manual_recv_loop<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+    let (pat, receiver) = match while_let_recv(expr).or_else(|| loop_let_recv(expr)) {
+        Some(found) => found,
+        None => return,
+    };
+    let receiver_ty = cx.tables.expr_ty(receiver);
+    if in_external_macro(cx, expr.span)
+        || is_refutable(cx, pat)
+        || !match_type(cx, walk_ptrs_ty(receiver_ty), &paths::RECEIVER)
+    {
+        return;
+    }
+    // `&Receiver` can be iterated over directly, `Receiver` would be consumed
+    let borrow = if receiver_ty.is_region_ptr() { "" } else { "&" };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_RECV_LOOP,
+        expr.span,
+        "this loop could be written as a `for` loop over the receiver",
+        "try",
+        format!(
+            "for {} in {}{} {{ .. }}",
+            snippet(cx, pat.span, "_"),
+            borrow,
+            snippet(cx, receiver.span, "_")
+        ),
+    );
+}
+
+/// Matches `while let Ok(msg) = rx.recv() { .. }`, returning the pattern and the receiver.
+fn while_let_recv(expr: &Expr) -> Option<(&Pat, &Expr)> {
+    if_chain! {
+        if let ExprKind::Loop(ref block, _, LoopSource::WhileLet) = expr.node;
+        if let Some(ref inner) = block.expr;
+        if let ExprKind::Match(ref match_expr, ref arms, MatchSource::WhileLetDesugar) = inner.node;
+        if let PatKind::TupleStruct(ref qpath, ref pat_args, _) = arms[0].pats[0].node;
+        if last_path_segment(qpath).ident.name == "Ok";
+        if let ExprKind::MethodCall(ref method, _, ref args) = match_expr.node;
+        if method.ident.name == "recv";
+        then {
+            return Some((&pat_args[0], &args[0]));
+        }
+    }
+    None
+}
+
+/// Matches `loop { let msg = rx.recv().unwrap(); .. }`, returning the pattern and the receiver.
+fn loop_let_recv(expr: &Expr) -> Option<(&Pat, &Expr)> {
+    if_chain! {
+        if let ExprKind::Loop(ref block, _, LoopSource::Loop) = expr.node;
+        if let Some(stmt) = block.stmts.first();
+        if let StmtKind::Decl(ref decl, _) = stmt.node;
+        if let DeclKind::Local(ref local) = decl.node;
+        if let Some(ref init) = local.init;
+        if let Some(args) = method_chain_args(init, &["recv", "unwrap"])
+            .or_else(|| method_chain_args(init, &["recv", "expect"]));
+        then {
+            return Some((&local.pat, &args[0][0]));
+        }
+    }
+    None
+}
+
 fn is_iterator_used_after_while_let<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, iter_expr: &'tcx Expr) -> bool {
     let def_id = match var_def_id(cx, iter_expr) {
         Some(id) => id,
@@ -0,0 +1,155 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::{BTreeSet, HashMap};
+use syntax::ast::NodeId;
+use syntax::codemap::{ExpnFormat, ExpnInfo, Span};
+
+use crate::utils::{in_macro, span_lint_node_and_then};
+
+/// **What it does:** Checks for `#[macro_use] use...`.
+///
+/// **Why is this bad?** Since the Rust 2018 edition, macros can be imported like any other item
+/// with `use`, which makes it obvious where they come from. `#[macro_use]` imports all the
+/// macros of a crate into every module.
+///
+/// **Known problems:** Only macros that are expanded in the crate are suggested, the suggestion
+/// must be adjusted if a macro is used from several modules.
+///
+/// **Example:**
+/// ```rust,ignore
+/// #[macro_use]
+/// extern crate log;
+/// ```
+///
+/// Use instead:
+///
+/// ```rust,ignore
+/// extern crate log;
+/// use log::{debug, info};
+/// ```
+declare_clippy_lint! {
+    pub MACRO_USE_IMPORTS,
+    pedantic,
+    "#[macro_use] is no longer needed"
+}
+
+struct MacroUseCrate {
+    id: NodeId,
+    /// the span of the `#[macro_use]` attribute
+    span: Span,
+    name: String,
+    /// the index of the crate, as used for the source files it contains
+    krate: u32,
+}
+
+#[derive(Default)]
+pub struct MacroUseImports {
+    crates: Vec<MacroUseCrate>,
+    /// the names of the macros used from each crate
+    used_macros: HashMap<u32, BTreeSet<String>>,
+}
+
+impl LintPass for MacroUseImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MACRO_USE_IMPORTS)
+    }
+}
+
+impl MacroUseImports {
+    fn check_span(&mut self, cx: &LateContext, span: Span) {
+        if !in_macro(span) {
+            return;
+        }
+        if let Some(info) = outermost_expansion(span) {
+            if let (ExpnFormat::MacroBang(name), Some(def_site)) = (info.format, info.def_site) {
+                let file = cx.sess().codemap().lookup_char_pos(def_site.lo()).file;
+                self.used_macros
+                    .entry(file.crate_of_origin)
+                    .or_insert_with(BTreeSet::new)
+                    .insert(name.to_string());
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MacroUseImports {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        self.check_span(cx, item.span);
+        if !cx.sess().rust_2018() {
+            return;
+        }
+        if let ItemKind::ExternCrate(_) = item.node {
+            // `#[macro_use(foo, bar)]` already names the imported macros
+            let attr = item.attrs.iter().find(|attr| attr.check_name("macro_use") && attr.meta_item_list().is_none());
+            if let Some(attr) = attr {
+                let def_id = cx.tcx.hir.local_def_id(item.id);
+                if let Some(krate) = cx.tcx.extern_mod_stmt_cnum(def_id) {
+                    self.crates.push(MacroUseCrate {
+                        id: item.id,
+                        span: attr.span,
+                        name: item.name.to_string(),
+                        krate: krate.as_u32(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        self.check_span(cx, stmt.span);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        self.check_span(cx, expr.span);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        self.check_span(cx, pat.span);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        self.check_span(cx, ty.span);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for krate in &self.crates {
+            // unused `#[macro_use]` attributes are already linted by rustc
+            let macros = match self.used_macros.get(&krate.krate) {
+                Some(macros) => macros.iter().cloned().collect::<Vec<_>>(),
+                None => continue,
+            };
+            let imports = if macros.len() == 1 {
+                macros[0].clone()
+            } else {
+                format!("{{{}}}", macros.join(", "))
+            };
+            span_lint_node_and_then(
+                cx,
+                MACRO_USE_IMPORTS,
+                krate.id,
+                krate.span,
+                "`macro_use` attributes are no longer needed in the Rust 2018 edition",
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        krate.span,
+                        "remove the attribute and import the macro directly, try",
+                        format!("use {}::{};", krate.name, imports),
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the expansion of the macro that was invoked outside of any macro, macros called by
+/// other macros of the same crate don't need to be imported.
+fn outermost_expansion(span: Span) -> Option<ExpnInfo> {
+    let mut info = span.ctxt().outer().expn_info()?;
+    while let Some(outer) = info.call_site.ctxt().outer().expn_info() {
+        info = outer;
+    }
+    Some(info)
+}
@@ -0,0 +1,123 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use semver::Version;
+use syntax::ast::UintTy;
+
+use crate::consts::{constant, Constant};
+use crate::utils::{get_parent_expr, in_macro, match_def_path, meets_msrv, opt_def_id, paths, span_lint_and_sugg};
+
+/// **What it does:** Checks for usage of `std::mem::size_of::<T>() * 8` when
+/// `T::BITS` is available.
+///
+/// **Why is this bad?** `T::BITS` states the intent directly, and doesn't need a cast to get the
+/// `u32` that bit counts are usually taken as.
+///
+/// **Known problems:** Only integer types have `BITS`, so other types are not linted. The `msrv`
+/// configuration option is respected.
+///
+/// **Example:**
+/// ```rust
+/// std::mem::size_of::<usize>() * 8;
+/// ```
+/// Use instead:
+/// ```rust
+/// usize::BITS as usize;
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_BITS,
+    style,
+    "manual implementation of `size_of::<T>() * 8` can be simplified with `T::BITS`"
+}
+
+/// The first version of Rust with a stable `BITS` constant on the integer types.
+const MANUAL_BITS_MSRV: [u64; 3] = [1, 53, 0];
+
+pub struct ManualBits {
+    msrv: Option<Version>,
+}
+
+impl ManualBits {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualBits {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_BITS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualBits {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(ref op, ref left, ref right) = expr.node;
+            if op.node == BinOpKind::Mul;
+            if !in_macro(expr.span);
+            if meets_msrv(self.msrv.as_ref(), &MANUAL_BITS_MSRV);
+            if let Some(ty) = size_of_times_8(cx, left, right).or_else(|| size_of_times_8(cx, right, left));
+            then {
+                // `(size_of::<T>() * 8) as u32` is just `T::BITS`, other casts stay as they are
+                let (span, sugg) = match get_parent_expr(cx, expr) {
+                    Some(parent) if is_cast_to_u32(cx, parent) => (parent.span, format!("{}::BITS", ty)),
+                    Some(&Expr { node: ExprKind::Cast(..), .. }) => (expr.span, format!("{}::BITS", ty)),
+                    // `u32::BITS as usize < n` would parse `usize<` as the start of generic arguments
+                    Some(&Expr { node: ExprKind::Binary(ref op, ..), .. }) if is_comparison_or_shift(op.node) => {
+                        (expr.span, format!("({}::BITS as usize)", ty))
+                    },
+                    _ => (expr.span, format!("{}::BITS as usize", ty)),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_BITS,
+                    span,
+                    "usage of `mem::size_of::<T>()` to obtain the size of `T` in bits",
+                    "consider using",
+                    sugg,
+                );
+            }
+        }
+    }
+}
+
+/// If `size` is `size_of::<T>()` for an integer type `T` and `factor` is `8`, returns `T`.
+fn size_of_times_8<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, size: &'tcx Expr, factor: &'tcx Expr) -> Option<Ty<'tcx>> {
+    if_chain! {
+        if let Some((Constant::Int(8), _)) = constant(cx, cx.tables, factor);
+        if let ExprKind::Call(ref func, ref args) = size.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF);
+        let ty = cx.tables.node_substs(func.hir_id).type_at(0);
+        if ty.is_integral();
+        then {
+            return Some(ty);
+        }
+    }
+    None
+}
+
+fn is_cast_to_u32(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Cast(..) => cx.tables.expr_ty(expr).sty == ty::TyUint(UintTy::U32),
+        _ => false,
+    }
+}
+
+fn is_comparison_or_shift(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Lt
+        | BinOpKind::Le
+        | BinOpKind::Gt
+        | BinOpKind::Ge
+        | BinOpKind::Eq
+        | BinOpKind::Ne
+        | BinOpKind::Shl
+        | BinOpKind::Shr => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,213 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use semver::Version;
+use std::cmp::Ordering;
+
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    in_macro, match_trait_method, meets_msrv, paths, snippet, span_help_and_lint, span_lint_and_sugg, SpanlessEq,
+};
+
+/// **What it does:** Identifies good opportunities for a clamp function from std or core, and
+/// suggests using it.
+///
+/// **Why is this bad?** The clamp function is clearer about what the code does, and doesn't
+/// depend on getting the order of `min` and `max` or the comparisons right.
+///
+/// **Known problems:** Only bounds that are constants are linted, as `clamp` panics if the lower
+/// bound is greater than the upper bound, while the original code doesn't. For the same reason,
+/// constant bounds in the wrong order are reported without a suggestion: the original code then
+/// never evaluates to the input. For floats, `clamp` returns `NaN` for a `NaN` input, while
+/// `x.max(lo).min(hi)` returns `lo`. The `msrv` configuration option is respected.
+///
+/// **Example:**
+/// ```rust
+/// # let input: u32 = 5;
+/// let x = input.max(2).min(8);
+///
+/// let y = if input > 8 {
+///     8
+/// } else if input < 2 {
+///     2
+/// } else {
+///     input
+/// };
+/// ```
+/// Use instead:
+/// ```rust
+/// # let input: u32 = 5;
+/// let x = input.clamp(2, 8);
+/// let y = input.clamp(2, 8);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_CLAMP,
+    complexity,
+    "using a clamp pattern instead of the clamp function"
+}
+
+/// The first version of Rust with a stable `clamp` for `Ord` types and floats.
+const MANUAL_CLAMP_MSRV: [u64; 3] = [1, 50, 0];
+
+pub struct ManualClamp {
+    msrv: Option<Version>,
+}
+
+impl ManualClamp {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualClamp {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_CLAMP)
+    }
+}
+
+/// A clamp-like pattern with its input and bounds.
+struct Clamp<'a> {
+    input: &'a Expr,
+    lo: &'a Expr,
+    hi: &'a Expr,
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualClamp {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let clamp = match min_max_chain(cx, expr).or_else(|| if_chain_clamp(cx, expr)) {
+            Some(clamp) => clamp,
+            None => return,
+        };
+        let (lo, hi) = match (constant(cx, cx.tables, clamp.lo), constant(cx, cx.tables, clamp.hi)) {
+            (Some((lo, _)), Some((hi, _))) => (lo, hi),
+            _ => return,
+        };
+
+        match Constant::partial_cmp(cx.tcx, &cx.tables.expr_ty(clamp.input).sty, &lo, &hi) {
+            Some(Ordering::Greater) => span_help_and_lint(
+                cx,
+                MANUAL_CLAMP,
+                expr.span,
+                "clamp-like pattern with reversed bounds",
+                "the lower bound is greater than the upper bound, so this never evaluates to the input",
+            ),
+            Some(_) if meets_msrv(self.msrv.as_ref(), &MANUAL_CLAMP_MSRV) => span_lint_and_sugg(
+                cx,
+                MANUAL_CLAMP,
+                expr.span,
+                "clamp-like pattern without using clamp function",
+                "replace with clamp",
+                format!(
+                    "{}.clamp({}, {})",
+                    Sugg::hir(cx, clamp.input, "..").maybe_par(),
+                    snippet(cx, clamp.lo.span, ".."),
+                    snippet(cx, clamp.hi.span, "..")
+                ),
+            ),
+            _ => (),
+        }
+    }
+}
+
+/// `x.max(lo).min(hi)` or `x.min(hi).max(lo)`
+fn min_max_chain<'a>(cx: &LateContext, expr: &'a Expr) -> Option<Clamp<'a>> {
+    let (outer, outer_args) = match expr.node {
+        ExprKind::MethodCall(ref path, _, ref args) if args.len() == 2 => (path.ident.name, args),
+        _ => return None,
+    };
+    let (inner, inner_args) = match outer_args[0].node {
+        ExprKind::MethodCall(ref path, _, ref args) if args.len() == 2 => (path.ident.name, args),
+        _ => return None,
+    };
+    let input = &inner_args[0];
+    let is_min_max = |call: &Expr| {
+        cx.tables.expr_ty(input).is_floating_point() || match_trait_method(cx, call, &paths::ORD)
+    };
+    if !is_min_max(expr) || !is_min_max(&outer_args[0]) {
+        return None;
+    }
+
+    if inner == "max" && outer == "min" {
+        Some(Clamp {
+            input,
+            lo: &inner_args[1],
+            hi: &outer_args[1],
+        })
+    } else if inner == "min" && outer == "max" {
+        Some(Clamp {
+            input,
+            lo: &outer_args[1],
+            hi: &inner_args[1],
+        })
+    } else {
+        None
+    }
+}
+
+/// `if x < lo { lo } else if x > hi { hi } else { x }`, with the comparisons in any order and
+/// direction
+fn if_chain_clamp<'a>(cx: &LateContext, expr: &'a Expr) -> Option<Clamp<'a>> {
+    let (first_cond, first_then, first_else) = match expr.node {
+        ExprKind::If(ref cond, ref then, Some(ref els)) => (cond, then, els),
+        _ => return None,
+    };
+    let (second_cond, second_then, second_else) = match first_else.node {
+        ExprKind::If(ref cond, ref then, Some(ref els)) => (cond, then, els),
+        _ => return None,
+    };
+    let input = block_value(second_else)?;
+    let input_ty = cx.tables.expr_ty(input);
+    if !input_ty.is_integral() && !input_ty.is_floating_point() {
+        return None;
+    }
+
+    let (first_is_lower, first_bound) = bound_of(cx, first_cond, input)?;
+    let (second_is_lower, second_bound) = bound_of(cx, second_cond, input)?;
+    let mut eq = SpanlessEq::new(cx);
+    if first_is_lower == second_is_lower
+        || !eq.eq_expr(block_value(first_then)?, first_bound)
+        || !eq.eq_expr(block_value(second_then)?, second_bound)
+    {
+        return None;
+    }
+
+    let (lo, hi) = if first_is_lower {
+        (first_bound, second_bound)
+    } else {
+        (second_bound, first_bound)
+    };
+    Some(Clamp { input, lo, hi })
+}
+
+/// If `cond` compares `input` to a bound, returns whether it's the lower bound, and the bound.
+fn bound_of<'a>(cx: &LateContext, cond: &'a Expr, input: &Expr) -> Option<(bool, &'a Expr)> {
+    if let ExprKind::Binary(op, ref left, ref right) = cond.node {
+        let mut eq = SpanlessEq::new(cx);
+        let is_less = match op.node {
+            BinOpKind::Lt | BinOpKind::Le => true,
+            BinOpKind::Gt | BinOpKind::Ge => false,
+            _ => return None,
+        };
+        if eq.eq_expr(left, input) {
+            Some((is_less, right))
+        } else if eq.eq_expr(right, input) {
+            Some((!is_less, left))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// The value of a block without statements.
+fn block_value(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Block(ref block, _) if block.stmts.is_empty() => block.expr.as_ref().map(|e| &**e),
+        _ => None,
+    }
+}
@@ -0,0 +1,154 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use semver::Version;
+
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_constant, in_macro, meets_msrv, sext, snippet, span_lint_and_sugg, SpanlessEq};
+
+/// **What it does:** Checks for an expression like `((x % 4) + 4) % 4` which is a common manual
+/// reimplementation of `x.rem_euclid(4)`.
+///
+/// **Why is this bad?** It's simpler and more readable to call `rem_euclid`, which also makes
+/// the intent of always getting a non-negative remainder clear.
+///
+/// **Known problems:** For a constant modulus, only positive ones are linted. For a negative
+/// modulus, `rem_euclid` still returns a non-negative remainder, while the original code returns
+/// a non-positive one, so the suggestion is wrong if a variable modulus can be negative. The
+/// `msrv` configuration option is respected.
+///
+/// **Example:**
+/// ```rust
+/// let x: i32 = 24;
+/// let rem = ((x % 4) + 4) % 4;
+/// ```
+/// Use instead:
+/// ```rust
+/// let x: i32 = 24;
+/// let rem = x.rem_euclid(4);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_REM_EUCLID,
+    complexity,
+    "manually reimplementing `rem_euclid`"
+}
+
+/// The first version of Rust with a stable `rem_euclid`.
+const MANUAL_REM_EUCLID_MSRV: [u64; 3] = [1, 38, 0];
+
+pub struct ManualRemEuclid {
+    msrv: Option<Version>,
+}
+
+impl ManualRemEuclid {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualRemEuclid {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_REM_EUCLID)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualRemEuclid {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || !meets_msrv(self.msrv.as_ref(), &MANUAL_REM_EUCLID_MSRV) {
+            return;
+        }
+        // `((x % m) + m) % m`, with the operands of the addition in either order
+        let (sum, outer_m) = match expr.node {
+            ExprKind::Binary(op, ref sum, ref m) if op.node == BinOpKind::Rem => (sum, m),
+            _ => return,
+        };
+        let (rem, add_m) = match sum.node {
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::Add => if is_rem(lhs) {
+                (lhs, rhs)
+            } else if is_rem(rhs) {
+                (rhs, lhs)
+            } else {
+                return;
+            },
+            _ => return,
+        };
+        let (x, inner_m) = match rem.node {
+            ExprKind::Binary(_, ref x, ref m) => (x, m),
+            _ => return,
+        };
+
+        let ty = cx.tables.expr_ty(expr);
+        if !ty.is_integral() && !ty.is_floating_point() {
+            return;
+        }
+        let mut eq = SpanlessEq::new(cx);
+        if !eq.eq_expr(inner_m, add_m) || !eq.eq_expr(inner_m, outer_m) {
+            return;
+        }
+        if let Some((m, _)) = constant(cx, cx.tables, inner_m) {
+            if !is_positive(cx, &ty.sty, &m) {
+                return;
+            }
+        }
+        // `rem_euclid` can't be called in constants
+        if in_constant(cx, expr.id) {
+            return;
+        }
+
+        // the span of `x` includes the parentheses around it, if there are any
+        let recv = snippet(cx, x.span, "..");
+        let recv = if is_parenthesized(&recv) {
+            recv.into_owned()
+        } else {
+            Sugg::hir(cx, x, "..").maybe_par().to_string()
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_REM_EUCLID,
+            expr.span,
+            "manual `rem_euclid` implementation",
+            "consider using",
+            format!("{}.rem_euclid({})", recv, snippet(cx, inner_m.span, "..")),
+        );
+    }
+}
+
+fn is_rem(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Binary(op, ..) => op.node == BinOpKind::Rem,
+        _ => false,
+    }
+}
+
+/// Checks if the whole of `snippet` is wrapped in one pair of parentheses, unlike `(a) * (b)`.
+fn is_parenthesized(snippet: &str) -> bool {
+    if !snippet.starts_with('(') {
+        return false;
+    }
+    let mut depth = 0;
+    for (i, c) in snippet.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == snippet.len() - 1;
+                }
+            },
+            _ => (),
+        }
+    }
+    false
+}
+
+fn is_positive(cx: &LateContext, ty: &ty::TypeVariants, constant: &Constant) -> bool {
+    match (constant, ty) {
+        (&Constant::Int(n), &ty::TyInt(ity)) => sext(cx.tcx, n, ity) > 0,
+        (&Constant::Int(n), _) => n > 0,
+        (&Constant::F32(f), _) => f > 0.0,
+        (&Constant::F64(f), _) => f > 0.0,
+        _ => false,
+    }
+}
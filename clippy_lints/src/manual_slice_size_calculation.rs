@@ -0,0 +1,96 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_macro, match_def_path, opt_def_id, paths, snippet, span_lint_and_sugg, walk_ptrs_ty};
+
+/// **What it does:** Checks for calculations of the size of a slice in bytes with
+/// `slice.len() * size_of::<T>()`.
+///
+/// **Why is this bad?** `std::mem::size_of_val` does the same, and can't end up using the size
+/// of the wrong type when the element type of the slice is changed.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// # let data: &[i32] = &[1, 2, 3];
+/// let newlen = data.len() * std::mem::size_of::<i32>();
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # let data: &[i32] = &[1, 2, 3];
+/// let newlen = std::mem::size_of_val(data);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_SLICE_SIZE_CALCULATION,
+    complexity,
+    "manual slice size calculation"
+}
+
+pub struct ManualSliceSizeCalculation;
+
+impl LintPass for ManualSliceSizeCalculation {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_SLICE_SIZE_CALCULATION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualSliceSizeCalculation {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(ref op, ref left, ref right) = expr.node;
+            if op.node == BinOpKind::Mul;
+            if !in_macro(expr.span);
+            if let Some(slice) = slice_len_times_size(cx, left, right).or_else(|| slice_len_times_size(cx, right, left));
+            then {
+                let is_ref = cx.tables.expr_ty(slice).is_region_ptr();
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_SLICE_SIZE_CALCULATION,
+                    expr.span,
+                    "manual slice size calculation",
+                    "try",
+                    format!(
+                        "std::mem::size_of_val({}{})",
+                        if is_ref { "" } else { "&" },
+                        snippet(cx, slice.span, "..")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// If `len` is `slice.len()` and `size` is `size_of::<T>()` for the element type `T` of the slice,
+/// returns `slice`.
+fn slice_len_times_size<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, len: &'tcx Expr, size: &'tcx Expr) -> Option<&'tcx Expr> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method, _, ref args) = len.node;
+        if method.ident.name == "len" && args.len() == 1;
+        if let ty::TySlice(elem_ty) = walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty;
+        if size_of_ty(cx, size) == Some(elem_ty);
+        then {
+            return Some(&args[0]);
+        }
+    }
+    None
+}
+
+/// If `expr` is `size_of::<T>()`, returns `T`.
+fn size_of_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<Ty<'tcx>> {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF);
+        then {
+            return Some(cx.tables.node_substs(func.hir_id).type_at(0));
+        }
+    }
+    None
+}
@@ -4,6 +4,7 @@ use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
+use rustc::ty::layout::LayoutOf;
 use rustc::hir::def::Def;
 use std::borrow::Cow;
 use std::fmt;
@@ -12,14 +13,24 @@ use syntax::ast;
 use syntax::codemap::{Span, BytePos};
 use crate::utils::{get_arg_name, get_trait_def_id, implements_trait, in_external_macro, in_macro, is_copy, is_expn_of, is_self,
             is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath, match_trait_method,
-            match_type, method_chain_args, match_var, return_ty, remove_blocks, same_tys, single_segment_path, snippet,
+            match_type, meets_msrv, method_chain_args, match_var, opt_def_id, return_ty, remove_blocks, same_tys, single_segment_path, snippet,
             span_lint, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, SpanlessEq};
+use crate::utils::higher;
 use crate::utils::paths;
 use crate::utils::sugg;
 use crate::consts::{constant, Constant};
+use semver::Version;
 
 #[derive(Clone)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<Version>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
 
 /// **What it does:** Checks for `.unwrap()` calls on `Option`s.
 ///
@@ -676,6 +687,79 @@ declare_clippy_lint! {
     "using `fold` when a more succinct alternative exists"
 }
 
+/// **What it does:** Checks for `offset(_)`, `add(_)`, `sub(_)` and their `wrapping_` variants
+/// on raw pointers to zero-sized types.
+///
+/// **Why is this bad?** The resulting pointer is the same as the original one, which is rarely
+/// what was intended.
+///
+/// **Known problems:** Pointers whose pointee is only a ZST for some instantiation of a generic
+/// type are not linted.
+///
+/// **Example:**
+/// ```rust
+/// unsafe { (&() as *const ()).offset(1) };
+/// ```
+declare_clippy_lint! {
+    pub ZST_OFFSET,
+    correctness,
+    "offset calculations on raw pointers to zero-sized types"
+}
+
+/// **What it does:** Checks for `extend` calls on a `Vec` or `VecDeque` with an
+/// iterator of a single element, like `iter::once(x)` or `Some(x)`.
+///
+/// **Why is this bad?** `push` (or `push_back`) says the same more directly,
+/// without going through an iterator.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = vec![1];
+/// v.extend(std::iter::once(2));
+/// v.extend(Some(3));
+/// ```
+/// Could be written as:
+/// ```rust
+/// let mut v = vec![1];
+/// v.push(2);
+/// v.push(3);
+/// ```
+declare_clippy_lint! {
+    pub EXTEND_WITH_SINGLE_ELEMENT,
+    style,
+    "using `extend` with an iterator of a single element instead of `push`"
+}
+
+/// **What it does:** Checks for `drain` calls over the whole of a `Vec`,
+/// `VecDeque`, `String` or a map or set that are directly collected into the
+/// same collection type.
+///
+/// **Why is this bad?** `std::mem::take` moves the whole allocation over and
+/// leaves an empty collection behind, while draining and collecting moves the
+/// elements one at a time into a new allocation.
+///
+/// **Known problems:** Only `..` and `0..` are recognized as ranges covering
+/// the whole `Vec`, `VecDeque` or `String`. If the `msrv` configuration option
+/// is older than `std::mem::take`, `std::mem::replace` is suggested instead.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = vec![1, 2, 3];
+/// let w: Vec<_> = v.drain(..).collect();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let mut v = vec![1, 2, 3];
+/// let w = std::mem::take(&mut v);
+/// ```
+declare_clippy_lint! {
+    pub DRAIN_COLLECT,
+    perf,
+    "calling `.drain(..).collect()` to move all elements into a new collection"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
@@ -708,13 +792,16 @@ impl LintPass for Pass {
             STRING_EXTEND_CHARS,
             ITER_CLONED_COLLECT,
             USELESS_ASREF,
-            UNNECESSARY_FOLD
+            UNNECESSARY_FOLD,
+            ZST_OFFSET,
+            EXTEND_WITH_SINGLE_ELEMENT,
+            DRAIN_COLLECT
         )
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
-    #[allow(cyclomatic_complexity)]
+    #[allow(cognitive_complexity)]
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
         if in_macro(expr.span) {
             return;
@@ -766,6 +853,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                     lint_iter_skip_next(cx, expr);
                 } else if let Some(arglists) = method_chain_args(expr, &["cloned", "collect"]) {
                     lint_iter_cloned_collect(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["drain", "collect"]) {
+                    lint_drain_collect(cx, self.msrv.as_ref(), expr, arglists[0]);
                 } else if let Some(arglists) = method_chain_args(expr, &["as_ref"]) {
                     lint_asref(cx, expr, "as_ref", arglists[0]);
                 } else if let Some(arglists) = method_chain_args(expr, &["as_mut"]) {
@@ -789,6 +878,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                             lint_single_char_pattern(cx, expr, &args[pos]);
                         }
                     },
+                    ty::TyRawPtr(ty::TypeAndMut { ty: pointee, .. }) if args.len() == 2 => {
+                        lint_zst_offset(cx, expr, &method_call.ident.as_str(), pointee);
+                    },
                     _ => (),
                 }
             },
@@ -1227,6 +1319,39 @@ fn lint_extend(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr]) {
     let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
     if match_type(cx, obj_ty, &paths::STRING) {
         lint_string_extend(cx, expr, args);
+    } else if match_type(cx, obj_ty, &paths::VEC) {
+        lint_extend_with_single_element(cx, expr, args, "push");
+    } else if match_type(cx, obj_ty, &paths::VEC_DEQUE) {
+        lint_extend_with_single_element(cx, expr, args, "push_back");
+    }
+}
+
+fn lint_extend_with_single_element(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr], push: &str) {
+    if_chain! {
+        if let hir::ExprKind::Call(ref fun, ref fun_args) = args[1].node;
+        if fun_args.len() == 1;
+        if let hir::ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        if match_def_path(cx.tcx, def_id, &paths::ITER_ONCE) || match_def_path(cx.tcx, def_id, &paths::OPTION_SOME);
+        if !in_macro(args[1].span);
+        // `Vec<T>` also extends from references to `T: Copy`, which `push` doesn't take
+        if let ty::TyAdt(_, substs) = walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty;
+        if substs.type_at(0) == cx.tables.expr_ty(&fun_args[0]);
+        then {
+            span_lint_and_sugg(
+                cx,
+                EXTEND_WITH_SINGLE_ELEMENT,
+                expr.span,
+                "calling `extend` with an iterator of a single element",
+                "try this",
+                format!(
+                    "{}.{}({})",
+                    snippet(cx, args[0].span, "_"),
+                    push,
+                    snippet(cx, fun_args[0].span, "_")
+                ),
+            );
+        }
     }
 }
 
@@ -1265,6 +1390,63 @@ fn lint_iter_cloned_collect(cx: &LateContext, expr: &hir::Expr, iter_args: &[hir
     }
 }
 
+/// The first version of Rust with a stable `std::mem::take`.
+const MEM_TAKE_MSRV: [u64; 3] = [1, 40, 0];
+
+/// Checks for `.drain(..).collect()` into the type of the drained collection.
+fn lint_drain_collect(cx: &LateContext, msrv: Option<&Version>, expr: &hir::Expr, drain_args: &[hir::Expr]) {
+    let recv = &drain_args[0];
+    let recv_ty = cx.tables.expr_ty(recv);
+    let coll_ty = walk_ptrs_ty(recv_ty);
+    let full = if match_type(cx, coll_ty, &paths::VEC)
+        || match_type(cx, coll_ty, &paths::VEC_DEQUE)
+        || match_type(cx, coll_ty, &paths::STRING)
+    {
+        drain_args.len() == 2 && is_full_range(cx, &drain_args[1])
+    } else {
+        (match_type(cx, coll_ty, &paths::HASHMAP)
+            || match_type(cx, coll_ty, &paths::HASHSET)
+            || match_type(cx, coll_ty, &paths::BINARY_HEAP))
+            && drain_args.len() == 1
+    };
+    if !full || !same_tys(cx, cx.tables.expr_ty(expr), coll_ty) {
+        return;
+    }
+
+    let recv_snippet = snippet(cx, recv.span, "..");
+    let recv_sugg = if recv_ty.is_region_ptr() {
+        recv_snippet.into_owned()
+    } else {
+        format!("&mut {}", recv_snippet)
+    };
+    let (help, sugg) = if meets_msrv(msrv, &MEM_TAKE_MSRV) {
+        ("consider using `mem::take`", format!("std::mem::take({})", recv_sugg))
+    } else {
+        (
+            "consider using `mem::replace`",
+            format!("std::mem::replace({}, Default::default())", recv_sugg),
+        )
+    };
+    span_lint_and_sugg(
+        cx,
+        DRAIN_COLLECT,
+        expr.span,
+        "you seem to be trying to move all elements into a new collection",
+        help,
+        sugg,
+    );
+}
+
+/// Is `range` either `..` or `0..`?
+fn is_full_range(cx: &LateContext, range: &hir::Expr) -> bool {
+    higher::range(cx, range).map_or(false, |range| {
+        range.end.is_none()
+            && range
+                .start
+                .map_or(true, |start| constant(cx, cx.tables, start).map_or(false, |(c, _)| c == Constant::Int(0)))
+    })
+}
+
 fn lint_unnecessary_fold(cx: &LateContext, expr: &hir::Expr, fold_args: &[hir::Expr]) {
     // Check that this is a call to Iterator::fold rather than just some function called fold
     if !match_trait_method(cx, expr, &paths::ITERATOR) {
@@ -1353,6 +1535,19 @@ fn lint_unnecessary_fold(cx: &LateContext, expr: &hir::Expr, fold_args: &[hir::E
     };
 }
 
+fn lint_zst_offset<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &hir::Expr, method: &str, pointee: Ty<'tcx>) {
+    const OFFSET_METHODS: [&str; 6] = ["offset", "add", "sub", "wrapping_offset", "wrapping_add", "wrapping_sub"];
+
+    if_chain! {
+        if OFFSET_METHODS.contains(&method);
+        if let Ok(layout) = cx.layout_of(pointee);
+        if layout.size.bytes() == 0;
+        then {
+            span_lint(cx, ZST_OFFSET, expr.span, "offset calculation on zero-sized value");
+        }
+    }
+}
+
 fn lint_iter_nth(cx: &LateContext, expr: &hir::Expr, iter_args: &[hir::Expr], is_mut: bool) {
     let mut_str = if is_mut { "_mut" } else { "" };
     let caller_type = if derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_some() {
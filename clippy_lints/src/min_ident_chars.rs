@@ -0,0 +1,123 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use std::collections::HashSet;
+use syntax::ast::*;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_lint};
+
+/// **What it does:** Checks for idents which comprise of a single letter, or more generally
+/// whose length is at most the `min-ident-chars-threshold` configuration option. Bindings,
+/// function parameters and lifetime names are checked.
+///
+/// **Why is this bad?** In many cases it's not, but at times it can severely hinder readability.
+/// Some codebases may wish to disallow this, to improve readability.
+///
+/// **Known problems:** Patterns of closure parameters and `for` loops, as well as struct field
+/// shorthands, are not linted. The idents in the `allowed-idents-below-min-chars` configuration
+/// option (by default `i`, `j`, `x`, `y`, `z`, `w` and `n`) are allowed everywhere.
+///
+/// **Example:**
+/// ```rust,ignore
+/// for m in movies {
+///     let title = m.t;
+/// }
+/// ```
+/// Use instead:
+/// ```rust,ignore
+/// for movie in movies {
+///     let title = movie.title;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MIN_IDENT_CHARS,
+    restriction,
+    "disallows idents that are too short"
+}
+
+pub struct MinIdentChars {
+    allowed_idents_below_min_chars: Vec<String>,
+    min_ident_chars_threshold: u64,
+    /// spans of closure parameters and `for` loop patterns, which are not linted
+    allowed_regions: Vec<Span>,
+    /// ids of struct field shorthand patterns, which are not linted
+    shorthands: HashSet<NodeId>,
+}
+
+impl MinIdentChars {
+    pub fn new(allowed_idents_below_min_chars: Vec<String>, min_ident_chars_threshold: u64) -> Self {
+        Self {
+            allowed_idents_below_min_chars,
+            min_ident_chars_threshold,
+            allowed_regions: Vec::new(),
+            shorthands: HashSet::new(),
+        }
+    }
+
+    fn check_ident(&self, cx: &EarlyContext, ident: Ident, name: &str) {
+        if in_macro(ident.span)
+            || name.chars().count() as u64 > self.min_ident_chars_threshold
+            || self.allowed_idents_below_min_chars.iter().any(|allowed| allowed == name)
+        {
+            return;
+        }
+        let msg = if self.min_ident_chars_threshold == 1 {
+            "this ident consists of a single char".to_string()
+        } else {
+            format!(
+                "this ident is too short ({} <= {})",
+                name.chars().count(),
+                self.min_ident_chars_threshold
+            )
+        };
+        span_lint(cx, MIN_IDENT_CHARS, ident.span, &msg);
+    }
+}
+
+impl LintPass for MinIdentChars {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MIN_IDENT_CHARS)
+    }
+}
+
+impl EarlyLintPass for MinIdentChars {
+    fn check_expr(&mut self, _: &EarlyContext, expr: &Expr) {
+        match expr.node {
+            ExprKind::Closure(.., ref decl, _, _) => {
+                self.allowed_regions.extend(decl.inputs.iter().map(|arg| arg.pat.span));
+            },
+            ExprKind::ForLoop(ref pat, ..) => self.allowed_regions.push(pat.span),
+            _ => (),
+        }
+    }
+
+    fn check_pat(&mut self, cx: &EarlyContext, pat: &Pat) {
+        match pat.node {
+            PatKind::Ident(_, ident, _) => {
+                if self.shorthands.contains(&pat.id) || self.allowed_regions.iter().any(|region| region.contains(pat.span)) {
+                    return;
+                }
+                self.check_ident(cx, ident, &ident.as_str());
+            },
+            // the name of the binding is the name of the field
+            PatKind::Struct(_, ref fields, _) => {
+                self.shorthands.extend(
+                    fields
+                        .iter()
+                        .filter(|field| field.node.is_shorthand)
+                        .map(|field| field.node.pat.id),
+                );
+            },
+            _ => (),
+        }
+    }
+
+    fn check_generics(&mut self, cx: &EarlyContext, generics: &Generics) {
+        for param in &generics.params {
+            if let GenericParamKind::Lifetime { .. } = param.kind {
+                let name = param.ident.as_str();
+                self.check_ident(cx, param.ident, name.trim_left_matches('\''));
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::map::Node::NodeItem;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use rustc_target::spec::abi::Abi;
+use semver::Version;
+use syntax::ast::{NodeId, CRATE_NODE_ID};
+use syntax::codemap::Span;
+use crate::utils::{in_macro, meets_msrv, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Suggests the use of `const` in functions and methods where possible.
+///
+/// **Why is this bad?** Not having the function const prevents callers of the function from being
+/// const as well.
+///
+/// **Known problems:** Const functions are currently still being worked on, with some features
+/// only being available on nightly. The lint follows the rules of the first stable version of
+/// `const fn` (Rust 1.31), so most of the function bodies it accepts contain only arithmetic,
+/// constructors and calls to other `const fn`s. The `msrv` configuration option is respected.
+///
+/// Also, making a public function `const` is a promise to the users of the crate that can't be
+/// taken back without a breaking change.
+///
+/// **Example:**
+///
+/// ```rust
+/// fn new() -> Self {
+///     Self { random_number: 42 }
+/// }
+/// ```
+///
+/// Could be a const fn:
+///
+/// ```rust
+/// const fn new() -> Self {
+///     Self { random_number: 42 }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_CONST_FOR_FN,
+    nursery,
+    "Lint functions definitions that could be made `const fn`"
+}
+
+/// The first version of Rust with stable `const fn`s.
+const CONST_FN_MSRV: [u64; 3] = [1, 31, 0];
+
+pub struct MissingConstForFn {
+    msrv: Option<Version>,
+}
+
+impl MissingConstForFn {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for MissingConstForFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_CONST_FOR_FN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if !meets_msrv(self.msrv.as_ref(), &CONST_FN_MSRV) {
+            return;
+        }
+
+        let def_id = cx.tcx.hir.local_def_id(node_id);
+        if in_macro(span) || cx.tcx.is_const_fn(def_id) {
+            return;
+        }
+
+        let header = match kind {
+            FnKind::ItemFn(name, generics, header, ..) => {
+                // the entry point can't be `const`
+                if name == "main" && cx.tcx.hir.get_parent(node_id) == CRATE_NODE_ID {
+                    return;
+                }
+                if !generics.params.is_empty() {
+                    return;
+                }
+                header
+            },
+            FnKind::Method(_, sig, ..) => {
+                // trait methods can't be `const`
+                match cx.tcx.hir.find(cx.tcx.hir.get_parent(node_id)) {
+                    Some(NodeItem(&Item {
+                        node: ItemKind::Impl(_, _, _, ref generics, None, _, _),
+                        ..
+                    })) if generics.params.is_empty() => (),
+                    _ => return,
+                }
+                if !cx.tcx.generics_of(def_id).params.is_empty() {
+                    return;
+                }
+                sig.header
+            },
+            FnKind::Closure(..) => return,
+        };
+        // `const unsafe fn`s and `const extern fn`s weren't stable yet
+        if header.unsafety == Unsafety::Unsafe || header.abi != Abi::Rust {
+            return;
+        }
+
+        // nothing could be observed from calling a `const fn` that returns `()`
+        if let FunctionRetTy::DefaultReturn(_) = decl.output {
+            return;
+        }
+
+        let sig = cx.tcx.fn_sig(def_id);
+        let sig = sig.skip_binder();
+        if !sig.inputs_and_output.iter().all(|ty| is_const_ty(ty)) {
+            return;
+        }
+        let simple_args = body.arguments.iter().all(|arg| {
+            matches!(arg.pat.node, PatKind::Binding(BindingAnnotation::Unannotated, ..) | PatKind::Wild)
+        });
+        if !simple_args {
+            return;
+        }
+        if !is_const_expr(cx, &body.value) {
+            return;
+        }
+        // a `const fn` can't run destructors, local 0 is the return place, which isn't dropped
+        let mir = cx.tcx.optimized_mir(def_id);
+        if mir
+            .local_decls
+            .iter()
+            .skip(1)
+            .any(|local| local.ty.needs_drop(cx.tcx, cx.param_env))
+        {
+            return;
+        }
+
+        let fn_span = span.with_hi(decl.output.span().hi());
+        span_lint_and_then(cx, MISSING_CONST_FOR_FN, fn_span, "this could be a `const fn`", |db| {
+            if let Some(snippet) = snippet_opt(cx, fn_span) {
+                db.span_suggestion_with_applicability(
+                    fn_span,
+                    "make the function `const`",
+                    snippet.replacen("fn ", "const fn ", 1),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        });
+    }
+}
+
+/// Can values of this type be handled in a `const fn`? Mutable references, raw pointers, function
+/// pointers and trait objects weren't allowed.
+fn is_const_ty(ty: Ty) -> bool {
+    ty.walk().all(|t| match t.sty {
+        ty::TyRef(_, _, MutMutable)
+        | ty::TyRawPtr(_)
+        | ty::TyFnPtr(_)
+        | ty::TyDynamic(..)
+        | ty::TyAnon(..)
+        | ty::TyParam(_) => false,
+        _ => true,
+    })
+}
+
+/// Is this expression allowed in a `const fn` body?
+fn is_const_expr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> bool {
+    let all_const = |exprs: &'tcx [Expr]| exprs.iter().all(|e| is_const_expr(cx, e));
+    match expr.node {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::Local(_) | Def::Const(_) | Def::AssociatedConst(_) | Def::StructCtor(..) | Def::VariantCtor(..) => {
+                true
+            },
+            _ => false,
+        },
+        ExprKind::Tup(ref exprs) | ExprKind::Array(ref exprs) => all_const(exprs),
+        ExprKind::Struct(_, ref fields, ref base) => {
+            fields.iter().all(|field| is_const_expr(cx, &field.expr))
+                && base.as_ref().map_or(true, |base| is_const_expr(cx, base))
+        },
+        ExprKind::Field(ref base, _) => is_const_expr(cx, base),
+        ExprKind::AddrOf(MutImmutable, ref inner) => is_const_expr(cx, inner),
+        ExprKind::Unary(UnDeref, ref inner) => {
+            cx.tables.expr_ty(inner).is_region_ptr() && is_const_expr(cx, inner)
+        },
+        ExprKind::Unary(_, ref inner) => is_const_operand(cx, inner),
+        // `&&` and `||` are control flow, which wasn't allowed
+        ExprKind::Binary(op, ref lhs, ref rhs) => {
+            op.node != BinOpKind::And
+                && op.node != BinOpKind::Or
+                && is_const_operand(cx, lhs)
+                && is_const_operand(cx, rhs)
+        },
+        ExprKind::Cast(ref inner, _) => {
+            cx.tables.expr_ty(inner).is_integral()
+                && cx.tables.expr_ty(expr).is_integral()
+                && is_const_expr(cx, inner)
+        },
+        ExprKind::Call(ref callee, ref args) => {
+            let callee_is_const = if let ExprKind::Path(ref qpath) = callee.node {
+                match cx.tables.qpath_def(qpath, callee.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) => cx.tcx.is_const_fn(def_id),
+                    Def::StructCtor(..) | Def::VariantCtor(..) => true,
+                    _ => false,
+                }
+            } else {
+                false
+            };
+            callee_is_const && all_const(args)
+        },
+        ExprKind::MethodCall(_, _, ref args) => {
+            cx.tables
+                .type_dependent_defs()
+                .get(expr.hir_id)
+                .map_or(false, |def| cx.tcx.is_const_fn(def.def_id()))
+                && all_const(args)
+        },
+        // `let` bindings weren't allowed, only items and a tail expression
+        ExprKind::Block(ref block, _) => {
+            let only_items = block.stmts.iter().all(|stmt| match stmt.node {
+                StmtKind::Decl(ref decl, _) => matches!(decl.node, DeclKind::Item(_)),
+                _ => false,
+            });
+            only_items && block.expr.as_ref().map_or(true, |e| is_const_expr(cx, e))
+        },
+        _ => false,
+    }
+}
+
+/// Is this a `const` operand of a unary or binary operator? Only operations on integers, `bool`s and
+/// `char`s were allowed.
+fn is_const_operand<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> bool {
+    let ty = cx.tables.expr_ty(expr);
+    (ty.is_integral() || ty.is_bool() || ty.is_char()) && is_const_expr(cx, expr)
+}
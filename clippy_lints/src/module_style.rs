@@ -0,0 +1,133 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use std::path::{Path, PathBuf};
+use syntax::ast::*;
+use syntax::codemap::FileName;
+
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks that module layout uses only self named module files, bans `mod.rs`
+/// files.
+///
+/// **Why is this bad?** Having multiple module layout styles in a project can be confusing.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```text
+/// src/
+///   stuff/
+///     stuff_files.rs
+///     mod.rs
+///   lib.rs
+/// ```
+/// Use instead:
+/// ```text
+/// src/
+///   stuff/
+///     stuff_files.rs
+///   stuff.rs
+///   lib.rs
+/// ```
+declare_clippy_lint! {
+    pub MOD_MODULE_FILES,
+    restriction,
+    "checks that module layout is consistent"
+}
+
+/// **What it does:** Checks that module layout uses only `mod.rs` files.
+///
+/// **Why is this bad?** Having multiple module layout styles in a project can be confusing.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```text
+/// src/
+///   stuff/
+///     stuff_files.rs
+///   stuff.rs
+///   lib.rs
+/// ```
+/// Use instead:
+/// ```text
+/// src/
+///   stuff/
+///     stuff_files.rs
+///     mod.rs
+///   lib.rs
+/// ```
+declare_clippy_lint! {
+    pub SELF_NAMED_MODULE_FILES,
+    restriction,
+    "checks that module layout is consistent"
+}
+
+pub struct ModStyle;
+
+impl LintPass for ModStyle {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MOD_MODULE_FILES, SELF_NAMED_MODULE_FILES)
+    }
+}
+
+impl EarlyLintPass for ModStyle {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        let module = match item.node {
+            ItemKind::Mod(ref module) if !module.inline => module,
+            _ => return,
+        };
+        let path = match module_file(cx, module) {
+            Some(path) => path,
+            None => return,
+        };
+
+        if path.file_name().map_or(false, |name| name == "mod.rs") {
+            if let Some(dir) = path.parent() {
+                span_help_and_lint(
+                    cx,
+                    MOD_MODULE_FILES,
+                    item.span,
+                    &format!("`mod.rs` files are not allowed, found `{}`", path.display()),
+                    &format!("move `{}` to `{}`", path.display(), dir.with_extension("rs").display()),
+                );
+            }
+        } else if has_submodule_files(cx, module, &path.with_extension("")) {
+            // a `foo.rs` file with the submodules in `foo/`
+            span_help_and_lint(
+                cx,
+                SELF_NAMED_MODULE_FILES,
+                item.span,
+                &format!("`mod.rs` files are required, found `{}`", path.display()),
+                &format!(
+                    "move `{}` to `{}`",
+                    path.display(),
+                    path.with_extension("").join("mod.rs").display()
+                ),
+            );
+        }
+    }
+}
+
+/// Returns the path of the file containing the module, if it comes from a file.
+fn module_file(cx: &EarlyContext, module: &Mod) -> Option<PathBuf> {
+    match cx.sess().codemap().span_to_filename(module.inner) {
+        FileName::Real(path) => Some(path),
+        _ => None,
+    }
+}
+
+/// Does the module have submodules in files of the directory `dir`?
+fn has_submodule_files(cx: &EarlyContext, module: &Mod, dir: &Path) -> bool {
+    module.items.iter().any(|item| match item.node {
+        ItemKind::Mod(ref submodule) if !submodule.inline => {
+            module_file(cx, submodule).map_or(false, |path| path.starts_with(dir))
+        },
+        _ => false,
+    })
+}
@@ -0,0 +1,163 @@
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** Checks for `unsafe` blocks that contain more than one unsafe operation,
+/// i.e. raw pointer dereferences, calls to unsafe functions or methods, reads of union fields,
+/// accesses to mutable statics and inline assembly.
+///
+/// **Why is this bad?** Each unsafe operation has its own preconditions. With one operation per
+/// block, every block can be justified by its own `// SAFETY:` comment, and it's obvious which
+/// operation a comment is about.
+///
+/// **Known problems:** Nested `unsafe` blocks are counted on their own.
+///
+/// **Example:**
+/// ```rust
+/// # let ptr = &5 as *const i32;
+/// unsafe {
+///     let x = *ptr;
+///     std::ptr::read(ptr.offset(x as isize));
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # let ptr = &5 as *const i32;
+/// // SAFETY: `ptr` is valid for reads
+/// let x = unsafe { *ptr };
+/// // SAFETY: `ptr` points into an allocation of at least `x + 1` elements
+/// unsafe { std::ptr::read(ptr.offset(x as isize)) };
+/// ```
+declare_clippy_lint! {
+    pub MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+    restriction,
+    "more than one unsafe operation per `unsafe` block"
+}
+
+pub struct MultipleUnsafeOpsPerBlock;
+
+impl LintPass for MultipleUnsafeOpsPerBlock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MULTIPLE_UNSAFE_OPS_PER_BLOCK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MultipleUnsafeOpsPerBlock {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        match block.rules {
+            BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) if !in_macro(block.span) => (),
+            _ => return,
+        }
+        let mut visitor = UnsafeOpVisitor { cx, ops: Vec::new() };
+        visitor.visit_block_contents(block);
+        if visitor.ops.len() > 1 {
+            span_lint_and_then(
+                cx,
+                MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+                block.span,
+                &format!(
+                    "this `unsafe` block contains {} unsafe operations, expected only one",
+                    visitor.ops.len()
+                ),
+                |db| {
+                    for (span, msg) in visitor.ops {
+                        db.span_note(span, msg);
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Collects the unsafe operations in an `unsafe` block, including in closures, but not in
+/// nested `unsafe` blocks.
+struct UnsafeOpVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    ops: Vec<(Span, &'static str)>,
+}
+
+impl<'a, 'tcx> UnsafeOpVisitor<'a, 'tcx> {
+    fn visit_block_contents(&mut self, block: &'tcx Block) {
+        for stmt in &block.stmts {
+            self.visit_stmt(stmt);
+        }
+        if let Some(ref expr) = block.expr {
+            self.visit_expr(expr);
+        }
+    }
+
+    fn is_union_field(&self, expr: &Expr) -> bool {
+        if let ExprKind::Field(ref base, _) = expr.node {
+            if let ty::TyAdt(adt, _) = walk_ptrs_ty(self.cx.tables.expr_ty(base)).sty {
+                return adt.is_union();
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeOpVisitor<'a, 'tcx> {
+    fn visit_block(&mut self, block: &'tcx Block) {
+        if let BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) = block.rules {
+            return;
+        }
+        self.visit_block_contents(block);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        let cx = self.cx;
+        match expr.node {
+            ExprKind::Unary(UnDeref, ref inner) if cx.tables.expr_ty(inner).is_unsafe_ptr() => {
+                self.ops.push((expr.span, "raw pointer dereference occurs here"));
+            },
+            ExprKind::Call(ref func, _) => {
+                let fn_ty = cx.tables.expr_ty(func);
+                match fn_ty.sty {
+                    ty::TyFnDef(..) | ty::TyFnPtr(_)
+                        if fn_ty.fn_sig(cx.tcx).skip_binder().unsafety == Unsafety::Unsafe =>
+                    {
+                        self.ops.push((expr.span, "unsafe function call occurs here"));
+                    },
+                    _ => (),
+                }
+            },
+            ExprKind::MethodCall(..) => {
+                if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    if cx.tcx.fn_sig(def.def_id()).skip_binder().unsafety == Unsafety::Unsafe {
+                        self.ops.push((expr.span, "unsafe method call occurs here"));
+                    }
+                }
+            },
+            // writing to a union field is safe
+            ExprKind::Assign(ref lhs, ref rhs) if self.is_union_field(lhs) => {
+                if let ExprKind::Field(ref base, _) = lhs.node {
+                    self.visit_expr(base);
+                }
+                self.visit_expr(rhs);
+                return;
+            },
+            ExprKind::Field(..) if self.is_union_field(expr) => {
+                self.ops.push((expr.span, "union field access occurs here"));
+            },
+            ExprKind::Path(ref qpath) => {
+                if let Def::Static(_, true) = cx.tables.qpath_def(qpath, expr.hir_id) {
+                    self.ops.push((expr.span, "access of a mutable static occurs here"));
+                }
+            },
+            ExprKind::InlineAsm(..) => self.ops.push((expr.span, "inline assembly used here")),
+            _ => (),
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
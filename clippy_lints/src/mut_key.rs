@@ -0,0 +1,150 @@
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::map::Node::NodeItem;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use syntax_pos::DUMMY_SP;
+
+use crate::utils::{in_macro, match_def_path, paths, span_lint, walk_ptrs_ty};
+
+/// **What it does:** Checks for sets and maps whose key type has interior mutability, like
+/// `HashSet<Cell<u32>>` or `BTreeMap<Rc<RefCell<String>>, u32>`.
+///
+/// **Why is this bad?** The containers find their elements by the hash or the ordering of the
+/// keys. If a key is changed through its interior mutability while it is in the container, it is
+/// filed in the wrong place. It then can't be found anymore, and for `BTreeMap` and `BTreeSet`
+/// even the iteration order can go wrong.
+///
+/// **Known problems:** Key types that don't use their interior mutability for `Hash` or `Ord`,
+/// like `bytes::Bytes`, are false positives. These can be listed in the
+/// `ignore-interior-mutability` configuration option. Types containing interior mutable types
+/// as generic arguments are always considered mutable, even if they never hand out access to them.
+///
+/// **Example:**
+/// ```rust
+/// use std::cmp::{PartialEq, Eq};
+/// use std::collections::HashSet;
+/// use std::hash::{Hash, Hasher};
+/// use std::sync::atomic::AtomicUsize;
+///
+/// struct Bad(AtomicUsize);
+/// impl PartialEq for Bad {
+///     fn eq(&self, rhs: &Self) -> bool {
+///         unimplemented!()
+///     }
+/// }
+///
+/// impl Eq for Bad {}
+///
+/// impl Hash for Bad {
+///     fn hash<H: Hasher>(&self, h: &mut H) {
+///         unimplemented!()
+///     }
+/// }
+///
+/// fn main() {
+///     let _: HashSet<Bad> = HashSet::new();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MUTABLE_KEY_TYPE,
+    correctness,
+    "a set or map with a key type that has interior mutability"
+}
+
+const CONTAINERS: [&[&str]; 4] = [&paths::BTREEMAP, &paths::BTREESET, &paths::HASHMAP, &paths::HASHSET];
+
+pub struct MutableKeyType {
+    ignore_interior_mutability: Vec<String>,
+}
+
+impl MutableKeyType {
+    pub fn new(ignore_interior_mutability: Vec<String>) -> Self {
+        Self {
+            ignore_interior_mutability,
+        }
+    }
+
+    /// Lints `ty` if it is a set or map with a mutable key type.
+    fn check_ty<'a, 'tcx>(&self, cx: &LateContext<'a, 'tcx>, span: Span, ty: Ty<'tcx>) {
+        if let ty::TyAdt(adt, substs) = walk_ptrs_ty(ty).sty {
+            if CONTAINERS.iter().any(|path| match_def_path(cx.tcx, adt.did, path))
+                && self.is_mutable_type(cx, substs.type_at(0))
+            {
+                span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
+            }
+        }
+    }
+
+    /// Does `ty` have interior mutability, or contain a mutable reference?
+    fn is_mutable_type<'a, 'tcx>(&self, cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
+        match ty.sty {
+            ty::TyRef(_, inner, mutbl) | ty::TyRawPtr(ty::TypeAndMut { ty: inner, mutbl }) => {
+                mutbl == MutMutable || self.is_mutable_type(cx, inner)
+            },
+            ty::TySlice(inner) | ty::TyArray(inner, _) => self.is_mutable_type(cx, inner),
+            ty::TyTuple(tys) => tys.iter().any(|ty| self.is_mutable_type(cx, ty)),
+            ty::TyAdt(adt, substs) => {
+                if self.ignore_interior_mutability.iter().any(|path| {
+                    let path: Vec<&str> = path.split("::").collect();
+                    match_def_path(cx.tcx, adt.did, &path)
+                }) {
+                    return false;
+                }
+                // e.g. `Rc<RefCell<_>>`, where the `Rc` itself is `Freeze`
+                !ty.is_freeze(cx.tcx, cx.param_env, DUMMY_SP)
+                    || substs.types().any(|ty| self.is_mutable_type(cx, ty))
+            },
+            _ => false,
+        }
+    }
+}
+
+impl LintPass for MutableKeyType {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MUTABLE_KEY_TYPE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MutableKeyType {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        // the signatures of trait implementations are given by the trait
+        if let Some(NodeItem(item)) = cx.tcx.hir.find(cx.tcx.hir.get_parent(node_id)) {
+            if let ItemKind::Impl(_, _, _, _, Some(..), _, _) = item.node {
+                return;
+            }
+        }
+
+        let fn_sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(node_id));
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+        for (hir_ty, &ty) in decl.inputs.iter().zip(fn_sig.inputs()) {
+            self.check_ty(cx, hir_ty.span, ty);
+        }
+        if let FunctionRetTy::Return(ref hir_ty) = decl.output {
+            self.check_ty(cx, hir_ty.span, fn_sig.output());
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if !in_macro(local.span) {
+            self.check_ty(cx, local.pat.span, cx.tables.pat_ty(&local.pat));
+        }
+    }
+}
@@ -2,6 +2,7 @@ use rustc::hir::def_id::DefId;
 use rustc::hir;
 use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
 use syntax::codemap::Span;
@@ -129,7 +130,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NewWithoutDefault {
                                             impl_item.span,
                                             &format!("you should consider deriving a `Default` implementation for `{}`", self_ty),
                                             |db| {
-                                                db.suggest_item_with_attr(cx, sp, "try this", "#[derive(Default)]");
+                                                db.suggest_item_with_attr(
+                                                    cx,
+                                                    sp,
+                                                    "try this",
+                                                    "#[derive(Default)]",
+                                                    Applicability::Unspecified,
+                                                );
                                             });
                                     } else {
                                         span_lint_and_then(
@@ -0,0 +1,102 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, paths, span_lint_and_then};
+
+/// **What it does:** Checks for `unsafe impl Send` on types whose fields are not all `Send`.
+///
+/// **Why is this bad?** The fields are sent to another thread along with the value, which is
+/// unsound for e.g. raw pointers, `Rc`s or `Cell`s of such types, unless the author upholds the
+/// thread safety of every field by some other means.
+///
+/// **Known problems:** A correct implementation may ensure that the fields are never accessed
+/// from another thread, which the lint can't see. Fields of generic types are linted unless the
+/// generic parameter has a `Send` bound in the impl.
+///
+/// **Example:**
+/// ```rust,ignore
+/// struct ExampleStruct<T> {
+///     rc_is_not_send: Rc<String>,
+///     unbounded_generic_field: T,
+/// }
+///
+/// unsafe impl<T> Send for ExampleStruct<T> {}
+/// ```
+///
+/// Use thread-safe types and bounds instead:
+/// ```rust,ignore
+/// struct ExampleStruct<T> {
+///     arc_is_send: Arc<String>,
+///     bounded_generic_field: T,
+/// }
+///
+/// unsafe impl<T: Send> Send for ExampleStruct<T> {}
+/// ```
+declare_clippy_lint! {
+    pub NON_SEND_FIELDS_IN_SEND_TY,
+    nursery,
+    "there is field that does not implement `Send` in a `Send` struct"
+}
+
+pub struct NonSendFieldsInSendTy;
+
+impl LintPass for NonSendFieldsInSendTy {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_SEND_FIELDS_IN_SEND_TY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonSendFieldsInSendTy {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if !in_macro(item.span);
+            if let ItemKind::Impl(Unsafety::Unsafe, ImplPolarity::Positive, _, _, Some(ref trait_ref), _, _) = item.node;
+            if let Some(send_trait) = get_trait_def_id(cx, &paths::SEND);
+            if trait_ref.path.def.def_id() == send_trait;
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            if let ty::TyAdt(adt, substs) = self_ty.sty;
+            then {
+                let non_send_fields: Vec<_> = adt
+                    .all_fields()
+                    .filter_map(|field| {
+                        let field_ty = field.ty(cx.tcx, substs);
+                        if implements_trait(cx, field_ty, send_trait, &[]) {
+                            return None;
+                        }
+                        cx.tcx.hir.span_if_local(field.did).map(|span| (span, field.ident, field_ty))
+                    })
+                    .collect();
+                if non_send_fields.is_empty() {
+                    return;
+                }
+
+                span_lint_and_then(
+                    cx,
+                    NON_SEND_FIELDS_IN_SEND_TY,
+                    item.span,
+                    &format!(
+                        "some fields in `{}` are not safe to be sent to another thread",
+                        self_ty
+                    ),
+                    |db| {
+                        for &(span, ident, field_ty) in &non_send_fields {
+                            db.span_note(
+                                span,
+                                &format!(
+                                    "it is not safe to send field `{}` of type `{}` to another thread",
+                                    ident, field_ty
+                                ),
+                            );
+                        }
+                        db.help(
+                            "use thread-safe types, or add `Send` bounds to the generic parameters of the impl",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
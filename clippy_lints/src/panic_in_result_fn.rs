@@ -0,0 +1,138 @@
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, is_expn_of, match_type, paths, return_ty, span_lint_and_then};
+
+/// **What it does:** Checks for usage of `panic!`, `unimplemented!`, `todo!`,
+/// `unreachable!` or assertions in a function of type result.
+///
+/// **Why is this bad?** For some codebases, it is desirable for functions of
+/// type result to return an error instead of crashing. Hence panicking macros
+/// should be avoided.
+///
+/// **Known problems:** Functions called from a function returning a `Result`
+/// may invoke a panicking macro. This is not checked. Closures are only
+/// searched if they are called right away.
+///
+/// **Example:**
+///
+/// ```rust
+/// fn result_with_panic() -> Result<bool, String> {
+///     panic!("error");
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn result_without_panic() -> Result<bool, String> {
+///     Err(String::from("error"))
+/// }
+/// ```
+declare_clippy_lint! {
+    pub PANIC_IN_RESULT_FN,
+    restriction,
+    "functions of type `Result<..>` that contain `panic!()`, `todo!()`, `unreachable!()`, `unimplemented!()` or assertion"
+}
+
+/// The macros that panic. Macros that expand to others of this list come first,
+/// so that the user's call is found.
+const PANIC_MACROS: [&str; 7] = [
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "unreachable",
+    "unimplemented",
+    "todo",
+    "panic",
+];
+
+pub struct PanicInResultFn;
+
+impl LintPass for PanicInResultFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PANIC_IN_RESULT_FN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PanicInResultFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        if in_macro(span) || !match_type(cx, return_ty(cx, node_id), &paths::RESULT) {
+            return;
+        }
+
+        let mut finder = FindPanic {
+            cx,
+            panics: Vec::new(),
+        };
+        finder.visit_expr(&body.value);
+        if finder.panics.is_empty() {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            PANIC_IN_RESULT_FN,
+            span,
+            "used `unimplemented!()`, `unreachable!()`, `todo!()`, `panic!()` or assertion in a function that returns `Result`",
+            |db| {
+                db.help(
+                    "`unimplemented!()`, `unreachable!()`, `todo!()`, `panic!()` or assertions should not be used in a \
+                     function that returns `Result` as `Result` is expected to return an error instead of crashing",
+                );
+                for &panic in &finder.panics {
+                    db.span_note(panic, "return Err() instead of panicking");
+                }
+            },
+        );
+    }
+}
+
+struct FindPanic<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    panics: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanic<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let Some(span) = PANIC_MACROS.iter().filter_map(|name| is_expn_of(expr.span, name)).next() {
+            // there are several expressions in one expansion
+            let span = span.source_callsite();
+            if !self.panics.contains(&span) {
+                self.panics.push(span);
+            }
+            return;
+        }
+        match expr.node {
+            // closures may be called later or not at all, unless they are called right here
+            ExprKind::Closure(..) => (),
+            ExprKind::Call(ref callee, ref args) => {
+                if let ExprKind::Closure(_, _, body_id, _, _) = callee.node {
+                    self.visit_expr(&self.cx.tcx.hir.body(body_id).value);
+                } else {
+                    self.visit_expr(callee);
+                }
+                for arg in args {
+                    self.visit_expr(arg);
+                }
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
@@ -4,7 +4,6 @@ use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use syntax::ast::LitKind;
 use syntax::ptr::P;
-use syntax::ext::quote::rt::Span;
 use crate::utils::{is_direct_expn_of, is_expn_of, match_def_path, opt_def_id, paths, resolve_node, span_lint};
 
 /// **What it does:** Checks for missing parameters in `panic!`.
@@ -42,12 +41,28 @@ declare_clippy_lint! {
     "`unimplemented!` should not be present in production code"
 }
 
+/// **What it does:** Checks for usage of `todo!`.
+///
+/// **Why is this bad?** This macro should not be present in production code
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// todo!();
+/// ```
+declare_clippy_lint! {
+    pub TODO,
+    restriction,
+    "`todo!` should not be present in production code"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(PANIC_PARAMS, UNIMPLEMENTED)
+        lint_array!(PANIC_PARAMS, UNIMPLEMENTED, TODO)
     }
 }
 
@@ -59,30 +74,31 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             if let ExprKind::Call(ref fun, ref params) = ex.node;
             if let ExprKind::Path(ref qpath) = fun.node;
             if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
-            if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC);
-            if params.len() == 2;
             then {
-                if is_expn_of(expr.span, "unimplemented").is_some() {
-                    let span = get_outer_span(expr);
-                    span_lint(cx, UNIMPLEMENTED, span,
-                              "`unimplemented` should not be present in production code");
-                } else {
-                    match_panic(params, expr, cx);
+                if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC) && params.len() == 2 {
+                    if !check_stub(cx, expr) {
+                        match_panic(params, expr, cx);
+                    }
+                } else if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC_FMT) {
+                    check_stub(cx, expr);
                 }
             }
         }
     }
 }
 
-fn get_outer_span(expr: &Expr) -> Span {
-    if_chain! {
-        if let Some(first) = expr.span.ctxt().outer().expn_info();
-        if let Some(second) = first.call_site.ctxt().outer().expn_info();
-        then {
-            second.call_site
-        } else {
-            expr.span
-        }
+/// Lints `unimplemented!` and `todo!` (with or without a message) at the
+/// user's macro call. Returns `true` if `expr` is one of them.
+fn check_stub(cx: &LateContext, expr: &Expr) -> bool {
+    if let Some(span) = is_expn_of(expr.span, "unimplemented") {
+        span_lint(cx, UNIMPLEMENTED, span,
+                  "`unimplemented` should not be present in production code");
+        true
+    } else if let Some(span) = is_expn_of(expr.span, "todo") {
+        span_lint(cx, TODO, span, "`todo` should not be present in production code");
+        true
+    } else {
+        false
     }
 }
 
@@ -0,0 +1,78 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+
+use crate::utils::span_help_and_lint;
+
+/// **What it does:** Checks whether partial fields of a struct are public.
+///
+/// Either make all fields of a type public, or make none of them public
+///
+/// **Why is this bad?** Most types should either be:
+/// * Abstract data types: complex objects with opaque implementation which guard
+/// interior invariants and expose intentionally limited API to the outside world.
+/// * Data: relatively simple objects which group a bunch of related attributes together.
+///
+/// Mixing both prevents users from constructing the struct and from using the
+/// functional update syntax, which is usually not intended.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// pub struct Color {
+///     pub r: u8,
+///     pub g: u8,
+///     b: u8,
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// pub struct Color {
+///     pub r: u8,
+///     pub g: u8,
+///     pub b: u8,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub PARTIAL_PUB_FIELDS,
+    restriction,
+    "partial fields of a struct are public"
+}
+
+pub struct PartialPubFields;
+
+impl LintPass for PartialPubFields {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PARTIAL_PUB_FIELDS)
+    }
+}
+
+impl EarlyLintPass for PartialPubFields {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        let fields = match item.node {
+            ItemKind::Struct(ref data, _) => data.fields(),
+            _ => return,
+        };
+
+        let mut fields = fields.iter();
+        let first_is_pub = match fields.next() {
+            Some(field) => field.vis.node.is_pub(),
+            None => return,
+        };
+        // only the first field that differs from the first one is linted
+        if let Some(field) = fields.find(|field| field.vis.node.is_pub() != first_is_pub) {
+            span_help_and_lint(
+                cx,
+                PARTIAL_PUB_FIELDS,
+                field.span,
+                "mixed usage of pub and non-pub fields",
+                if first_is_pub {
+                    "consider using public field here"
+                } else {
+                    "consider using private field here"
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::Ty;
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{in_macro, snippet_opt, span_lint_and_sugg};
+
+/// **What it does:** Checks for comparisons of references that are cast to raw pointers, like
+/// `a as *const _ == b as *const _`.
+///
+/// **Why is this bad?** `std::ptr::eq` says what is compared without the casts, and since it
+/// takes references it can't accidentally compare values of different types.
+///
+/// **Known problems:** References of different types, or to unsized values, are not linted, as
+/// `std::ptr::eq` doesn't take them or also compares their metadata.
+///
+/// **Example:**
+/// ```rust
+/// let a = &[1, 2, 3];
+/// let b = &[1, 2, 3];
+///
+/// let same = a as *const _ as usize == b as *const _ as usize;
+/// // or
+/// let same = a as *const _ == b as *const _;
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let a = &[1, 2, 3];
+/// let b = &[1, 2, 3];
+///
+/// let same = std::ptr::eq(a, b);
+/// ```
+declare_clippy_lint! {
+    pub PTR_EQ,
+    style,
+    "use `std::ptr::eq` when comparing raw pointers"
+}
+
+pub struct PtrEq;
+
+impl LintPass for PtrEq {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PTR_EQ)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PtrEq {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Binary(ref op, ref left, ref right) = expr.node {
+            let negation = match op.node {
+                BinOpKind::Eq => "",
+                BinOpKind::Ne => "!",
+                _ => return,
+            };
+            let left = expr_as_cast_from_usize(cx, left).unwrap_or(&**left);
+            let right = expr_as_cast_from_usize(cx, right).unwrap_or(&**right);
+            if let (Some(left), Some(right)) = (expr_as_cast_from_ref(cx, left), expr_as_cast_from_ref(cx, right)) {
+                // `ptr::eq` needs both to have the same type, and compares the metadata of fat pointers too
+                let ty = cx.tables.expr_ty(left);
+                if ty != cx.tables.expr_ty(right) || !is_sized_pointee(cx, expr, ty) {
+                    return;
+                }
+                if let (Some(left_snip), Some(right_snip)) = (snippet_opt(cx, left.span), snippet_opt(cx, right.span))
+                {
+                    span_lint_and_sugg(
+                        cx,
+                        PTR_EQ,
+                        expr.span,
+                        "use `std::ptr::eq` when comparing raw pointers",
+                        "try",
+                        format!("{}std::ptr::eq({}, {})", negation, left_snip, right_snip),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// If `expr` is `e as usize` where `e` is a raw pointer, returns `e`.
+fn expr_as_cast_from_usize<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<&'tcx Expr> {
+    if cx.tables.expr_ty(expr).is_integral() {
+        if let ExprKind::Cast(ref inner, _) = expr.node {
+            if cx.tables.expr_ty(inner).is_unsafe_ptr() {
+                return Some(inner);
+            }
+        }
+    }
+    None
+}
+
+/// If `expr` is `e as *const _` or `e as *mut _` where `e` is a reference, returns `e`.
+fn expr_as_cast_from_ref<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<&'tcx Expr> {
+    if cx.tables.expr_ty(expr).is_unsafe_ptr() {
+        if let ExprKind::Cast(ref inner, _) = expr.node {
+            if cx.tables.expr_ty(inner).is_region_ptr() {
+                return Some(inner);
+            }
+        }
+    }
+    None
+}
+
+fn is_sized_pointee<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &Expr, ty: Ty<'tcx>) -> bool {
+    ty.builtin_deref(true)
+        .map_or(false, |pointee| pointee.ty.is_sized(cx.tcx.at(expr.span), cx.param_env))
+}
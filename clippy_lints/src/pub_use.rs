@@ -0,0 +1,94 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::*;
+
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Restricts the usage of `pub use ...`
+///
+/// **Why is this bad?** `pub use` is usually fine, but a project may wish to limit `pub use`
+/// instances to prevent unintentional exports or to encourage placing exported items directly
+/// in public modules, so that every item has a single canonical path.
+///
+/// Re-exports in modules listed in the `pub-use-allowed-modules` configuration option (by
+/// default only `prelude` modules) are not linted.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// pub mod outer {
+///     mod inner {
+///         pub struct Test {}
+///     }
+///     pub use inner::Test;
+/// }
+///
+/// use outer::Test;
+/// ```
+/// Use instead:
+/// ```rust,ignore
+/// pub mod outer {
+///     pub struct Test {}
+/// }
+///
+/// use outer::Test;
+/// ```
+declare_clippy_lint! {
+    pub PUB_USE,
+    restriction,
+    "restricts the usage of `pub use`"
+}
+
+pub struct PubUse {
+    allowed_modules: Vec<String>,
+    /// the names of the modules the current item is nested in
+    modules: Vec<Ident>,
+}
+
+impl PubUse {
+    pub fn new(allowed_modules: Vec<String>) -> Self {
+        Self {
+            allowed_modules,
+            modules: Vec::new(),
+        }
+    }
+
+    fn in_allowed_module(&self) -> bool {
+        self.modules
+            .last()
+            .map_or(false, |module| self.allowed_modules.iter().any(|name| module.name == *name))
+    }
+}
+
+impl LintPass for PubUse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PUB_USE)
+    }
+}
+
+impl EarlyLintPass for PubUse {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        match item.node {
+            ItemKind::Use(_) => {
+                if item.vis.node.is_pub() && !in_macro(item.span) && !self.in_allowed_module() {
+                    span_help_and_lint(
+                        cx,
+                        PUB_USE,
+                        item.span,
+                        "using `pub use`",
+                        "move the exported item to a public module instead",
+                    );
+                }
+            },
+            ItemKind::Mod(_) => self.modules.push(item.ident),
+            _ => (),
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext, item: &Item) {
+        if let ItemKind::Mod(_) = item.node {
+            self.modules.pop();
+        }
+    }
+}
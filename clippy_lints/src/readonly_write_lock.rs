@@ -0,0 +1,134 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+
+use crate::utils::usage::mutated_variables;
+use crate::utils::{get_parent_expr, in_macro, match_type, method_chain_args, paths, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** Checks for `RwLock::write` calls whose guard is only used for reading.
+///
+/// **Why is this bad?** A write lock excludes all other readers and writers, while any number
+/// of read locks can be held at the same time.
+///
+/// **Known problems:** Only guards that are bound with `let` and unwrapped right away are checked,
+/// and guards that are used other than by dereferencing them are assumed to be written to.
+///
+/// **Example:**
+/// ```rust
+/// # use std::sync::RwLock;
+/// # let lock = RwLock::new(1);
+/// let num = lock.write().unwrap();
+/// println!("{}", *num);
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # use std::sync::RwLock;
+/// # let lock = RwLock::new(1);
+/// let num = lock.read().unwrap();
+/// println!("{}", *num);
+/// ```
+declare_clippy_lint! {
+    pub READONLY_WRITE_LOCK,
+    nursery,
+    "acquiring a write lock when a read lock would work"
+}
+
+pub struct ReadonlyWriteLock;
+
+impl LintPass for ReadonlyWriteLock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READONLY_WRITE_LOCK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadonlyWriteLock {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let block = match expr.node {
+            ExprKind::Block(ref block, _) if !in_macro(expr.span) => block,
+            _ => return,
+        };
+        for stmt in &block.stmts {
+            if_chain! {
+                if let StmtKind::Decl(ref decl, _) = stmt.node;
+                if let DeclKind::Local(ref local) = decl.node;
+                if let PatKind::Binding(_, binding_id, _, _) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let Some(args) = method_chain_args(init, &["write", "unwrap"])
+                    .or_else(|| method_chain_args(init, &["write", "expect"]));
+                if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0][0])), &paths::RWLOCK);
+                let write_call = &args[1][0];
+                if let ExprKind::MethodCall(ref write, _, _) = write_call.node;
+                if !in_macro(write_call.span);
+                if only_read(cx, expr, binding_id);
+                then {
+                    let write_span = write.ident.span.with_hi(write_call.span.hi());
+                    span_lint_and_then(
+                        cx,
+                        READONLY_WRITE_LOCK,
+                        write_span,
+                        "this write lock is used only for reading",
+                        |db| {
+                            db.span_suggestion_with_applicability(
+                                write_span,
+                                "consider using a read lock instead",
+                                "read()".to_string(),
+                                Applicability::MaybeIncorrect,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Are all uses of the guard `binding_id` in `block` dereferences that don't mutate it?
+fn only_read<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, block: &'tcx Expr, binding_id: NodeId) -> bool {
+    let mut visitor = UseVisitor {
+        cx,
+        binding_id,
+        only_derefs: true,
+    };
+    visitor.visit_expr(block);
+    visitor.only_derefs && mutated_variables(block, cx).map_or(false, |mutated| !mutated.contains(&binding_id))
+}
+
+struct UseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    binding_id: NodeId,
+    only_derefs: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node;
+            if match path.def {
+                Def::Local(id) | Def::Upvar(id, ..) => id == self.binding_id,
+                _ => false,
+            };
+            then {
+                let is_deref = match get_parent_expr(self.cx, expr).map(|parent| &parent.node) {
+                    Some(&ExprKind::Unary(UnDeref, _)) | Some(&ExprKind::Field(..)) => true,
+                    Some(&ExprKind::MethodCall(_, _, ref args)) => args[0].id == expr.id,
+                    _ => false,
+                };
+                if !is_deref {
+                    self.only_derefs = false;
+                }
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
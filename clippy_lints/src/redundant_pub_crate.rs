@@ -0,0 +1,80 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::span_lint_and_then;
+
+/// **What it does:** Checks for items declared `pub(crate)` that are not crate visible because they
+/// are inside a private module.
+///
+/// **Why is this bad?** Writing `pub(crate)` is misleading when it's redundant due to the parent
+/// module's visibility.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// mod internal {
+///     pub(crate) fn internal_fn() { }
+/// }
+/// ```
+/// This function is not visible outside the module and it can be declared with `pub` or
+/// private visibility
+/// ```rust
+/// mod internal {
+///     pub fn internal_fn() { }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_PUB_CRATE,
+    nursery,
+    "`pub(crate)` items that are not crate visible due to the visibility of their module"
+}
+
+#[derive(Default)]
+pub struct RedundantPubCrate {
+    /// whether each of the modules the current item is nested in is exported
+    is_exported: Vec<bool>,
+}
+
+impl LintPass for RedundantPubCrate {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_PUB_CRATE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantPubCrate {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let VisibilityKind::Crate(CrateSugar::PubCrate) = item.vis.node {
+            if let Some(false) = self.is_exported.last() {
+                let span = item.vis.span;
+                span_lint_and_then(
+                    cx,
+                    REDUNDANT_PUB_CRATE,
+                    span,
+                    &format!("pub(crate) {} inside private module", item.node.descriptive_variant()),
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "consider using",
+                            "pub".to_string(),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+
+        if let ItemKind::Mod { .. } = item.node {
+            self.is_exported.push(cx.access_levels.is_exported(item.id));
+        }
+    }
+
+    fn check_item_post(&mut self, _cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Mod { .. } = item.node {
+            self.is_exported.pop().expect("unbalanced check_item/check_item_post");
+        }
+    }
+}
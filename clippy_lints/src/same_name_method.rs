@@ -0,0 +1,113 @@
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use std::collections::{BTreeMap, HashMap};
+use syntax::ast::{Name, NodeId};
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_lint_node_and_then};
+
+/// **What it does:** It lints if a struct has two methods with the same name:
+/// one from a trait, another not from trait.
+///
+/// **Why is this bad?** Confusing. Method calls prefer the inherent method, while generic code
+/// calls the trait method, so the same call can behave differently depending on the context.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// trait T {
+///     fn foo(&self) {}
+/// }
+///
+/// struct S;
+///
+/// impl T for S {
+///     fn foo(&self) {}
+/// }
+///
+/// impl S {
+///     fn foo(&self) {}
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SAME_NAME_METHOD,
+    restriction,
+    "two methods with the same name, one of them from a trait"
+}
+
+#[derive(Default)]
+struct ImplMethods {
+    /// the inherent methods of a type
+    inherent: Vec<(Name, NodeId, Span)>,
+    /// the names of the methods of each trait implemented by a type, with the span of the impl
+    from_traits: HashMap<Name, Span>,
+}
+
+#[derive(Default)]
+pub struct SameNameMethod {
+    types: BTreeMap<DefId, ImplMethods>,
+}
+
+impl LintPass for SameNameMethod {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SAME_NAME_METHOD)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SameNameMethod {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if let ItemKind::Impl(_, _, _, _, ref trait_ref, _, ref impl_item_refs) = item.node {
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            let ty_def_id = match self_ty.sty {
+                ty::TyAdt(adt, _) => adt.did,
+                _ => return,
+            };
+            let methods = self.types.entry(ty_def_id).or_insert_with(ImplMethods::default);
+            match *trait_ref {
+                Some(ref trait_ref) => {
+                    // provided methods count as well, they can be called just the same
+                    for trait_item in cx.tcx.associated_items(trait_ref.path.def.def_id()) {
+                        if trait_item.kind == ty::AssociatedKind::Method {
+                            methods.from_traits.entry(trait_item.ident.name).or_insert(item.span);
+                        }
+                    }
+                },
+                None => {
+                    for impl_item_ref in impl_item_refs {
+                        if let AssociatedItemKind::Method { .. } = impl_item_ref.kind {
+                            methods
+                                .inherent
+                                .push((impl_item_ref.ident.name, impl_item_ref.id.node_id, impl_item_ref.span));
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for methods in self.types.values() {
+            for &(name, id, span) in &methods.inherent {
+                if let Some(&trait_impl_span) = methods.from_traits.get(&name) {
+                    span_lint_node_and_then(
+                        cx,
+                        SAME_NAME_METHOD,
+                        id,
+                        span,
+                        "method's name is the same as an existing method in a trait",
+                        |db| {
+                            db.span_note(trait_impl_span, &format!("existing `{}` defined here", name));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
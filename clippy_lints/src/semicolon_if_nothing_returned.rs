@@ -0,0 +1,76 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Looks for blocks of expressions and fires if the last expression returns `()`
+/// but is not followed by a semicolon.
+///
+/// **Why is this bad?** The semicolon might be optional but when extending the block with new
+/// code, it doesn't require a change in previous last line.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// # fn foo() {}
+/// fn main() {
+///     foo()
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// # fn foo() {}
+/// fn main() {
+///     foo();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SEMICOLON_IF_NOTHING_RETURNED,
+    pedantic,
+    "add a semicolon if nothing is returned"
+}
+
+pub struct SemicolonIfNothingReturned;
+
+impl LintPass for SemicolonIfNothingReturned {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SEMICOLON_IF_NOTHING_RETURNED)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SemicolonIfNothingReturned {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if_chain! {
+            if !in_macro(block.span);
+            if let Some(ref expr) = block.expr;
+            if !in_macro(expr.span);
+            if let ty::TyTuple(slice) = cx.tables.expr_ty(expr).sty;
+            if slice.is_empty();
+            if let Some(snippet) = snippet_opt(cx, expr.span);
+            // block-like expressions such as `if` and `match` don't need a semicolon
+            if !snippet.ends_with('}');
+            then {
+                span_lint_and_then(
+                    cx,
+                    SEMICOLON_IF_NOTHING_RETURNED,
+                    expr.span,
+                    "consider adding a `;` to the last statement for consistent formatting",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "add a `;` here",
+                            format!("{};", snippet),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
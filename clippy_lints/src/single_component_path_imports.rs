@@ -0,0 +1,89 @@
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_lint_and_then};
+
+/// **What it does:** Checking for imports with single component use path.
+///
+/// **Why is this bad?** Import with single component use path such as `use cratename;`
+/// is not necessary in the Rust 2018 edition, and thus can be removed.
+///
+/// **Known problems:** Imports of a macro defined by `macro_rules!` in the same module are
+/// not linted, as they are needed to make the macro usable by path.
+///
+/// **Example:**
+///
+/// ```rust,ignore
+/// use regex;
+///
+/// fn main() {
+///     regex::Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+/// }
+/// ```
+/// Better as
+/// ```rust,ignore
+/// fn main() {
+///     regex::Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SINGLE_COMPONENT_PATH_IMPORTS,
+    style,
+    "imports with single component path are redundant"
+}
+
+pub struct SingleComponentPathImports;
+
+impl LintPass for SingleComponentPathImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SINGLE_COMPONENT_PATH_IMPORTS)
+    }
+}
+
+impl EarlyLintPass for SingleComponentPathImports {
+    fn check_mod(&mut self, cx: &EarlyContext, module: &Mod, _: Span, _: NodeId) {
+        if !cx.sess().rust_2018() {
+            return;
+        }
+
+        let macros = module
+            .items
+            .iter()
+            .filter_map(|item| match item.node {
+                ItemKind::MacroDef(..) => Some(item.ident.name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for item in &module.items {
+            // re-exports are fine
+            if in_macro(item.span) || item.vis.node.is_pub() {
+                continue;
+            }
+            if let ItemKind::Use(ref use_tree) = item.node {
+                if let UseTreeKind::Simple(None, ..) = use_tree.kind {
+                    let segments = &use_tree.prefix.segments;
+                    if segments.len() == 1 && !macros.contains(&segments[0].ident.name) {
+                        span_lint_and_then(
+                            cx,
+                            SINGLE_COMPONENT_PATH_IMPORTS,
+                            item.span,
+                            "this import is redundant",
+                            |db| {
+                                db.span_suggestion_with_applicability(
+                                    item.span,
+                                    "remove it entirely",
+                                    String::new(),
+                                    Applicability::MachineApplicable,
+                                );
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, match_def_path, paths, span_lint_and_then};
+
+/// **What it does:** Checks for calls to `sort` on slices of primitive types, like `u8`,
+/// `bool` or `char`.
+///
+/// **Why is this bad?** `sort_unstable` is faster and sorts in place without allocating. The
+/// only difference of a stable sort is that equal elements keep their order, which can't be
+/// observed for primitives.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut vec = vec![2, 1, 3];
+/// vec.sort();
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let mut vec = vec![2, 1, 3];
+/// vec.sort_unstable();
+/// ```
+declare_clippy_lint! {
+    pub STABLE_SORT_PRIMITIVE,
+    pedantic,
+    "use of `sort()` when `sort_unstable()` is equivalent"
+}
+
+pub struct StableSortPrimitive;
+
+impl LintPass for StableSortPrimitive {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STABLE_SORT_PRIMITIVE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StableSortPrimitive {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if method.ident.name == "sort" && args.len() == 1;
+            if !in_macro(expr.span);
+            if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id);
+            if match_def_path(cx.tcx, def.def_id(), &paths::SLICE_SORT);
+            let elem_ty = cx.tables.node_substs(expr.hir_id).type_at(0);
+            if match elem_ty.sty {
+                ty::TyInt(_) | ty::TyUint(_) | ty::TyBool | ty::TyChar => true,
+                _ => false,
+            };
+            then {
+                span_lint_and_then(
+                    cx,
+                    STABLE_SORT_PRIMITIVE,
+                    expr.span,
+                    &format!("used `sort` on primitive type `{}`", elem_ty),
+                    |db| {
+                        let recv = Sugg::hir(cx, &args[0], "..").maybe_par();
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "try",
+                            format!("{}.sort_unstable()", recv),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
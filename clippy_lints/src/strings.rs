@@ -3,7 +3,11 @@ use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
 use syntax::codemap::Spanned;
 use crate::utils::SpanlessEq;
-use crate::utils::{get_parent_expr, is_allowed, match_type, paths, span_lint, span_lint_and_sugg, walk_ptrs_ty};
+use rustc::ty;
+use rustc_errors::Applicability;
+use crate::utils::higher;
+use crate::utils::{get_parent_expr, is_allowed, match_type, paths, snippet, span_help_and_lint, span_lint,
+                   span_lint_and_sugg, span_lint_and_then, walk_ptrs_ty};
 
 /// **What it does:** Checks for string appends of the form `x = x + y` (without
 /// `let`!).
@@ -54,6 +58,26 @@ declare_clippy_lint! {
     "using `x + ..` where x is a `String` instead of `push_str()`"
 }
 
+/// **What it does:** Checks for slice operations on strings
+///
+/// **Why is this bad?** UTF-8 characters span multiple bytes, and it is easy to inadvertently
+/// confuse character counts and string indices. This may lead to panics, and should warrant some
+/// test cases containing wide UTF-8 characters. This lint is most useful in code that should
+/// avoid panics at all costs.
+///
+/// **Known problems:** Probably lots of false positives. If an index comes from a known valid
+/// position (e.g. obtained via `char_indices` over the same string), it is totally OK.
+///
+/// **Example:**
+/// ```rust,should_panic
+/// &"Ölkanne"[1..];
+/// ```
+declare_clippy_lint! {
+    pub STRING_SLICE,
+    restriction,
+    "slicing a string"
+}
+
 /// **What it does:** Checks for the `as_bytes` method called on string literals
 /// that contain only ASCII characters.
 ///
@@ -72,12 +96,60 @@ declare_clippy_lint! {
     "calling `as_bytes` on a string literal instead of using a byte string literal"
 }
 
+/// **What it does:** This lint checks for `.to_string()` method calls on values of type `&str`.
+///
+/// **Why is this bad?** The `to_string` method is also used on other types to convert them to a
+/// string. When called on a `&str` it turns the `&str` into the owned variant `String`, which can
+/// be more specifically expressed with `.to_owned()`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let _ = "str".to_string();
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let _ = "str".to_owned();
+/// ```
+declare_clippy_lint! {
+    pub STR_TO_STRING,
+    restriction,
+    "using `to_string()` on a `&str`, which should be `to_owned()`"
+}
+
+/// **What it does:** This lint checks for `.to_string()` method calls on values of type `String`.
+///
+/// **Why is this bad?** The `to_string` method is also used on other types to convert them to a
+/// string. When called on a `String` it only clones the `String`, which can be more specifically
+/// expressed with `.clone()`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let msg = String::from("Hello World");
+/// let _ = msg.to_string();
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let msg = String::from("Hello World");
+/// let _ = msg.clone();
+/// ```
+declare_clippy_lint! {
+    pub STRING_TO_STRING,
+    restriction,
+    "using `to_string()` on a `String`, which should be `clone()`"
+}
+
 #[derive(Copy, Clone)]
 pub struct StringAdd;
 
 impl LintPass for StringAdd {
     fn get_lints(&self) -> LintArray {
-        lint_array!(STRING_ADD, STRING_ADD_ASSIGN)
+        lint_array!(STRING_ADD, STRING_ADD_ASSIGN, STRING_SLICE)
     }
 }
 
@@ -113,10 +185,28 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringAdd {
                      `String::push_str()` instead",
                 );
             }
+        } else if let ExprKind::Index(ref target, ref index) = e.node {
+            // `&s[..]` can't panic
+            let is_full_range = higher::range(cx, index)
+                .map_or(false, |range| range.start.is_none() && range.end.is_none());
+            if !is_full_range && is_str_or_string(cx, target) {
+                span_help_and_lint(
+                    cx,
+                    STRING_SLICE,
+                    e.span,
+                    "indexing into a string may panic if the index is within a UTF-8 character",
+                    "consider using `get(..)`, `char_indices` or `split_at` instead",
+                );
+            }
         }
     }
 }
 
+fn is_str_or_string(cx: &LateContext, e: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(e));
+    ty.sty == ty::TyStr || match_type(cx, ty, &paths::STRING)
+}
+
 fn is_string(cx: &LateContext, e: &Expr) -> bool {
     match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(e)), &paths::STRING)
 }
@@ -147,7 +237,7 @@ impl LintPass for StringLitAsBytes {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         use syntax::ast::LitKind;
-        use crate::utils::{in_macro, snippet};
+        use crate::utils::in_macro;
 
         if let ExprKind::MethodCall(ref path, _, ref args) = e.node {
             if path.ident.name == "as_bytes" {
@@ -169,3 +259,56 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct StrToString;
+
+impl LintPass for StrToString {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STR_TO_STRING)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StrToString {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = e.node {
+            if path.ident.name == "to_string" {
+                if let ty::TyRef(_, ty, _) = cx.tables.expr_ty(&args[0]).sty {
+                    if ty.sty == ty::TyStr {
+                        lint_to_string(cx, STR_TO_STRING, e, &args[0], "&str", "to_owned");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct StringToString;
+
+impl LintPass for StringToString {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STRING_TO_STRING)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringToString {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = e.node {
+            if path.ident.name == "to_string" && match_type(cx, cx.tables.expr_ty(&args[0]), &paths::STRING) {
+                lint_to_string(cx, STRING_TO_STRING, e, &args[0], "String", "clone");
+            }
+        }
+    }
+}
+
+fn lint_to_string(cx: &LateContext, lint: &'static Lint, e: &Expr, recv: &Expr, ty: &str, method: &str) {
+    span_lint_and_then(cx, lint, e.span, &format!("`to_string()` called on a `{}`", ty), |db| {
+        db.span_suggestion_with_applicability(
+            e.span,
+            &format!("consider using `.{}()`", method),
+            format!("{}.{}()", snippet(cx, recv.span, ".."), method),
+            Applicability::MachineApplicable,
+        );
+    });
+}
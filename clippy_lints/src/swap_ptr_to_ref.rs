@@ -0,0 +1,102 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+
+use crate::utils::{in_macro, match_def_path, opt_def_id, paths, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for calls to `mem::swap` with references created from raw pointers,
+/// like `mem::swap(&mut *a, &mut *b)`.
+///
+/// **Why is this bad?** If the two pointers point to the same value, the two mutable references
+/// alias, which is undefined behaviour. `ptr::swap` works with aliasing pointers.
+///
+/// **Known problems:** The lint doesn't know whether the pointers can actually alias.
+///
+/// **Example:**
+/// ```rust
+/// # let a = &mut 0;
+/// # let b = &mut 0;
+/// let x: *mut i32 = a;
+/// let y: *mut i32 = b;
+/// unsafe {
+///     std::mem::swap(&mut *x, &mut *y);
+/// }
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # let a = &mut 0;
+/// # let b = &mut 0;
+/// let x: *mut i32 = a;
+/// let y: *mut i32 = b;
+/// unsafe {
+///     std::ptr::swap(x, y);
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SWAP_PTR_TO_REF,
+    style,
+    "call to `mem::swap` using references created from raw pointers"
+}
+
+pub struct SwapPtrToRef;
+
+impl LintPass for SwapPtrToRef {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SWAP_PTR_TO_REF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SwapPtrToRef {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if args.len() == 2;
+            if !in_macro(expr.span);
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::MEM_SWAP);
+            let (arg1, arg2) = (&args[0], &args[1]);
+            let (ptr1, ptr2) = (deref_of_raw_ptr(cx, arg1), deref_of_raw_ptr(cx, arg2));
+            if ptr1.is_some() || ptr2.is_some();
+            then {
+                span_lint_and_then(
+                    cx,
+                    SWAP_PTR_TO_REF,
+                    expr.span,
+                    "call to `core::mem::swap` with a parameter derived from a raw pointer",
+                    |db| {
+                        if_chain! {
+                            if let Some(arg1) = snippet_opt(cx, ptr1.unwrap_or(arg1).span);
+                            if let Some(arg2) = snippet_opt(cx, ptr2.unwrap_or(arg2).span);
+                            then {
+                                db.span_suggestion_with_applicability(
+                                    expr.span,
+                                    "use ptr::swap",
+                                    format!("std::ptr::swap({}, {})", arg1, arg2),
+                                    Applicability::MachineApplicable,
+                                );
+                            }
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// If `expr` is `&mut *ptr` for a raw pointer `ptr`, returns `ptr`.
+fn deref_of_raw_ptr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<&'tcx Expr> {
+    if_chain! {
+        if let ExprKind::AddrOf(MutMutable, ref inner) = expr.node;
+        if let ExprKind::Unary(UnDeref, ref ptr) = inner.node;
+        if cx.tables.expr_ty(ptr).is_unsafe_ptr();
+        if !in_macro(inner.span);
+        then {
+            return Some(ptr);
+        }
+    }
+    None
+}
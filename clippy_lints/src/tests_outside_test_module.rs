@@ -0,0 +1,84 @@
+use if_chain::if_chain;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::attr;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, is_in_cfg_test, span_help_and_lint};
+
+/// **What it does:** Triggers when a testing function (marked with the `#[test]` attribute) isn't
+/// inside a testing module (marked with `#[cfg(test)]`).
+///
+/// **Why is this bad?** The idiomatic (and more performant) way of writing tests is inside a
+/// testing module (flagged with `#[cfg(test)]`), having test functions outside of this module is
+/// confusing and may lead to them being "hidden". The helpers and imports only used by such tests
+/// are also compiled in release builds.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[test]
+/// fn my_cool_test() {
+///     // [...]
+/// }
+///
+/// #[cfg(test)]
+/// mod tests {
+///     // [...]
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[cfg(test)]
+/// mod tests {
+///     #[test]
+///     fn my_cool_test() {
+///         // [...]
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub TESTS_OUTSIDE_TEST_MODULE,
+    restriction,
+    "a test function outside of a `#[cfg(test)]` module"
+}
+
+pub struct TestsOutsideTestModule;
+
+impl LintPass for TestsOutsideTestModule {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TESTS_OUTSIDE_TEST_MODULE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TestsOutsideTestModule {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if_chain! {
+            if let FnKind::ItemFn(..) = kind;
+            if !in_macro(span);
+            if attr::contains_name(cx.tcx.hir.attrs(node_id), "test");
+            if !is_in_cfg_test(cx, node_id);
+            then {
+                span_help_and_lint(
+                    cx,
+                    TESTS_OUTSIDE_TEST_MODULE,
+                    span,
+                    "this function marked with #[test] is outside a #[cfg(test)] module",
+                    "move it to a testing module marked with #[cfg(test)]",
+                );
+            }
+        }
+    }
+}
@@ -2,10 +2,12 @@ use rustc::lint::*;
 use rustc::{declare_lint, lint_array};
 use if_chain::if_chain;
 use rustc::ty::{self, Ty};
+use rustc::ty::layout::LayoutOf;
+use rustc::ty::subst::Substs;
 use rustc::hir::*;
 use std::borrow::Cow;
 use syntax::ast;
-use crate::utils::{last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_then};
+use crate::utils::{last_path_segment, match_def_path, paths, snippet, span_help_and_lint, span_lint, span_lint_and_then};
 use crate::utils::{opt_def_id, sugg};
 
 /// **What it does:** Checks for transmutes that can't ever be correct on any
@@ -197,6 +199,66 @@ declare_clippy_lint! {
     "transmutes from a pointer to a pointer / a reference to a reference"
 }
 
+/// **What it does:** Checks for transmutes between types which do not have a
+/// specified layout, like two `repr(Rust)` structs or a `repr(Rust)` struct
+/// and a `repr(C)` one.
+///
+/// **Why is this bad?** The layout of a `repr(Rust)` struct with more than one
+/// non-zero-sized field is unspecified: the compiler is free to reorder its
+/// fields. Such a transmute may work today and silently break with a future
+/// compiler version.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// struct Foo { a: u32, b: u16 }
+/// struct Bar { a: u32, b: u16 }
+/// let _: Bar = unsafe { std::mem::transmute(Foo { a: 0, b: 0 }) };
+/// ```
+declare_clippy_lint! {
+    pub TRANSMUTE_UNDEFINED_REPR,
+    nursery,
+    "transmute to or from a type with an undefined representation"
+}
+
+/// **What it does:** Checks for transmutes between collections whose element types differ, like
+/// `Vec<u32>` to `Vec<[u8; 4]>`.
+///
+/// **Why is this bad?** The layout of the standard collections is unspecified, and even if the
+/// elements can be transmuted into each other, the allocation of the collection was made for the
+/// original element type. Deallocating it as another type is undefined behaviour.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let v: Vec<u32> = vec![1, 2, 3];
+/// let _: Vec<[u8; 4]> = unsafe { std::mem::transmute(v) };
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let v: Vec<u32> = vec![1, 2, 3];
+/// let _: Vec<[u8; 4]> = v.into_iter().map(|x| unsafe { std::mem::transmute(x) }).collect();
+/// ```
+declare_clippy_lint! {
+    pub UNSOUND_COLLECTION_TRANSMUTE,
+    correctness,
+    "transmute between collections of different element types"
+}
+
+/// The collections whose allocation depends on the type of their elements.
+const COLLECTIONS: [&[&str]; 7] = [
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+    &paths::BINARY_HEAP,
+    &paths::BTREESET,
+    &paths::BTREEMAP,
+    &paths::HASHSET,
+    &paths::HASHMAP,
+];
+
 pub struct Transmute;
 
 impl LintPass for Transmute {
@@ -211,6 +273,8 @@ impl LintPass for Transmute {
             TRANSMUTE_BYTES_TO_STR,
             TRANSMUTE_INT_TO_BOOL,
             TRANSMUTE_INT_TO_FLOAT,
+            TRANSMUTE_UNDEFINED_REPR,
+            UNSOUND_COLLECTION_TRANSMUTE,
         )
     }
 }
@@ -440,6 +504,49 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     },
                                 )
                             },
+                            (&ty::TyAdt(from_adt, _), &ty::TyAdt(to_adt, _))
+                                if from_adt.did == to_adt.did
+                                    && COLLECTIONS.iter().any(|path| match_def_path(cx.tcx, from_adt.did, path)) =>
+                            {
+                                span_help_and_lint(
+                                    cx,
+                                    UNSOUND_COLLECTION_TRANSMUTE,
+                                    e.span,
+                                    &format!(
+                                        "transmute from `{}` to `{}` with mismatched layout is unsound",
+                                        from_ty,
+                                        to_ty
+                                    ),
+                                    "consider transmuting the elements one by one, e.g. with \
+                                     `into_iter().map(|x| mem::transmute(x)).collect()`",
+                                )
+                            },
+                            // a type that only differs in its lifetimes keeps its layout
+                            (&ty::TyAdt(from_adt, from_substs), &ty::TyAdt(to_adt, to_substs))
+                                if from_adt.is_struct()
+                                    && to_adt.is_struct()
+                                    && cx.tcx.erase_regions(&from_ty) != cx.tcx.erase_regions(&to_ty) =>
+                            {
+                                let from_undefined = has_undefined_repr(cx, from_adt, from_substs);
+                                let to_undefined = has_undefined_repr(cx, to_adt, to_substs);
+                                let msg = match (from_undefined, to_undefined) {
+                                    (true, true) => format!(
+                                        "transmute from `{}` to `{}`, both of which have an undefined layout",
+                                        from_ty,
+                                        to_ty
+                                    ),
+                                    (true, false) => format!("transmute from `{}` which has an undefined layout", from_ty),
+                                    (false, true) => format!("transmute to `{}` which has an undefined layout", to_ty),
+                                    (false, false) => return,
+                                };
+                                span_help_and_lint(
+                                    cx,
+                                    TRANSMUTE_UNDEFINED_REPR,
+                                    e.span,
+                                    &msg,
+                                    "consider adding `#[repr(C)]` to the type",
+                                )
+                            },
                             _ => return,
                         };
                     }
@@ -449,6 +556,22 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
     }
 }
 
+/// Returns true if the struct's fields may be laid out in any order, i.e. it
+/// is `repr(Rust)` and has more than one non-zero-sized field.
+fn has_undefined_repr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, adt: &'tcx ty::AdtDef, substs: &'tcx Substs<'tcx>) -> bool {
+    if adt.repr.c() || adt.repr.transparent() || adt.repr.simd() {
+        return false;
+    }
+    adt.non_enum_variant()
+        .fields
+        .iter()
+        .filter(|field| {
+            cx.layout_of(field.ty(cx.tcx, substs))
+                .map_or(true, |layout| layout.size.bytes() > 0)
+        })
+        .count() > 1
+}
+
 /// Get the snippet of `Bar` in `…::transmute<Foo, &Bar>`. If that snippet is
 /// not available , use
 /// the type's `ToString` implementation. In weird cases it could lead to types
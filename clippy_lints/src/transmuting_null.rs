@@ -0,0 +1,75 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+
+use crate::consts::{constant, Constant};
+use crate::utils::{in_macro, is_null_path, match_def_path, opt_def_id, paths, span_help_and_lint};
+
+/// **What it does:** Checks for transmutes of a null pointer, or of the integer `0`, into a
+/// reference.
+///
+/// **Why is this bad?** References are never null, so creating one from a null pointer is
+/// undefined behaviour.
+///
+/// **Known problems:** Only sources that are `ptr::null()`, `ptr::null_mut()` or evaluate to
+/// `0` as a constant (possibly cast to a pointer) are detected.
+///
+/// **Example:**
+/// ```rust
+/// let x: &u64 = unsafe { std::mem::transmute(std::ptr::null::<u64>()) };
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let x: Option<&u64> = unsafe { std::ptr::null::<u64>().as_ref() };
+/// ```
+declare_clippy_lint! {
+    pub TRANSMUTING_NULL,
+    correctness,
+    "transmutes a null pointer into a reference, which is undefined behavior"
+}
+
+pub struct TransmutingNull;
+
+impl LintPass for TransmutingNull {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TRANSMUTING_NULL)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TransmutingNull {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Call(ref func, ref args) = expr.node;
+            if args.len() == 1;
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::TRANSMUTE);
+            if cx.tables.expr_ty(expr).is_region_ptr();
+            if is_null(cx, &args[0]);
+            then {
+                span_help_and_lint(
+                    cx,
+                    TRANSMUTING_NULL,
+                    expr.span,
+                    "transmuting a known null pointer into a reference",
+                    "use `Option<&T>` (e.g. from `ptr::as_ref`) for a reference that may be null",
+                );
+            }
+        }
+    }
+}
+
+/// Is `expr` known to be a null pointer or the integer `0`, looking through casts?
+fn is_null<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> bool {
+    let mut expr = expr;
+    while let ExprKind::Cast(ref inner, _) = expr.node {
+        expr = inner;
+    }
+    is_null_path(expr) || match constant(cx, cx.tables, expr) {
+        Some((Constant::Int(0), _)) => true,
+        _ => false,
+    }
+}
@@ -12,12 +12,16 @@ use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::borrow::Cow;
 use syntax::ast::{FloatTy, IntTy, UintTy};
+use syntax::attr;
 use syntax::codemap::Span;
 use syntax::errors::DiagnosticBuilder;
-use crate::utils::{comparisons, differing_macro_contexts, higher, in_constant, in_external_macro, in_macro, last_path_segment, match_def_path, match_path,
-            match_type, multispan_sugg, opt_def_id, same_tys, snippet, snippet_opt, span_help_and_lint, span_lint,
+use rustc_errors::Applicability;
+use semver::Version;
+use crate::utils::{comparisons, differing_macro_contexts, get_parent_expr, higher, in_constant, in_external_macro, in_macro, last_path_segment, match_def_path, match_path,
+            match_type, meets_msrv, multispan_sugg, opt_def_id, same_tys, snippet, snippet_opt, span_help_and_lint, span_lint,
             span_lint_and_sugg, span_lint_and_then, clip, unsext, sext, int_bits};
 use crate::utils::paths;
+use crate::utils::sugg::Sugg;
 use crate::consts::{constant, Constant};
 
 /// Handles all the linting of funky types
@@ -131,9 +135,33 @@ declare_clippy_lint! {
     "a borrow of a boxed type"
 }
 
+/// **What it does:** Checks for maps with a value type of `()`, like `HashMap<K, ()>` or
+/// `BTreeMap<K, ()>`.
+///
+/// **Why is this bad?** A map without values is a set. `HashSet` and `BTreeSet` perform just
+/// the same and have a clearer API for this, like `insert` taking only the key.
+///
+/// **Known problems:** The types of local bindings are only checked at the top level.
+///
+/// **Example:**
+/// ```rust
+/// fn unique_words(text: &str) -> HashMap<&str, ()> { ... }
+/// ```
+///
+/// Better:
+///
+/// ```rust
+/// fn unique_words(text: &str) -> HashSet<&str> { ... }
+/// ```
+declare_clippy_lint! {
+    pub ZERO_SIZED_MAP_VALUES,
+    pedantic,
+    "usage of a map with zero-sized value type"
+}
+
 impl LintPass for TypePass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(BOX_VEC, OPTION_OPTION, LINKEDLIST, BORROWED_BOX)
+        lint_array!(BOX_VEC, OPTION_OPTION, LINKEDLIST, BORROWED_BOX, ZERO_SIZED_MAP_VALUES)
     }
 }
 
@@ -165,6 +193,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TypePass {
         if let Some(ref ty) = local.ty {
             check_ty(cx, ty, true);
         }
+        if !in_macro(local.span) {
+            let span = local.ty.as_ref().map_or(local.pat.span, |ty| ty.span);
+            check_zero_sized_map_values(cx, cx.tables.pat_ty(&local.pat), span);
+        }
     }
 }
 
@@ -243,6 +275,10 @@ fn check_ty(cx: &LateContext, ast_ty: &hir::Ty, is_local: bool) {
                         "a VecDeque might work",
                     );
                     return; // don't recurse into the type
+                } else if match_def_path(cx.tcx, def_id, &paths::HASHMAP)
+                    || match_def_path(cx.tcx, def_id, &paths::BTREEMAP)
+                {
+                    check_zero_sized_map_values(cx, hir_ty_to_ty(cx.tcx, ast_ty), ast_ty.span);
                 }
             }
             match *qpath {
@@ -294,6 +330,28 @@ fn check_ty(cx: &LateContext, ast_ty: &hir::Ty, is_local: bool) {
     }
 }
 
+/// Lints `ty` if it is a `HashMap` or `BTreeMap` with `()` values.
+fn check_zero_sized_map_values(cx: &LateContext, ty: Ty, span: Span) {
+    if let ty::TyAdt(adt, substs) = ty.sty {
+        let set = if match_def_path(cx.tcx, adt.did, &paths::HASHMAP) {
+            "HashSet"
+        } else if match_def_path(cx.tcx, adt.did, &paths::BTREEMAP) {
+            "BTreeSet"
+        } else {
+            return;
+        };
+        if is_unit(substs.type_at(1)) {
+            span_help_and_lint(
+                cx,
+                ZERO_SIZED_MAP_VALUES,
+                span,
+                "map with zero-sized value type",
+                &format!("consider using a `{}` instead", set),
+            );
+        }
+    }
+}
+
 fn check_ty_rptr(cx: &LateContext, ast_ty: &hir::Ty, is_local: bool, lt: &Lifetime, mut_ty: &MutTy) {
     match mut_ty.ty.node {
         TyKind::Path(ref qpath) => {
@@ -562,7 +620,15 @@ fn is_unit_literal(expr: &Expr) -> bool {
     }
 }
 
-pub struct CastPass;
+pub struct CastPass {
+    msrv: Option<Version>,
+}
+
+impl CastPass {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
 
 /// **What it does:** Checks for casts from any numerical to a float type where
 /// the receiving type cannot store all values from the original type without
@@ -751,6 +817,128 @@ declare_clippy_lint! {
     "cast from a pointer to a more-strictly-aligned pointer"
 }
 
+/// **What it does:** Checks for uses of the `abs()` method on a signed integer
+/// that is then cast to an unsigned type.
+///
+/// **Why is this bad?** `i32::min_value().abs()` overflows, which panics in
+/// debug builds. `unsigned_abs()` returns the unsigned absolute value directly
+/// and can never overflow.
+///
+/// **Known problems:** `unsigned_abs()` is only available since Rust 1.51, the
+/// lint respects the `msrv` configuration option.
+///
+/// **Example:**
+/// ```rust
+/// let x: i32 = -42;
+/// let y: u32 = x.abs() as u32;
+/// ```
+/// Could be written:
+/// ```rust
+/// let x: i32 = -42;
+/// let y: u32 = x.unsigned_abs();
+/// ```
+declare_clippy_lint! {
+    pub CAST_ABS_TO_UNSIGNED,
+    style,
+    "casting the result of `abs()` to an unsigned integer can overflow, use `unsigned_abs()` instead"
+}
+
+/// **What it does:** Checks for casts from an enum type to an integral type
+/// which will definitely truncate the value.
+///
+/// **Why is this bad?** The resulting integral value will not match the value
+/// of the variant it came from.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// enum E { X = 256 };
+/// let _ = E::X as u8;
+/// ```
+declare_clippy_lint! {
+    pub CAST_ENUM_TRUNCATION,
+    correctness,
+    "casts from an enum type to an integral type which will truncate the value"
+}
+
+/// **What it does:** Checks for casts from an enum tuple constructor to an
+/// integer.
+///
+/// **Why is this bad?** The cast is easily confused with casting a C-like
+/// enum value to an integer, but it actually casts the address of the
+/// constructor function.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// enum E { X(i32) };
+/// let _ = E::X as usize;
+/// ```
+declare_clippy_lint! {
+    pub CAST_ENUM_CONSTRUCTOR,
+    correctness,
+    "casts from an enum tuple constructor to an integer"
+}
+
+/// **What it does:** Checks for casts between raw pointers to slices with
+/// differently sized elements, like `*const [u8]` to `*const [u32]`.
+///
+/// **Why is this bad?** The length metadata of the pointer is kept as is, so
+/// it counts elements of the wrong size. Creating a slice from the result
+/// reads or writes past the end of the original memory if the new elements are
+/// bigger.
+///
+/// **Known problems:** A chain of casts that ends at the element size it
+/// started with is not linted, but only if the casts are in one expression.
+///
+/// **Example:**
+/// ```rust
+/// let a = [1_u8, 2, 3, 4];
+/// let p = &a as *const [u8] as *const [u32];
+/// ```
+/// Could be written:
+/// ```rust
+/// let a = [1_u8, 2, 3, 4];
+/// let p = unsafe { std::slice::from_raw_parts(a.as_ptr() as *const u32, a.len() / 4) };
+/// ```
+declare_clippy_lint! {
+    pub CAST_SLICE_DIFFERENT_SIZES,
+    correctness,
+    "casting between raw pointers to slices with differently sized elements"
+}
+
+/// **What it does:** Checks for the result of an `as_ptr` method taking `&self`
+/// being cast to a mutable pointer.
+///
+/// **Why is this bad?** The pointer is derived from a shared reference, so
+/// writing through it is undefined behaviour. `as_mut_ptr` returns a pointer
+/// that may be written through.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let bytes = vec![0u8; 1];
+/// let ptr = bytes.as_ptr() as *mut u8;
+/// unsafe { ptr.write(4) }; // UNDEFINED BEHAVIOUR
+/// ```
+/// Could be written:
+/// ```rust
+/// let mut bytes = vec![0u8; 1];
+/// let ptr = bytes.as_mut_ptr();
+/// unsafe { ptr.write(4) };
+/// ```
+declare_clippy_lint! {
+    pub AS_PTR_CAST_MUT,
+    nursery,
+    "casting the result of the `&self`-taking `as_ptr` to a mutable pointer"
+}
+
+/// The first version of Rust with `{integer}::unsigned_abs`.
+const UNSIGNED_ABS_MSRV: [u64; 3] = [1, 51, 0];
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty, tcx: TyCtxt) -> u64 {
@@ -775,6 +963,26 @@ fn int_ty_to_nbits(typ: Ty, tcx: TyCtxt) -> u64 {
     }
 }
 
+/// Returns the number of bits needed to store the given discriminant value.
+fn discr_nbits(value: i128) -> u64 {
+    let nbits = if value < 0 {
+        128 - (-(value + 1)).leading_zeros() + 1
+    } else {
+        128 - value.leading_zeros()
+    };
+    u64::from(nbits)
+}
+
+/// Returns the value of the discriminant, sign-extended if the enum's
+/// representation is signed.
+#[allow(cast_possible_wrap)]
+fn discr_value(cx: &LateContext, discr: ty::util::Discr) -> i128 {
+    match discr.ty.sty {
+        ty::TyInt(ity) => sext(cx.tcx, discr.val, ity),
+        _ => discr.val as i128,
+    }
+}
+
 fn is_isize_or_usize(typ: Ty) -> bool {
     match typ.sty {
         ty::TyInt(IntTy::Isize) | ty::TyUint(UintTy::Usize) => true,
@@ -925,6 +1133,196 @@ fn check_truncation_and_wrapping(cx: &LateContext, expr: &Expr, cast_from: Ty, c
     }
 }
 
+fn check_abs_to_unsigned(cx: &LateContext, msrv: Option<&Version>, expr: &Expr, op: &Expr, cast_to: Ty) {
+    if_chain! {
+        if meets_msrv(msrv, &UNSIGNED_ABS_MSRV);
+        if let ty::TyUint(_) = cast_to.sty;
+        if let ExprKind::MethodCall(ref method_path, _, ref args) = op.node;
+        if method_path.ident.name == "abs" && args.len() == 1;
+        if let ty::TyInt(_) = cx.tables.expr_ty(&args[0]).sty;
+        then {
+            let cast_from = cx.tables.expr_ty(op);
+            let recv = Sugg::hir(cx, &args[0], "..").maybe_par();
+            let sugg = if int_ty_to_nbits(cast_from, cx.tcx) == int_ty_to_nbits(cast_to, cx.tcx) {
+                format!("{}.unsigned_abs()", recv)
+            } else {
+                format!("{}.unsigned_abs() as {}", recv, cast_to)
+            };
+            span_lint_and_then(
+                cx,
+                CAST_ABS_TO_UNSIGNED,
+                expr.span,
+                &format!("casting the result of `{}::abs()` to {}", cast_from, cast_to),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "replace with",
+                        sugg,
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+fn check_enum_truncation(cx: &LateContext, expr: &Expr, op: &Expr, adt: &ty::AdtDef, cast_from: Ty, cast_to: Ty) {
+    // if we cast a single unit variant, only its own value matters
+    let variant = if let ExprKind::Path(ref qpath) = op.node {
+        if let def::Def::VariantCtor(id, def::CtorKind::Const) = cx.tables.qpath_def(qpath, op.hir_id) {
+            adt.variants.iter().position(|v| v.did == id)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    let from_nbits = adt.discriminants(cx.tcx)
+        .enumerate()
+        .filter(|&(i, _)| variant.map_or(true, |v| v == i))
+        .map(|(_, discr)| discr_nbits(discr_value(cx, discr)))
+        .max()
+        .unwrap_or(0);
+    let to_nbits = int_ty_to_nbits(cast_to, cx.tcx);
+    let from_ptr_sized = match adt.repr.int {
+        None | Some(attr::IntType::SignedInt(IntTy::Isize)) | Some(attr::IntType::UnsignedInt(UintTy::Usize)) => true,
+        _ => false,
+    };
+
+    let suffix = match (from_ptr_sized, is_isize_or_usize(cast_to)) {
+        (_, false) if from_nbits > to_nbits => "",
+        (false, true) if from_nbits > 64 => "",
+        (false, true) if from_nbits > 32 => " on targets with 32-bit wide pointers",
+        _ => return,
+    };
+
+    let msg = if let Some(i) = variant {
+        format!(
+            "casting `{}::{}` to `{}` will truncate the value{}",
+            cast_from,
+            adt.variants[i].name,
+            cast_to,
+            suffix
+        )
+    } else {
+        format!("casting `{}` to `{}` may truncate the value{}", cast_from, cast_to, suffix)
+    };
+    span_lint(cx, CAST_ENUM_TRUNCATION, expr.span, &msg);
+}
+
+fn is_enum_constructor(cx: &LateContext, op: &Expr) -> bool {
+    if let ExprKind::Path(ref qpath) = op.node {
+        if let def::Def::VariantCtor(_, def::CtorKind::Fn) = cx.tables.qpath_def(qpath, op.hir_id) {
+            return true;
+        }
+    }
+    false
+}
+
+fn check_slice_different_sizes<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &Expr,
+    op: &Expr,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if_chain! {
+        if let ty::TyRawPtr(from_ptr_ty) = cast_from.sty;
+        if let ty::TyRawPtr(to_ptr_ty) = cast_to.sty;
+        if let ty::TySlice(from_elem) = from_ptr_ty.ty.sty;
+        if let ty::TySlice(to_elem) = to_ptr_ty.ty.sty;
+        if let Ok(from_layout) = cx.layout_of(from_elem);
+        if let Ok(to_layout) = cx.layout_of(to_elem);
+        let (from_size, to_size) = (from_layout.size.bytes(), to_layout.size.bytes());
+        if from_size != to_size;
+        if !in_external_macro(cx, expr.span);
+        // in a chain like `p as *const [u8] as *const [i32]`, only the sizes at its ends matter
+        if !get_parent_expr(cx, expr).map_or(false, |parent| cast_to_elem_size(cx, parent) == Some(from_size));
+        if cast_from_elem_size(cx, op) != Some(to_size);
+        then {
+            span_help_and_lint(
+                cx,
+                CAST_SLICE_DIFFERENT_SIZES,
+                expr.span,
+                &format!(
+                    "casting between raw pointers to `[{}]` (element size {}) and `[{}]` (element size {}) does not \
+                     adjust the count",
+                    from_elem, from_size, to_elem, to_size
+                ),
+                &format!(
+                    "consider using `std::slice::from_raw_parts` with the length adjusted for the element size \
+                     (`len * {} / {}`)",
+                    from_size, to_size
+                ),
+            );
+        }
+    }
+}
+
+/// If `expr` is a cast to a raw slice pointer, returns the size of the slice elements.
+fn cast_to_elem_size(cx: &LateContext, expr: &Expr) -> Option<u64> {
+    match expr.node {
+        ExprKind::Cast(..) => slice_ptr_elem_size(cx, cx.tables.expr_ty(expr)),
+        _ => None,
+    }
+}
+
+/// If `expr` is a cast from a raw slice pointer, returns the size of the slice elements.
+fn cast_from_elem_size(cx: &LateContext, expr: &Expr) -> Option<u64> {
+    match expr.node {
+        ExprKind::Cast(ref op, _) => slice_ptr_elem_size(cx, cx.tables.expr_ty(op)),
+        _ => None,
+    }
+}
+
+fn slice_ptr_elem_size<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+    match ty.sty {
+        ty::TyRawPtr(ty::TypeAndMut { ty: pointee, .. }) => match pointee.sty {
+            ty::TySlice(elem) => cx.layout_of(elem).ok().map(|layout| layout.size.bytes()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn check_as_ptr_cast_mut(cx: &LateContext, expr: &Expr, op: &Expr, cast_to: Ty) {
+    if_chain! {
+        if let ty::TyRawPtr(ty::TypeAndMut { mutbl: MutMutable, ty: to_pointee }) = cast_to.sty;
+        if let ty::TyRawPtr(ty::TypeAndMut { mutbl: MutImmutable, .. }) = cx.tables.expr_ty(op).sty;
+        if let ExprKind::MethodCall(ref method_path, _, ref args) = op.node;
+        if method_path.ident.name == "as_ptr" && args.len() == 1;
+        if let Some(def) = cx.tables.type_dependent_defs().get(op.hir_id);
+        let def_id = def.def_id();
+        if let Some(&self_ty) = cx.tcx.fn_sig(def_id).skip_binder().inputs().first();
+        if let ty::TyRef(_, _, MutImmutable) = self_ty.sty;
+        if !in_external_macro(cx, expr.span);
+        then {
+            // only suggest `as_mut_ptr` if it is defined next to `as_ptr`
+            let has_as_mut_ptr = cx.tcx.impl_of_method(def_id).map_or(false, |impl_id| {
+                cx.tcx
+                    .associated_items(impl_id)
+                    .any(|item| item.ident.name == "as_mut_ptr")
+            });
+            span_lint_and_then(
+                cx,
+                AS_PTR_CAST_MUT,
+                expr.span,
+                &format!("casting the result of `as_ptr` to *mut {}", to_pointee),
+                |db| if has_as_mut_ptr {
+                    let recv = Sugg::hir(cx, &args[0], "..").maybe_par();
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "replace with",
+                        format!("{}.as_mut_ptr()", recv),
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
 fn check_lossless(cx: &LateContext, expr: &Expr, op: &Expr, cast_from: Ty, cast_to: Ty) {
     let cast_signed_to_unsigned = cast_from.is_signed() && !cast_to.is_signed();
     let from_nbits = int_ty_to_nbits(cast_from, cx.tcx);
@@ -947,6 +1345,11 @@ impl LintPass for CastPass {
             CAST_PTR_ALIGNMENT,
             FN_TO_NUMERIC_CAST,
             FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+            CAST_ABS_TO_UNSIGNED,
+            CAST_ENUM_TRUNCATION,
+            CAST_ENUM_CONSTRUCTOR,
+            CAST_SLICE_DIFFERENT_SIZES,
+            AS_PTR_CAST_MUT,
         )
     }
 }
@@ -1012,6 +1415,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         }
                         check_truncation_and_wrapping(cx, expr, cast_from, cast_to);
                         check_lossless(cx, expr, ex, cast_from, cast_to);
+                        check_abs_to_unsigned(cx, self.msrv.as_ref(), expr, ex, cast_to);
                     },
                     (false, false) => {
                         if let (&ty::TyFloat(FloatTy::F64), &ty::TyFloat(FloatTy::F32)) = (&cast_from.sty, &cast_to.sty)
@@ -1031,6 +1435,25 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                 }
             }
 
+            if_chain! {
+                if let ty::TyAdt(adt, _) = cast_from.sty;
+                if adt.is_enum() && cast_to.is_integral();
+                if !in_external_macro(cx, expr.span);
+                then {
+                    check_enum_truncation(cx, expr, ex, adt, cast_from, cast_to);
+                }
+            }
+
+            if cast_to.is_integral() && is_enum_constructor(cx, ex) {
+                span_lint(
+                    cx,
+                    CAST_ENUM_CONSTRUCTOR,
+                    expr.span,
+                    "cast of an enum tuple constructor to an integer",
+                );
+                return;
+            }
+
             match &cast_from.sty {
                 ty::TyFnDef(..) |
                 ty::TyFnPtr(..) => {
@@ -1082,6 +1505,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                     );
                 }
             }
+
+            check_slice_different_sizes(cx, expr, ex, cast_from, cast_to);
+            check_as_ptr_cast_mut(cx, expr, ex, cast_to);
         }
     }
 }
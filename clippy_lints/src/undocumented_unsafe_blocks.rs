@@ -0,0 +1,213 @@
+use rustc::hir::intravisit::{walk_block, walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{comment_lines_above, in_macro, span_help_and_lint, span_lint_and_then};
+
+/// **What it does:** Checks for `unsafe` blocks that are not preceded by a `// SAFETY:` comment.
+///
+/// **Why is this bad?** An `unsafe` block relies on invariants the compiler can't check. Writing
+/// down why they hold makes the block reviewable, and lets whoever changes the surrounding code
+/// later know what they have to preserve.
+///
+/// **Known problems:** Only `//` comments on the lines right above the line of the block are
+/// considered, so a comment on the same line or a `/* .. */` comment is not found. Blocks in
+/// macros are ignored.
+///
+/// **Example:**
+/// ```rust
+/// let a = &[1, 2, 3];
+/// let first = unsafe { *a.as_ptr() };
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let a = &[1, 2, 3];
+/// // SAFETY: `a` is not empty, so its pointer points to an initialized element
+/// let first = unsafe { *a.as_ptr() };
+/// ```
+declare_clippy_lint! {
+    pub UNDOCUMENTED_UNSAFE_BLOCKS,
+    restriction,
+    "`unsafe` blocks without a `// SAFETY:` comment"
+}
+
+/// **What it does:** Checks for `// SAFETY:` comments on statements and items that don't contain
+/// any `unsafe` block.
+///
+/// **Why is this bad?** Such comments are usually left behind when the `unsafe` code they
+/// justified is refactored away. They make readers look for an unsafe operation that isn't there,
+/// and the safety documentation stops being trustworthy.
+///
+/// **Known problems:** Statements in `unsafe fn`s are not checked, since they can do unsafe
+/// operations without an `unsafe` block.
+///
+/// **Example:**
+/// ```rust
+/// let a = &[1, 2, 3];
+/// // SAFETY: `a` is not empty
+/// let first = a[0];
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let a = &[1, 2, 3];
+/// let first = a[0];
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_SAFETY_COMMENT,
+    restriction,
+    "`// SAFETY:` comments on code without `unsafe` blocks"
+}
+
+#[derive(Default)]
+pub struct UndocumentedUnsafeBlocks {
+    /// the number of `unsafe fn`s the current node is in
+    unsafe_fn_depth: usize,
+}
+
+impl LintPass for UndocumentedUnsafeBlocks {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNDOCUMENTED_UNSAFE_BLOCKS, UNNECESSARY_SAFETY_COMMENT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UndocumentedUnsafeBlocks {
+    fn check_fn(
+        &mut self,
+        _: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        _: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        if is_unsafe_fn(kind) {
+            self.unsafe_fn_depth += 1;
+        }
+    }
+
+    fn check_fn_post(
+        &mut self,
+        _: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        _: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        if is_unsafe_fn(kind) {
+            self.unsafe_fn_depth -= 1;
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        let is_unsafe = match item.node {
+            ItemKind::Fn(_, header, ..) => header.unsafety == Unsafety::Unsafe,
+            ItemKind::Impl(unsafety, ..) | ItemKind::Trait(_, unsafety, ..) => unsafety == Unsafety::Unsafe,
+            _ => false,
+        };
+        if !is_unsafe && !in_macro(item.span) && !contains_unsafe_block(cx, |v| v.visit_item(item)) {
+            check_unnecessary_comment(cx, item.span, "item");
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        if let StmtKind::Decl(ref decl, _) = stmt.node {
+            // items are checked by `check_item`
+            if let DeclKind::Item(_) = decl.node {
+                return;
+            }
+        }
+        if self.unsafe_fn_depth == 0 && !in_macro(stmt.span) && !contains_unsafe_block(cx, |v| v.visit_stmt(stmt)) {
+            check_unnecessary_comment(cx, stmt.span, "statement");
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if let BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) = block.rules {
+            let documented = comment_lines_above(cx, block.span)
+                .iter()
+                .any(|&(_, ref comment)| is_safety_comment(comment));
+            if !documented && !in_macro(block.span) {
+                span_help_and_lint(
+                    cx,
+                    UNDOCUMENTED_UNSAFE_BLOCKS,
+                    block.span,
+                    "unsafe block missing a safety comment",
+                    "consider adding a `// SAFETY:` comment on the preceding line",
+                );
+            }
+        } else if let Some(ref expr) = block.expr {
+            if self.unsafe_fn_depth == 0 && !in_macro(expr.span) && !contains_unsafe_block(cx, |v| v.visit_expr(expr)) {
+                check_unnecessary_comment(cx, expr.span, "expression");
+            }
+        }
+    }
+}
+
+fn is_unsafe_fn(kind: FnKind) -> bool {
+    match kind {
+        FnKind::ItemFn(_, _, header, ..) => header.unsafety == Unsafety::Unsafe,
+        FnKind::Method(_, sig, ..) => sig.header.unsafety == Unsafety::Unsafe,
+        FnKind::Closure(..) => false,
+    }
+}
+
+fn is_safety_comment(comment: &str) -> bool {
+    comment.to_uppercase().starts_with("SAFETY:")
+}
+
+/// Lints a `// SAFETY:` comment above `span`, which contains no `unsafe` block.
+fn check_unnecessary_comment(cx: &LateContext, span: Span, what: &str) {
+    let comments = comment_lines_above(cx, span);
+    if let Some(&(comment_span, _)) = comments.iter().find(|&&(_, ref comment)| is_safety_comment(comment)) {
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_SAFETY_COMMENT,
+            span,
+            &format!("{} has unnecessary safety comment", what),
+            |db| {
+                db.span_help(comment_span, "consider removing the safety comment");
+            },
+        );
+    }
+}
+
+/// Does the node visited by `visit` contain a user-written `unsafe` block, including in closures?
+fn contains_unsafe_block<'a, 'tcx, F>(cx: &'a LateContext<'a, 'tcx>, visit: F) -> bool
+where
+    F: FnOnce(&mut UnsafeBlockVisitor<'a, 'tcx>),
+{
+    let mut visitor = UnsafeBlockVisitor { cx, found: false };
+    visit(&mut visitor);
+    visitor.found
+}
+
+struct UnsafeBlockVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeBlockVisitor<'a, 'tcx> {
+    fn visit_block(&mut self, block: &'tcx Block) {
+        if let BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) = block.rules {
+            self.found = true;
+        } else {
+            walk_block(self, block);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if !self.found {
+            walk_expr(self, expr);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir)
+    }
+}
@@ -0,0 +1,117 @@
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc::{declare_lint, lint_array};
+
+use crate::utils::{match_def_path, match_type, opt_def_id, paths, span_lint_and_then};
+
+/// **What it does:** Checks for `mem::uninitialized::<T>()` and
+/// `MaybeUninit::uninit().assume_init()` where `T` has invalid bit patterns, i.e. contains
+/// references, `bool`s, `char`s, enums, function pointers or non-zero types like `Box`.
+///
+/// **Why is this bad?** Uninitialized memory doesn't have to be a valid value of `T`, and just
+/// creating an invalid `bool` or a dangling reference is undefined behavior, even if it's never
+/// read.
+///
+/// **Known problems:** Generic types are not linted, as the validity of `T` depends on how it is
+/// instantiated. Uninitialized references are linted by `invalid_ref` instead. `MaybeUninit` is
+/// not part of `std` yet, so only `mem::uninitialized` can be linted for now.
+///
+/// **Example:**
+/// ```rust
+/// let flag: bool = unsafe { std::mem::uninitialized() };
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// let flag = false;
+/// ```
+declare_clippy_lint! {
+    pub UNINIT_ASSUMED_INIT,
+    correctness,
+    "creating uninitialized values of types with invalid bit patterns"
+}
+
+pub struct UninitAssumedInit;
+
+impl LintPass for UninitAssumedInit {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNINIT_ASSUMED_INIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UninitAssumedInit {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let ty = cx.tables.expr_ty(expr);
+        let call = match uninit_call(cx, expr) {
+            Some(call) => call,
+            None => return,
+        };
+        if let Some(invalid) = invalid_when_uninit(cx, ty) {
+            span_lint_and_then(
+                cx,
+                UNINIT_ASSUMED_INIT,
+                expr.span,
+                &format!("`{}` creates an uninitialized `{}`, which is undefined behavior", call, ty),
+                |db| {
+                    if invalid != ty {
+                        db.note(&format!("`{}` contains a `{}`, which has invalid bit patterns", ty, invalid));
+                    }
+                    db.help("initialize the value, or use `Option<T>` for a value that is set later");
+                },
+            );
+        }
+    }
+}
+
+/// Returns how `expr` creates an uninitialized value, if it does.
+fn uninit_call(cx: &LateContext, expr: &Expr) -> Option<&'static str> {
+    match expr.node {
+        ExprKind::Call(ref func, ref args) if args.is_empty() => if_chain! {
+            if let ExprKind::Path(ref qpath) = func.node;
+            if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+            if match_def_path(cx.tcx, def_id, &paths::MEM_UNINIT) || match_def_path(cx.tcx, def_id, &paths::UNINIT);
+            // `invalid_ref` lints uninitialized references
+            if !cx.tables.expr_ty(expr).is_region_ptr();
+            then {
+                return Some("mem::uninitialized()");
+            }
+        },
+        // `MaybeUninit` isn't in this version of `core` yet, it is matched by path so that the lint
+        // covers it once it lands
+        ExprKind::MethodCall(ref method, _, ref args) if method.ident.name == "assume_init" => if_chain! {
+            if let ExprKind::Call(ref func, _) = args[0].node;
+            if let ExprKind::Path(QPath::TypeRelative(_, ref ctor)) = func.node;
+            if ctor.ident.name == "uninit";
+            if match_type(cx, cx.tables.expr_ty(&args[0]), &paths::MAYBE_UNINIT);
+            then {
+                return Some("MaybeUninit::uninit().assume_init()");
+            }
+        },
+        _ => (),
+    }
+    None
+}
+
+/// Returns the part of `ty` that can't be left uninitialized, if any.
+fn invalid_when_uninit<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.sty {
+        ty::TyBool | ty::TyChar | ty::TyRef(..) | ty::TyFnPtr(_) | ty::TyNever => Some(ty),
+        ty::TyArray(elem, _) => invalid_when_uninit(cx, elem),
+        ty::TyTuple(tys) => tys.iter().filter_map(|ty| invalid_when_uninit(cx, ty)).next(),
+        ty::TyAdt(adt, substs) => {
+            // `MaybeUninit` will be a union, its path is matched as well in case that changes
+            if adt.is_enum() || adt.is_box() || match_def_path(cx.tcx, adt.did, &paths::NON_ZERO) {
+                Some(ty)
+            } else if adt.is_union() || match_def_path(cx.tcx, adt.did, &paths::MAYBE_UNINIT) {
+                None
+            } else {
+                adt.all_fields()
+                    .filter_map(|field| invalid_when_uninit(cx, field.ty(cx.tcx, substs)))
+                    .next()
+            }
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,155 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then};
+
+/// **What it does:** Checks for `set_len` calls on a `Vec` right after reserving its buffer with
+/// `Vec::with_capacity` or `reserve`.
+///
+/// **Why is this bad?** The reserved memory is uninitialized, so `set_len` makes the vector
+/// contain uninitialized values. Reading them, or even just dropping the vector, is undefined
+/// behavior for most element types.
+///
+/// **Known problems:** Only calls in consecutive statements are paired. Vectors whose elements
+/// are initialized by other means (e.g. by an FFI function) between the two calls are not linted.
+///
+/// **Example:**
+/// ```rust,ignore
+/// let mut vec: Vec<u8> = Vec::with_capacity(1000);
+/// unsafe {
+///     vec.set_len(1000);
+/// }
+/// reader.read(&mut vec);
+/// ```
+///
+/// Use instead:
+/// ```rust,ignore
+/// let mut vec: Vec<u8> = vec![0; 1000];
+/// reader.read(&mut vec);
+///
+/// // or
+///
+/// let mut vec: Vec<u8> = Vec::with_capacity(1000);
+/// vec.resize(1000, 0);
+/// reader.read(&mut vec);
+/// ```
+declare_clippy_lint! {
+    pub UNINIT_VEC,
+    correctness,
+    "`Vec` with uninitialized data"
+}
+
+pub struct UninitVec;
+
+impl LintPass for UninitVec {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNINIT_VEC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UninitVec {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        let next_exprs = block
+            .stmts
+            .iter()
+            .skip(1)
+            .map(stmt_expr)
+            .chain(Some(block.expr.as_ref().map(|expr| &**expr)));
+        for (stmt, next) in block.stmts.iter().zip(next_exprs) {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let Some((vec_id, reserve_span)) = reserved_vec(cx, stmt);
+                if let Some(next) = next;
+                if let Some(set_len) = set_len_call(cx, next, vec_id);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        UNINIT_VEC,
+                        set_len.span,
+                        "calling `set_len()` right after reserving a buffer creates uninitialized values",
+                        |db| {
+                            db.span_note(reserve_span, "the buffer is reserved here");
+                            db.help(
+                                "initialize the buffer before setting its length, e.g. with `vec![0; len]` or \
+                                 `resize`",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn stmt_expr(stmt: &Stmt) -> Option<&Expr> {
+    match stmt.node {
+        StmtKind::Expr(ref expr, _) | StmtKind::Semi(ref expr, _) => Some(expr),
+        StmtKind::Decl(..) => None,
+    }
+}
+
+/// If `stmt` is `let v = Vec::with_capacity(..)` or `v.reserve(..)`, returns the id of the local
+/// `v` and the span of the call.
+fn reserved_vec(cx: &LateContext, stmt: &Stmt) -> Option<(NodeId, Span)> {
+    match stmt.node {
+        StmtKind::Decl(ref decl, _) => if_chain! {
+            if let DeclKind::Local(ref local) = decl.node;
+            if let PatKind::Binding(_, id, _, _) = local.pat.node;
+            if let Some(ref init) = local.init;
+            if let ExprKind::Call(ref func, _) = init.node;
+            if let ExprKind::Path(QPath::TypeRelative(_, ref method)) = func.node;
+            if method.ident.name == "with_capacity";
+            if match_type(cx, cx.tables.expr_ty(init), &paths::VEC);
+            then {
+                return Some((id, init.span));
+            }
+        },
+        StmtKind::Expr(ref expr, _) | StmtKind::Semi(ref expr, _) => if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if method.ident.name == "reserve" || method.ident.name == "reserve_exact";
+            if let Some(id) = local_id(&args[0]);
+            if match_type(cx, cx.tables.expr_ty(&args[0]), &paths::VEC);
+            then {
+                return Some((id, expr.span));
+            }
+        },
+    }
+    None
+}
+
+/// Returns the `vec.set_len(..)` call of `expr`, directly or as the first thing in an `unsafe`
+/// block. `vec.set_len(0)` is skipped, it doesn't make any value uninitialized.
+fn set_len_call<'e>(cx: &LateContext, expr: &'e Expr, vec_id: NodeId) -> Option<&'e Expr> {
+    let call = match expr.node {
+        ExprKind::Block(ref block, _) => match block.stmts.first() {
+            Some(stmt) => stmt_expr(stmt)?,
+            None => block.expr.as_ref()?,
+        },
+        _ => expr,
+    };
+    if_chain! {
+        if let ExprKind::MethodCall(ref method, _, ref args) = call.node;
+        if method.ident.name == "set_len";
+        if local_id(&args[0]) == Some(vec_id);
+        if constant_simple(cx, cx.tables, &args[1]) != Some(Constant::Int(0));
+        then {
+            return Some(call);
+        }
+    }
+    None
+}
+
+fn local_id(expr: &Expr) -> Option<NodeId> {
+    if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+        if let Def::Local(id) = path.def {
+            return Some(id);
+        }
+    }
+    None
+}
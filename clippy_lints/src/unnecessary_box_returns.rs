@@ -0,0 +1,160 @@
+use if_chain::if_chain;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::map::Node::NodeItem;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::{HashMap, HashSet};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::usage::FnUses;
+use crate::utils::{get_parent_expr, in_macro, return_ty, span_lint_node_and_then};
+
+/// **What it does:** Checks for private functions that return a `Box<T>` of a sized type `T`,
+/// where every caller dereferences the box right away.
+///
+/// **Why is this bad?** The box is an allocation that the callers throw away immediately.
+/// Returning `T` avoids it, and the callers can still box the value if they need to.
+///
+/// **Known problems:** A function that is used as a value, e.g. passed to `map`, is not linted,
+/// as the dereferences of the boxes it returns can't be seen.
+///
+/// **Example:**
+///
+/// ```rust
+/// fn foo() -> Box<String> {
+///     Box::new(String::from("Hello, world!"))
+/// }
+///
+/// let s = *foo();
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo() -> String {
+///     String::from("Hello, world!")
+/// }
+///
+/// let s = foo();
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_BOX_RETURNS,
+    pedantic,
+    "functions returning a `Box` that every caller dereferences"
+}
+
+struct Candidate {
+    id: NodeId,
+    ret_ty_span: Span,
+    boxed_ty: String,
+}
+
+#[derive(Default)]
+pub struct UnnecessaryBoxReturns {
+    candidates: Vec<(DefId, Candidate)>,
+    /// spans of the dereferenced calls to each function
+    deref_sites: HashMap<DefId, Vec<Span>>,
+    /// functions with a call whose result is used as a `Box`
+    used_boxed: HashSet<DefId>,
+    fn_uses: FnUses,
+}
+
+impl UnnecessaryBoxReturns {
+    fn check_call(&mut self, cx: &LateContext, call: &Expr, def_id: DefId) {
+        if_chain! {
+            if let Some(parent) = get_parent_expr(cx, call);
+            if let ExprKind::Unary(UnDeref, ref inner) = parent.node;
+            if inner.id == call.id;
+            then {
+                self.deref_sites.entry(def_id).or_insert_with(Vec::new).push(parent.span);
+                return;
+            }
+        }
+        self.used_boxed.insert(def_id);
+    }
+}
+
+impl LintPass for UnnecessaryBoxReturns {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_BOX_RETURNS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessaryBoxReturns {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        match kind {
+            FnKind::ItemFn(..) => (),
+            // only inherent methods can change their signature
+            FnKind::Method(..) => match cx.tcx.hir.find(cx.tcx.hir.get_parent(node_id)) {
+                Some(NodeItem(&Item { node: ItemKind::Impl(.., None, _, _), .. })) => (),
+                _ => return,
+            },
+            FnKind::Closure(..) => return,
+        }
+        if in_macro(span) || cx.access_levels.is_exported(node_id) {
+            return;
+        }
+
+        let ret_ty = return_ty(cx, node_id);
+        if_chain! {
+            if ret_ty.is_box();
+            let boxed_ty = ret_ty.boxed_ty();
+            if boxed_ty.is_sized(cx.tcx.at(span), cx.param_env);
+            if let FunctionRetTy::Return(ref ret_hir_ty) = decl.output;
+            then {
+                self.candidates.push((
+                    cx.tcx.hir.local_def_id(node_id),
+                    Candidate {
+                        id: node_id,
+                        ret_ty_span: ret_hir_ty.span,
+                        boxed_ty: boxed_ty.to_string(),
+                    },
+                ));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let Some(def_id) = self.fn_uses.check_expr(cx, expr) {
+            self.check_call(cx, expr, def_id);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(ref def_id, ref candidate) in &self.candidates {
+            if self.fn_uses.is_used_as_value(*def_id) || self.used_boxed.contains(def_id) {
+                continue;
+            }
+            let deref_sites = self.deref_sites.get(def_id);
+            span_lint_node_and_then(
+                cx,
+                UNNECESSARY_BOX_RETURNS,
+                candidate.id,
+                candidate.ret_ty_span,
+                &format!("boxed return of the sized type `{}`", candidate.boxed_ty),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        candidate.ret_ty_span,
+                        "try",
+                        candidate.boxed_ty.clone(),
+                        Applicability::MaybeIncorrect,
+                    );
+                    db.help("changing this also requires a change to the return expressions in this function");
+                    for &deref_site in deref_sites.into_iter().flatten() {
+                        db.span_note(deref_site, "this dereference needs to be removed");
+                    }
+                },
+            );
+        }
+    }
+}
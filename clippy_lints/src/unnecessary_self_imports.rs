@@ -0,0 +1,79 @@
+use if_chain::if_chain;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use syntax::symbol::keywords;
+
+use crate::utils::{in_macro, span_lint_and_then};
+
+/// **What it does:** Checks for imports ending in `::{self}`.
+///
+/// **Why is this bad?** In most cases, this can be written much more cleanly by omitting
+/// `::{self}`.
+///
+/// **Known problems:** Removing `::{self}` will cause any non-module items at the same path to
+/// also be imported. This might cause a naming conflict
+/// ([#3419](https://github.com/rust-lang/rust/issues/3419)). This lint makes no attempt to
+/// detect this scenario and that is why it is a restriction lint.
+///
+/// **Example:**
+///
+/// ```rust
+/// use std::io::{self};
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::io;
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_SELF_IMPORTS,
+    restriction,
+    "imports ending in `::{self}`, which can be omitted"
+}
+
+pub struct UnnecessarySelfImports;
+
+impl LintPass for UnnecessarySelfImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_SELF_IMPORTS)
+    }
+}
+
+impl EarlyLintPass for UnnecessarySelfImports {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if_chain! {
+            if !in_macro(item.span);
+            if let ItemKind::Use(ref use_tree) = item.node;
+            if let UseTreeKind::Nested(ref nodes) = use_tree.kind;
+            if let [(ref self_tree, _)] = **nodes;
+            if let [ref self_seg] = *self_tree.prefix.segments;
+            if self_seg.ident.name == keywords::SelfValue.name();
+            if let Some(last_segment) = use_tree.prefix.segments.last();
+            then {
+                let rename = match self_tree.kind {
+                    UseTreeKind::Simple(Some(alias), ..) => format!(" as {}", alias),
+                    _ => String::new(),
+                };
+                span_lint_and_then(
+                    cx,
+                    UNNECESSARY_SELF_IMPORTS,
+                    item.span,
+                    "import ending with `::{self}`",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            last_segment.ident.span.with_hi(item.span.hi()),
+                            "consider omitting `::{self}`",
+                            format!("{}{};", last_segment.ident, rename),
+                            Applicability::MaybeIncorrect,
+                        );
+                        db.note(
+                            "this will slightly change semantics; any non-module items at the same path will also \
+                             be imported",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, is_copy, match_def_path, paths, snippet, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** Detects uses of `sort_by` and `sort_unstable_by` whose comparator only
+/// compares the two elements, or a key of them, with `Ord::cmp`.
+///
+/// **Why is this bad?** `sort` and `sort_by_key` (or their unstable variants) say the same
+/// with less code, and a comparator can mix up the two elements by accident.
+///
+/// **Known problems:** Keys that borrow from the element, or would move out of it, can't be
+/// used with `sort_by_key`, so these comparators are not linted.
+///
+/// **Example:**
+/// ```rust
+/// # let mut vec: Vec<i32> = vec![1, -2, 3];
+/// vec.sort_by(|a, b| a.abs().cmp(&b.abs()));
+/// vec.sort_by(|a, b| b.abs().cmp(&a.abs()));
+/// ```
+///
+/// Use instead:
+/// ```rust
+/// # let mut vec: Vec<i32> = vec![1, -2, 3];
+/// vec.sort_by_key(|a| a.abs());
+/// vec.sort_by_key(|b| std::cmp::Reverse(b.abs()));
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_SORT_BY,
+    complexity,
+    "use of `sort_by` when `sort` or `sort_by_key` would do"
+}
+
+pub struct UnnecessarySortBy;
+
+impl LintPass for UnnecessarySortBy {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_SORT_BY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessarySortBy {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, ref args) = expr.node;
+            if args.len() == 2;
+            if !in_macro(expr.span);
+            if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id);
+            let unstable = if match_def_path(cx.tcx, def.def_id(), &paths::SLICE_SORT_BY) {
+                ""
+            } else if match_def_path(cx.tcx, def.def_id(), &paths::SLICE_SORT_UNSTABLE_BY) {
+                "_unstable"
+            } else {
+                return;
+            };
+            if let ExprKind::Closure(_, _, body_id, _, _) = args[1].node;
+            let body = cx.tcx.hir.body(body_id);
+            if let [ref a_arg, ref b_arg] = *body.arguments;
+            if let PatKind::Binding(_, a_id, a_ident, None) = a_arg.pat.node;
+            if let PatKind::Binding(_, b_id, b_ident, None) = b_arg.pat.node;
+            if let ExprKind::MethodCall(_, _, ref cmp_args) = body.value.node;
+            if let Some(cmp) = cx.tables.type_dependent_defs().get(body.value.hir_id);
+            if match_def_path(cx.tcx, cmp.def_id(), &paths::ORD_CMP);
+            then {
+                let left = &cmp_args[0];
+                let right = match cmp_args[1].node {
+                    ExprKind::AddrOf(MutImmutable, ref inner) => &**inner,
+                    _ => &cmp_args[1],
+                };
+                let recv = Sugg::hir(cx, &args[0], "..").maybe_par();
+                let (method, sugg) = if is_local(left, a_id) && is_local(right, b_id) {
+                    (format!("sort{}", unstable), format!("{}.sort{}()", recv, unstable))
+                } else if is_local(left, b_id) && is_local(right, a_id) {
+                    // the elements are references, so they have to be copied out to be a key
+                    if !is_copy(cx, walk_ptrs_ty(cx.tables.expr_ty(left))) {
+                        return;
+                    }
+                    (
+                        format!("sort{}_by_key", unstable),
+                        format!("{}.sort{}_by_key(|{}| std::cmp::Reverse(*{}))", recv, unstable, b_ident, b_ident),
+                    )
+                } else if borrows(cx, left) || (is_place(left) && !is_copy(cx, cx.tables.expr_ty(left))) {
+                    return;
+                } else if mirrored_exprs(cx, left, a_id, right, b_id) {
+                    (
+                        format!("sort{}_by_key", unstable),
+                        format!("{}.sort{}_by_key(|{}| {})", recv, unstable, a_ident, snippet(cx, left.span, "..")),
+                    )
+                } else if mirrored_exprs(cx, left, b_id, right, a_id) {
+                    (
+                        format!("sort{}_by_key", unstable),
+                        format!(
+                            "{}.sort{}_by_key(|{}| std::cmp::Reverse({}))",
+                            recv,
+                            unstable,
+                            b_ident,
+                            snippet(cx, left.span, "..")
+                        ),
+                    )
+                } else {
+                    return;
+                };
+                span_lint_and_then(cx, UNNECESSARY_SORT_BY, expr.span, &format!("use `{}` here instead", method), |db| {
+                    db.span_suggestion_with_applicability(expr.span, "try", sugg, Applicability::MachineApplicable);
+                });
+            }
+        }
+    }
+}
+
+/// Is `expr` a path to the local binding `id`?
+fn is_local(expr: &Expr, id: NodeId) -> bool {
+    if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+        if let Def::Local(local_id) = path.def {
+            return local_id == id;
+        }
+    }
+    false
+}
+
+/// Does the value of `expr` borrow anything, so it can't be used as the key of `sort_by_key`?
+fn borrows(cx: &LateContext, expr: &Expr) -> bool {
+    cx.tables.expr_ty(expr).walk().any(|ty| match ty.sty {
+        ty::TyRef(..) => true,
+        _ => false,
+    })
+}
+
+/// Does `expr` denote a place, whose value would be moved out of the element when used as a key?
+fn is_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnDeref, _) => true,
+        _ => false,
+    }
+}
+
+/// Is `left` the same expression as `right` where the binding `a_id` is used instead of `b_id`?
+fn mirrored_exprs(cx: &LateContext, left: &Expr, a_id: NodeId, right: &Expr, b_id: NodeId) -> bool {
+    let mirrored_all = |left: &[Expr], right: &[Expr]| {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right)
+                .all(|(l, r)| mirrored_exprs(cx, l, a_id, r, b_id))
+    };
+    match (&left.node, &right.node) {
+        (&ExprKind::MethodCall(ref l_method, _, ref l_args), &ExprKind::MethodCall(ref r_method, _, ref r_args)) => {
+            l_method.ident.name == r_method.ident.name && mirrored_all(l_args, r_args)
+        },
+        (&ExprKind::Call(ref l_func, ref l_args), &ExprKind::Call(ref r_func, ref r_args)) => {
+            mirrored_exprs(cx, l_func, a_id, r_func, b_id) && mirrored_all(l_args, r_args)
+        },
+        (&ExprKind::Field(ref l_base, l_ident), &ExprKind::Field(ref r_base, r_ident)) => {
+            l_ident.name == r_ident.name && mirrored_exprs(cx, l_base, a_id, r_base, b_id)
+        },
+        (&ExprKind::Index(ref l_base, ref l_idx), &ExprKind::Index(ref r_base, ref r_idx)) => {
+            mirrored_exprs(cx, l_base, a_id, r_base, b_id) && mirrored_exprs(cx, l_idx, a_id, r_idx, b_id)
+        },
+        (&ExprKind::Unary(l_op, ref l_inner), &ExprKind::Unary(r_op, ref r_inner)) => {
+            l_op == r_op && mirrored_exprs(cx, l_inner, a_id, r_inner, b_id)
+        },
+        (&ExprKind::AddrOf(l_mut, ref l_inner), &ExprKind::AddrOf(r_mut, ref r_inner)) => {
+            l_mut == r_mut && mirrored_exprs(cx, l_inner, a_id, r_inner, b_id)
+        },
+        (&ExprKind::Binary(l_op, ref l_lhs, ref l_rhs), &ExprKind::Binary(r_op, ref r_lhs, ref r_rhs)) => {
+            l_op.node == r_op.node
+                && mirrored_exprs(cx, l_lhs, a_id, r_lhs, b_id)
+                && mirrored_exprs(cx, l_rhs, a_id, r_rhs, b_id)
+        },
+        (&ExprKind::Tup(ref l_exprs), &ExprKind::Tup(ref r_exprs)) => mirrored_all(l_exprs, r_exprs),
+        (&ExprKind::Lit(ref l_lit), &ExprKind::Lit(ref r_lit)) => l_lit.node == r_lit.node,
+        (&ExprKind::Path(ref l_path), &ExprKind::Path(ref r_path)) => {
+            let l_def = cx.tables.qpath_def(l_path, left.hir_id);
+            let r_def = cx.tables.qpath_def(r_path, right.hir_id);
+            match (l_def, r_def) {
+                (Def::Local(l_id), Def::Local(r_id)) if l_id == a_id || r_id == b_id => l_id == a_id && r_id == b_id,
+                _ => l_def == r_def,
+            }
+        },
+        _ => false,
+    }
+}
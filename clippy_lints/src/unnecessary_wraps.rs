@@ -0,0 +1,240 @@
+use if_chain::if_chain;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::map::Node::NodeItem;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use std::collections::HashMap;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::usage::FnUses;
+use crate::utils::{in_macro, last_path_segment, match_def_path, match_type, multispan_sugg, paths, return_ty, snippet,
+                   span_lint_node_and_then};
+
+/// **What it does:** Checks for private functions that only return `Ok` or `Some`.
+///
+/// **Why is this bad?** It is not meaningful to wrap values when no `None` or `Err` is returned.
+///
+/// **Known problems:** Functions that are used as values (e.g. passed to `map`) are not linted,
+/// but everything that would break when the signature changes outside of the crate (e.g. uses
+/// in macros of other crates) is not considered.
+///
+/// **Example:**
+///
+/// ```rust
+/// fn get_cool_number(a: bool, b: bool) -> Option<i32> {
+///     if a && b {
+///         return Some(50);
+///     }
+///     if a {
+///         Some(0)
+///     } else {
+///         Some(10)
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn get_cool_number(a: bool, b: bool) -> i32 {
+///     if a && b {
+///         return 50;
+///     }
+///     if a {
+///         0
+///     } else {
+///         10
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_WRAPS,
+    pedantic,
+    "functions that only return `Ok` or `Some`"
+}
+
+struct Candidate {
+    id: NodeId,
+    span: Span,
+    wrapper: &'static str,
+    ret_ty_sugg: (Span, String),
+    returns_sugg: Vec<(Span, String)>,
+}
+
+#[derive(Default)]
+pub struct UnnecessaryWraps {
+    candidates: Vec<(DefId, Candidate)>,
+    /// spans of the calls to each function
+    call_sites: HashMap<DefId, Vec<Span>>,
+    fn_uses: FnUses,
+}
+
+impl LintPass for UnnecessaryWraps {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_WRAPS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessaryWraps {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        match kind {
+            FnKind::ItemFn(..) => (),
+            // only inherent methods can change their signature
+            FnKind::Method(..) => match cx.tcx.hir.find(cx.tcx.hir.get_parent(node_id)) {
+                Some(NodeItem(&Item { node: ItemKind::Impl(.., None, _, _), .. })) => (),
+                _ => return,
+            },
+            FnKind::Closure(..) => return,
+        }
+        if in_macro(span) || cx.access_levels.is_exported(node_id) {
+            return;
+        }
+
+        let ret_ty = return_ty(cx, node_id);
+        let (wrapper, ctor_path): (_, &[&str]) = if match_type(cx, ret_ty, &paths::OPTION) {
+            ("Option", &paths::OPTION_SOME)
+        } else if match_type(cx, ret_ty, &paths::RESULT) {
+            ("Result", &paths::RESULT_OK)
+        } else {
+            return;
+        };
+
+        let mut returns = Vec::new();
+        collect_returned_exprs(&body.value, &mut returns);
+        let mut visitor = ReturnVisitor { returns: &mut returns };
+        visitor.visit_expr(&body.value);
+
+        let mut returns_sugg = Vec::new();
+        for ret in returns {
+            if cx.tables.expr_ty(ret).is_never() {
+                continue;
+            }
+            if_chain! {
+                if !in_macro(ret.span);
+                if let ExprKind::Call(ref func, ref args) = ret.node;
+                if args.len() == 1;
+                if let ExprKind::Path(ref qpath) = func.node;
+                if match_def_path(cx.tcx, cx.tables.qpath_def(qpath, func.hir_id).def_id(), ctor_path);
+                then {
+                    returns_sugg.push((ret.span, snippet(cx, args[0].span, "..").into_owned()));
+                    continue;
+                }
+            }
+            return;
+        }
+
+        if_chain! {
+            if !returns_sugg.is_empty();
+            if let FunctionRetTy::Return(ref ret_hir_ty) = decl.output;
+            if let TyKind::Path(ref qpath) = ret_hir_ty.node;
+            if let Some(ref params) = last_path_segment(qpath).args;
+            if let Some(inner_hir_ty) = params.args.iter().find_map(|arg| match arg {
+                GenericArg::Type(ty) => Some(ty),
+                GenericArg::Lifetime(_) => None,
+            });
+            then {
+                self.candidates.push((
+                    cx.tcx.hir.local_def_id(node_id),
+                    Candidate {
+                        id: node_id,
+                        span: span.with_hi(ret_hir_ty.span.hi()),
+                        wrapper,
+                        ret_ty_sugg: (ret_hir_ty.span, snippet(cx, inner_hir_ty.span, "..").into_owned()),
+                        returns_sugg,
+                    },
+                ));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let Some(def_id) = self.fn_uses.check_expr(cx, expr) {
+            self.call_sites.entry(def_id).or_insert_with(Vec::new).push(expr.span);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(ref def_id, ref candidate) in &self.candidates {
+            if self.fn_uses.is_used_as_value(*def_id) {
+                continue;
+            }
+            let call_sites = self.call_sites.get(def_id);
+            span_lint_node_and_then(
+                cx,
+                UNNECESSARY_WRAPS,
+                candidate.id,
+                candidate.span,
+                &format!(
+                    "this function's return value is unnecessarily wrapped by `{}`",
+                    candidate.wrapper
+                ),
+                |db| {
+                    multispan_sugg(
+                        db,
+                        format!("remove `{}` from the return type...", candidate.wrapper),
+                        vec![candidate.ret_ty_sugg.clone()],
+                    );
+                    multispan_sugg(
+                        db,
+                        "...and change the returning expressions".to_string(),
+                        candidate.returns_sugg.clone(),
+                    );
+                    for &call_site in call_sites.into_iter().flatten() {
+                        db.span_note(call_site, "this call site needs to be updated");
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Collects the expressions that are the value of `expr`, looking through blocks, `if`s and
+/// `match`es.
+fn collect_returned_exprs<'tcx>(expr: &'tcx Expr, returns: &mut Vec<&'tcx Expr>) {
+    match expr.node {
+        ExprKind::Block(ref block, _) => {
+            // a block without a tail expression either returns `()` or diverges
+            if let Some(ref expr) = block.expr {
+                collect_returned_exprs(expr, returns);
+            }
+        },
+        ExprKind::If(_, ref then, Some(ref els)) => {
+            collect_returned_exprs(then, returns);
+            collect_returned_exprs(els, returns);
+        },
+        ExprKind::Match(_, ref arms, MatchSource::Normal)
+        | ExprKind::Match(_, ref arms, MatchSource::IfLetDesugar { contains_else_clause: true }) => {
+            for arm in arms {
+                collect_returned_exprs(&arm.body, returns);
+            }
+        },
+        _ => returns.push(expr),
+    }
+}
+
+/// Collects the values of all `return` expressions outside of closures.
+struct ReturnVisitor<'r, 'tcx: 'r> {
+    returns: &'r mut Vec<&'tcx Expr>,
+}
+
+impl<'r, 'tcx> Visitor<'tcx> for ReturnVisitor<'r, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Ret(Some(ref value)) = expr.node {
+            collect_returned_exprs(value, self.returns);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,113 @@
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for functions that are declared `async` but have no `await!`s
+/// inside of them.
+///
+/// **Why is this bad?** Async functions with no async code create overhead, both mentally and
+/// computationally. Callers of async methods either need to be calling from an async
+/// function themselves or run it on an executor, both of which causes runtime overhead and
+/// hassle for the caller.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust,ignore
+/// async fn get_random_number() -> i64 {
+///     4 // Chosen by fair dice roll. Guaranteed to be random.
+/// }
+/// let number_future = get_random_number();
+/// ```
+///
+/// Could be written as:
+///
+/// ```rust,ignore
+/// fn get_random_number_improved() -> i64 {
+///     4 // Chosen by fair dice roll. Guaranteed to be random.
+/// }
+/// let number_future = async { get_random_number_improved() };
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_ASYNC,
+    pedantic,
+    "finds async functions with no await statements"
+}
+
+pub struct UnusedAsync;
+
+impl LintPass for UnusedAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_ASYNC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedAsync {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        let header = match kind {
+            FnKind::ItemFn(_, _, header, ..) => header,
+            FnKind::Method(_, sig, ..) => sig.header,
+            FnKind::Closure(..) => return,
+        };
+        if header.asyncness != IsAsync::Async || in_macro(span) {
+            return;
+        }
+
+        let mut visitor = AsyncFnVisitor {
+            cx,
+            found_await: false,
+            in_generator: false,
+        };
+        visitor.visit_expr(&body.value);
+        if !visitor.found_await {
+            span_help_and_lint(
+                cx,
+                UNUSED_ASYNC,
+                span,
+                "unused `async` for function with no await statements",
+                "consider removing the `async` from this function",
+            );
+        }
+    }
+}
+
+/// Looks for the `yield`s that `await!` expands to. The body of an `async fn` is lowered to a
+/// generator, so the first closure that is found is the function body itself. Any further
+/// closures or `async` blocks are not searched, as their `await!`s don't belong to the function.
+struct AsyncFnVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found_await: bool,
+    in_generator: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AsyncFnVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Yield(_) => self.found_await = true,
+            ExprKind::Closure(..) if self.in_generator => (),
+            ExprKind::Closure(..) => {
+                self.in_generator = true;
+                walk_expr(self, expr);
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
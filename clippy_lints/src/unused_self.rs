@@ -0,0 +1,109 @@
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+
+use crate::utils::{in_external_macro, span_help_and_lint};
+
+/// **What it does:** Checks methods that contain a `self` argument but don't use it
+///
+/// **Why is this bad?** It may be clearer to define the method as an associated function instead
+/// of an instance method if it doesn't require `self`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,ignore
+/// struct A;
+/// impl A {
+///     fn method(&self) {}
+/// }
+/// ```
+///
+/// Could be written:
+///
+/// ```rust,ignore
+/// struct A;
+/// impl A {
+///     fn method() {}
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_SELF,
+    pedantic,
+    "methods that contain a `self` argument but don't use it"
+}
+
+pub struct UnusedSelf;
+
+impl LintPass for UnusedSelf {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_SELF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedSelf {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_external_macro(cx, item.span) {
+            return;
+        }
+        // trait implementations can't drop the `self` argument
+        if let ItemKind::Impl(_, _, _, _, None, _, ref impl_item_refs) = item.node {
+            for impl_item_ref in impl_item_refs {
+                let impl_item = cx.tcx.hir.impl_item(impl_item_ref.id);
+                if_chain! {
+                    if let ImplItemKind::Method(_, body_id) = impl_item.node;
+                    if cx.tcx.associated_item(cx.tcx.hir.local_def_id(impl_item.id)).method_has_self_argument;
+                    let body = cx.tcx.hir.body(body_id);
+                    if let PatKind::Binding(_, self_id, _, _) = body.arguments[0].pat.node;
+                    then {
+                        let mut visitor = UnusedSelfVisitor {
+                            cx,
+                            uses_self: false,
+                            self_id,
+                        };
+                        visitor.visit_body(body);
+                        if !visitor.uses_self {
+                            span_help_and_lint(
+                                cx,
+                                UNUSED_SELF,
+                                body.arguments[0].pat.span,
+                                "unused `self` argument",
+                                "consider refactoring to an associated function",
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct UnusedSelfVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    uses_self: bool,
+    self_id: NodeId,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnusedSelfVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.uses_self {
+            // This function already uses `self`
+            return;
+        }
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+            match path.def {
+                Def::Local(id) | Def::Upvar(id, ..) if id == self.self_id => self.uses_self = true,
+                _ => (),
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
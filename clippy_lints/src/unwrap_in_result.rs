@@ -0,0 +1,137 @@
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, is_in_test, match_type, paths, return_ty, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** Checks for functions of type Result that contain `expect()` or `unwrap()`
+///
+/// **Why is this bad?** These functions promote recoverable errors to non-recoverable errors which
+/// may be undesirable in code bases which wish to avoid panics.
+///
+/// **Known problems:** This can cause false positives in functions that handle both recoverable
+/// and non recoverable errors. Test code is only skipped if the `allow-unwrap-in-tests` option is
+/// set.
+///
+/// **Example:**
+/// Before:
+/// ```rust
+/// fn divisible_by_3(i_str: String) -> Result<(), String> {
+///     let i = i_str
+///         .parse::<i32>()
+///         .expect("cannot divide the input by three");
+///
+///     if i % 3 != 0 {
+///         Err("Number is not divisible by 3")?
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// After:
+/// ```rust
+/// fn divisible_by_3(i_str: String) -> Result<(), String> {
+///     let i = i_str
+///         .parse::<i32>()
+///         .map_err(|e| format!("cannot divide the input by three: {}", e))?;
+///
+///     if i % 3 != 0 {
+///         Err("Number is not divisible by 3")?
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNWRAP_IN_RESULT,
+    restriction,
+    "functions of type `Result<..>` or `Option`<...> that contain `expect()` or `unwrap()`"
+}
+
+pub struct UnwrapInResult {
+    allow_unwrap_in_tests: bool,
+}
+
+impl UnwrapInResult {
+    pub fn new(allow_unwrap_in_tests: bool) -> Self {
+        Self { allow_unwrap_in_tests }
+    }
+}
+
+impl LintPass for UnwrapInResult {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNWRAP_IN_RESULT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnwrapInResult {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        let ret_ty = return_ty(cx, node_id);
+        if in_macro(span) || !(match_type(cx, ret_ty, &paths::RESULT) || match_type(cx, ret_ty, &paths::OPTION)) {
+            return;
+        }
+        if self.allow_unwrap_in_tests && is_in_test(cx, node_id) {
+            return;
+        }
+
+        let mut finder = FindUnwrap {
+            cx,
+            unwraps: Vec::new(),
+        };
+        finder.visit_expr(&body.value);
+        if finder.unwraps.is_empty() {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            UNWRAP_IN_RESULT,
+            span,
+            "used unwrap or expect in a function that returns result or option",
+            |db| {
+                db.help("unwrap and expect should not be used in a function that returns result or option");
+                for &unwrap in &finder.unwraps {
+                    db.span_note(unwrap, "potential non-recoverable error(s)");
+                }
+            },
+        );
+    }
+}
+
+struct FindUnwrap<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    unwraps: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindUnwrap<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            if path.ident.name == "unwrap" || path.ident.name == "expect" {
+                let recv_ty = walk_ptrs_ty(self.cx.tables.expr_ty(&args[0]));
+                if match_type(self.cx, recv_ty, &paths::OPTION) || match_type(self.cx, recv_ty, &paths::RESULT) {
+                    self.unwraps.push(expr.span);
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    // closures have a return type of their own
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
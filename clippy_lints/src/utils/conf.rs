@@ -131,8 +131,8 @@ macro_rules! define_Conf {
 define_Conf! {
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about
     (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
-    /// Lint: CYCLOMATIC_COMPLEXITY. The maximum cyclomatic complexity a function can have
-    (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", 25 => u64),
+    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    (cognitive_complexity_threshold, "cognitive_complexity_threshold", 25 => u64),
     /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks
     (doc_valid_idents, "doc_valid_idents", [
         "KiB", "MiB", "GiB", "TiB", "PiB", "EiB",
@@ -166,8 +166,63 @@ define_Conf! {
     (verbose_bit_mask_threshold, "verbose_bit_mask_threshold", 1 => u64),
     /// Lint: DECIMAL_LITERAL_REPRESENTATION. The lower bound for linting decimal literals
     (literal_representation_threshold, "literal_representation_threshold", 16384 => u64),
-    /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
+    /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference. By default this is twice the target's pointer width, but no more than 8 bytes
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
+    /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
+    (too_many_lines_threshold, "too_many_lines_threshold", 100 => u64),
+    /// Lint: STRUCT_EXCESSIVE_BOOLS. The maximum number of bools a struct can have
+    (max_struct_bools, "max_struct_bools", 3 => u64),
+    /// Lint: FN_PARAMS_EXCESSIVE_BOOLS. The maximum number of bools function parameters can have
+    (max_fn_params_bools, "max_fn_params_bools", 3 => u64),
+    /// Lint: CAST_ABS_TO_UNSIGNED, BORROW_AS_PTR, MISSING_CONST_FOR_FN, DRAIN_COLLECT, MANUAL_CLAMP, MANUAL_REM_EUCLID, MANUAL_BITS. The minimum rust version that the project supports
+    (msrv, "msrv", None => Option<String>),
+    /// Lint: MISSING_PANICS_DOC. Whether `debug_assert!`s should be ignored when looking for possible panics
+    (missing_panics_doc_ignore_debug_assert, "missing_panics_doc_ignore_debug_assert", false => bool),
+    /// Lint: UNWRAP_IN_RESULT. Whether `unwrap` and `expect` should be allowed in `#[test]` functions and `#[cfg(test)]` modules
+    (allow_unwrap_in_tests, "allow_unwrap_in_tests", false => bool),
+    /// Lint: AS_CONVERSIONS. Whether pointer-to-pointer casts and `usize` casts of indices should be allowed
+    (as_conversions_exempt_ptr_and_index, "as_conversions_exempt_ptr_and_index", false => bool),
+    /// Lint: WILDCARD_IMPORTS. Whether to also lint wildcard imports of `prelude` modules and `use super::*` in test modules
+    (warn_on_all_wildcard_imports, "warn_on_all_wildcard_imports", false => bool),
+    /// Lint: ENUM_GLOB_USE. The enums, by name or by path, whose variants may be imported with a glob import
+    (enum_glob_use_whitelist, "enum_glob_use_whitelist", Vec::<&str>::new() => Vec<String>),
+    /// Lint: PUB_USE. The names of the modules in which `pub use` re-exports are allowed
+    (pub_use_allowed_modules, "pub_use_allowed_modules", ["prelude"] => Vec<String>),
+    /// Lint: MIN_IDENT_CHARS. The idents that are allowed regardless of their length
+    (allowed_idents_below_min_chars, "allowed_idents_below_min_chars", ["i", "j", "x", "y", "z", "w", "n"] => Vec<String>),
+    /// Lint: MIN_IDENT_CHARS. The maximum number of chars of the idents that are linted
+    (min_ident_chars_threshold, "min_ident_chars_threshold", 1 => u64),
+    /// Lint: STUTTER. The prefixes that are allowed in front of the containing module's name, e.g. `into` for `IntoFoo` in module `foo`
+    (stutter_allowed_prefixes, "stutter_allowed_prefixes", Vec::<&str>::new() => Vec<String>),
+    /// Lint: STUTTER. The suffixes that are allowed after the containing module's name, e.g. `stream` for `TokenStream` in module `token`
+    (stutter_allowed_suffixes, "stutter_allowed_suffixes", Vec::<&str>::new() => Vec<String>),
+    /// Lint: AWAIT_HOLDING_LOCK. The paths of the lock guard types where they are defined, e.g. `std::sync::mutex::MutexGuard`
+    (await_holding_lock_guards, "await_holding_lock_guards", [
+        "std::sync::mutex::MutexGuard",
+        "std::sync::rwlock::RwLockReadGuard",
+        "std::sync::rwlock::RwLockWriteGuard",
+        "lock_api::mutex::MutexGuard",
+        "lock_api::rwlock::RwLockReadGuard",
+        "lock_api::rwlock::RwLockWriteGuard",
+    ] => Vec<String>),
+    /// Lint: LARGE_FUTURES. The maximum size (in bytes) of a future that can be awaited without boxing it
+    (future_size_threshold, "future_size_threshold", 16 * 1024 => u64),
+    /// Lint: BLOCKING_IN_ASYNC. The paths of further blocking functions, a path also matches every item inside of it
+    (blocking_in_async_paths, "blocking_in_async_paths", Vec::<&str>::new() => Vec<String>),
+    /// Lint: BLOCK_ON_IN_ASYNC. The paths of the functions that block on a future
+    (block_on_paths, "block_on_paths", [
+        "futures::executor::block_on",
+        "tokio::runtime::Runtime::block_on",
+        "async_std::task::block_on",
+    ] => Vec<String>),
+    /// Lint: LARGE_TYPES_PASSED_BY_VALUE. The minimum size (in bytes) to consider a type for passing by reference instead of by value.
+    (pass_by_value_size_limit, "pass_by_value_size_limit", 256 => u64),
+    /// Lint: LARGE_STACK_ARRAYS. The maximum allowed size for arrays on the stack
+    (array_size_threshold, "array_size_threshold", 512_000 => u64),
+    /// Lint: MUTABLE_KEY_TYPE. The paths of the types with interior mutability that don't affect their `Hash` or `Ord` implementations, e.g. `bytes::bytes::Bytes`
+    (ignore_interior_mutability, "ignore_interior_mutability", ["bytes::bytes::Bytes"] => Vec<String>),
+    /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY. Use the `cognitive-complexity-threshold` option instead
+    (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", None => Option<u64>),
 }
 
 /// Search for the configuration file.
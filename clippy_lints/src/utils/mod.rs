@@ -12,6 +12,7 @@ use rustc::session::Session;
 use rustc::traits;
 use rustc::ty::{self, Binder, Ty, TyCtxt, layout::{self, IntegerExt}, subst::Kind};
 use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart};
+use semver::Version;
 use std::borrow::Cow;
 use std::env;
 use std::mem;
@@ -19,10 +20,11 @@ use std::str::FromStr;
 use std::rc::Rc;
 use syntax::ast::{self, LitKind};
 use syntax::attr;
-use syntax::codemap::{CompilerDesugaringKind, ExpnFormat, ExpnInfo, Span, DUMMY_SP};
+use syntax::codemap::{BytePos, CompilerDesugaringKind, ExpnFormat, ExpnInfo, Span, DUMMY_SP};
 use syntax::errors::DiagnosticBuilder;
 use syntax::ptr::P;
-use syntax::symbol::keywords;
+use syntax::symbol::{keywords, LocalInternedString, Symbol};
+use syntax_pos::Pos;
 
 pub mod comparisons;
 pub mod conf;
@@ -107,19 +109,10 @@ pub fn in_external_macro<'a, T: LintContext<'a>>(cx: &T, span: Span) -> bool {
         .map_or(false, |info| in_macro_ext(cx, &info))
 }
 
-/// Check if a `DefId`'s path matches the given absolute type path usage.
-///
-/// # Examples
-/// ```rust,ignore
-/// match_def_path(cx.tcx, id, &["core", "option", "Option"])
-/// ```
-///
-/// See also the `paths` module.
-pub fn match_def_path(tcx: TyCtxt, def_id: DefId, path: &[&str]) -> bool {
-    use syntax::symbol;
-
+/// Get the absolute path of a `DefId`, e.g. `["core", "option", "Option"]`.
+pub fn get_def_path(tcx: TyCtxt, def_id: DefId) -> Vec<LocalInternedString> {
     struct AbsolutePathBuffer {
-        names: Vec<symbol::LocalInternedString>,
+        names: Vec<LocalInternedString>,
     }
 
     impl ty::item_path::ItemPathBuffer for AbsolutePathBuffer {
@@ -129,7 +122,7 @@ pub fn match_def_path(tcx: TyCtxt, def_id: DefId, path: &[&str]) -> bool {
         }
 
         fn push(&mut self, text: &str) {
-            self.names.push(symbol::Symbol::intern(text).as_str());
+            self.names.push(Symbol::intern(text).as_str());
         }
     }
 
@@ -137,11 +130,21 @@ pub fn match_def_path(tcx: TyCtxt, def_id: DefId, path: &[&str]) -> bool {
 
     tcx.push_item_path(&mut apb, def_id);
 
-    apb.names.len() == path.len()
-        && apb.names
-            .into_iter()
-            .zip(path.iter())
-            .all(|(a, &b)| *a == *b)
+    apb.names
+}
+
+/// Check if a `DefId`'s path matches the given absolute type path usage.
+///
+/// # Examples
+/// ```rust,ignore
+/// match_def_path(cx.tcx, id, &["core", "option", "Option"])
+/// ```
+///
+/// See also the `paths` module.
+pub fn match_def_path(tcx: TyCtxt, def_id: DefId, path: &[&str]) -> bool {
+    let names = get_def_path(tcx, def_id);
+
+    names.len() == path.len() && names.into_iter().zip(path.iter()).all(|(a, &b)| *a == *b)
 }
 
 /// Check if type is struct, enum or union type with given def path.
@@ -437,6 +440,40 @@ pub fn last_line_of_span<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Span {
     Span::new(*line_start, span.hi(), span.ctxt())
 }
 
+/// Returns the `//` comments on the lines right above the line `span` starts on, from the
+/// closest one upwards. Each comment comes with its span and its text without the leading
+/// slashes and whitespace.
+///
+/// # Example
+/// ```rust,ignore
+/// // SAFETY: `ptr` is valid
+/// unsafe { *ptr }
+/// ```
+/// Given the span of the `unsafe` block, the text of the only comment returned is
+/// ``SAFETY: `ptr` is valid``.
+pub fn comment_lines_above<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Vec<(Span, String)> {
+    let lo = cx.sess().codemap().lookup_char_pos(span.lo());
+    let mut comments = Vec::new();
+    // line numbers in `Loc` are 1-based, `get_line` takes 0-based ones
+    for line_no in (0..lo.line - 1).rev() {
+        let line = match lo.file.get_line(line_no) {
+            Some(line) => line,
+            None => break,
+        };
+        let start = match line.find(|c: char| !c.is_whitespace()) {
+            Some(start) if line[start..].starts_with("//") => start,
+            _ => break,
+        };
+        let comment = line[start..].trim_right();
+        let comment_lo = lo.file.lines[line_no] + BytePos::from_usize(start);
+        comments.push((
+            Span::new(comment_lo, comment_lo + BytePos::from_usize(comment.len()), span.ctxt()),
+            comment.trim_left_matches('/').trim().to_string(),
+        ));
+    }
+    comments
+}
+
 /// Like `snippet_block`, but add braces if the expr is not an `ExprKind::Block`.
 /// Also takes an `Option<String>` which can be put inside the braces.
 pub fn expr_block<'a, 'b, T: LintContext<'b>>(
@@ -609,6 +646,30 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     db.docs_link(lint);
 }
 
+/// Like `span_lint`, but the lint level is taken from the node with the given
+/// id instead of the current position of the lint pass.
+pub fn span_lint_node(cx: &LateContext, lint: &'static Lint, node: NodeId, sp: Span, msg: &str) {
+    DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg)).docs_link(lint);
+}
+
+/// Like `span_lint_and_then`, but the lint level is taken from the node with
+/// the given id instead of the current position of the lint pass. Use this
+/// when emitting lints after the fact, e.g. in `check_crate_post`.
+pub fn span_lint_node_and_then<'a, 'tcx: 'a, F>(
+    cx: &'a LateContext<'a, 'tcx>,
+    lint: &'static Lint,
+    node: NodeId,
+    sp: Span,
+    msg: &str,
+    f: F,
+) where
+    F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
+{
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg));
+    f(&mut db.0);
+    db.docs_link(lint);
+}
+
 /// Add a span lint with a suggestion on how to fix it.
 ///
 /// These suggestions can be parsed by rustfix to allow it to automatically fix your code.
@@ -645,6 +706,18 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
 pub fn multispan_sugg<I>(db: &mut DiagnosticBuilder, help_msg: String, sugg: I)
 where
     I: IntoIterator<Item = (Span, String)>,
+{
+    multispan_sugg_with_applicability(db, help_msg, Applicability::Unspecified, sugg);
+}
+
+/// Like `multispan_sugg`, but with the given applicability.
+pub fn multispan_sugg_with_applicability<I>(
+    db: &mut DiagnosticBuilder,
+    help_msg: String,
+    applicability: Applicability,
+    sugg: I,
+) where
+    I: IntoIterator<Item = (Span, String)>,
 {
     let sugg = CodeSuggestion {
         substitutions: vec![
@@ -661,7 +734,7 @@ where
         ],
         msg: help_msg,
         show_code_when_inline: true,
-        applicability: Applicability::Unspecified,
+        applicability,
     };
     db.suggestions.push(sugg);
 }
@@ -705,6 +778,18 @@ pub fn is_integer_literal(expr: &Expr, value: u128) -> bool {
     false
 }
 
+/// Is `expr` a call to `ptr::null()` or `ptr::null_mut()`?
+pub fn is_null_path(expr: &Expr) -> bool {
+    if let ExprKind::Call(ref pathexp, ref args) = expr.node {
+        if args.is_empty() {
+            if let ExprKind::Path(ref path) = pathexp.node {
+                return match_qpath(path, &paths::PTR_NULL) || match_qpath(path, &paths::PTR_NULL_MUT);
+            }
+        }
+    }
+    false
+}
+
 pub fn is_adjusted(cx: &LateContext, e: &Expr) -> bool {
     cx.tables.adjustments().get(e.hir_id).is_some()
 }
@@ -962,6 +1047,14 @@ pub fn remove_blocks(expr: &Expr) -> &Expr {
     }
 }
 
+/// Returns the `DefId` of a function or method, or `None` for any other `Def`.
+pub fn fn_def_id(def: Def) -> Option<DefId> {
+    match def {
+        Def::Fn(def_id) | Def::Method(def_id) => Some(def_id),
+        _ => None,
+    }
+}
+
 pub fn opt_def_id(def: Def) -> Option<DefId> {
     match def {
         Def::Fn(id) |
@@ -1069,6 +1162,52 @@ pub fn is_allowed(cx: &LateContext, lint: &'static Lint, id: NodeId) -> bool {
     cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
 }
 
+/// Returns true if the node is a `#[test]` function or inside of one or of a `#[cfg(test)]` module
+pub fn is_in_test(cx: &LateContext, id: NodeId) -> bool {
+    any_parent_has_attr(cx, id, |attr| attr.check_name("test") || is_cfg_test_attr(attr))
+}
+
+/// Returns true if the node is inside of an item marked with `#[cfg(test)]`, usually a module
+pub fn is_in_cfg_test(cx: &LateContext, id: NodeId) -> bool {
+    any_parent_has_attr(cx, id, is_cfg_test_attr)
+}
+
+fn any_parent_has_attr(cx: &LateContext, mut id: NodeId, f: fn(&ast::Attribute) -> bool) -> bool {
+    loop {
+        if cx.tcx.hir.attrs(id).iter().any(f) {
+            return true;
+        }
+        if id == ast::CRATE_NODE_ID {
+            return false;
+        }
+        id = cx.tcx.hir.get_parent(id);
+    }
+}
+
+/// Checks if `expr` is the generator an `async` block, closure or fn is lowered to, as opposed
+/// to a closure of the `generators` feature.
+pub fn is_async_generator(cx: &LateContext, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Closure(.., Some(_)) = expr.node;
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::Call(ref func, _) = parent.node;
+        if let ExprKind::Path(ref qpath) = func.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+        then {
+            match_def_path(cx.tcx, def_id, &paths::FROM_GENERATOR)
+        } else {
+            false
+        }
+    }
+}
+
+fn is_cfg_test_attr(attr: &ast::Attribute) -> bool {
+    attr.check_name("cfg")
+        && attr
+            .meta_item_list()
+            .map_or(false, |items| items.iter().any(|item| item.check_name("test")))
+}
+
 pub fn get_arg_name(pat: &Pat) -> Option<ast::Name> {
     match pat.node {
         PatKind::Binding(_, _, ident, None) => Some(ident.name),
@@ -1154,3 +1293,19 @@ pub fn any_parent_is_automatically_derived(tcx: TyCtxt, node: NodeId) -> bool {
     }
     false
 }
+
+/// Parse the `msrv` configuration value, accepting both `1.30.0` and the
+/// shorter `1.30` form.
+pub fn parse_msrv(msrv: &str) -> Option<Version> {
+    Version::parse(msrv)
+        .or_else(|_| Version::parse(&format!("{}.0", msrv)))
+        .ok()
+}
+
+/// Returns true if the minimum supported Rust version configured by the user
+/// allows using a feature stabilized in `lint_msrv` (`[major, minor, patch]`).
+///
+/// If no MSRV is configured, every feature is assumed to be available.
+pub fn meets_msrv(msrv: Option<&Version>, lint_msrv: &[u64; 3]) -> bool {
+    msrv.map_or(true, |msrv| *msrv >= Version::new(lint_msrv[0], lint_msrv[1], lint_msrv[2]))
+}
@@ -1,6 +1,7 @@
 use rustc::lint::*;
 
 use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::hir::*;
 use rustc::middle::expr_use_visitor::*;
 use rustc::middle::mem_categorization::cmt_;
@@ -10,6 +11,8 @@ use std::collections::HashSet;
 use syntax::ast::NodeId;
 use syntax::codemap::Span;
 
+use crate::utils::fn_def_id;
+
 /// Returns a set of mutated local variable ids or None if mutations could not be determined.
 pub fn mutated_variables<'a, 'tcx: 'a>(expr: &'tcx Expr, cx: &'a LateContext<'a, 'tcx>) -> Option<HashSet<NodeId>> {
     let mut delegate = MutVarsDelegate {
@@ -80,3 +83,44 @@ impl<'tcx> Delegate<'tcx> for MutVarsDelegate {
 
     fn decl_without_init(&mut self, _: NodeId, _: Span) {}
 }
+
+/// Collects how the functions and methods of a crate are used, to be fed every expression from
+/// `check_expr`.
+#[derive(Default)]
+pub struct FnUses {
+    /// the ids of path expressions that are the callee of a call
+    callees: HashSet<NodeId>,
+    /// functions whose path is used other than by calling them
+    used_as_value: HashSet<DefId>,
+}
+
+impl FnUses {
+    /// Records the use of a function by `expr`, and returns the called function if `expr` is a
+    /// call of a function or method.
+    pub fn check_expr(&mut self, cx: &LateContext, expr: &Expr) -> Option<DefId> {
+        match expr.node {
+            ExprKind::Call(ref func, _) => if let ExprKind::Path(ref qpath) = func.node {
+                let def_id = fn_def_id(cx.tables.qpath_def(qpath, func.hir_id));
+                if def_id.is_some() {
+                    self.callees.insert(func.id);
+                }
+                return def_id;
+            },
+            ExprKind::MethodCall(..) => {
+                return cx.tables.type_dependent_defs().get(expr.hir_id).map(|def| def.def_id());
+            },
+            ExprKind::Path(ref qpath) => if !self.callees.contains(&expr.id) {
+                if let Some(def_id) = fn_def_id(cx.tables.qpath_def(qpath, expr.hir_id)) {
+                    self.used_as_value.insert(def_id);
+                }
+            },
+            _ => (),
+        }
+        None
+    }
+
+    /// Checks if the path of the function is used other than by calling it, e.g. passed to `map`.
+    pub fn is_used_as_value(&self, def_id: DefId) -> bool {
+        self.used_as_value.contains(&def_id)
+    }
+}
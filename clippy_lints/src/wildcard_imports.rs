@@ -0,0 +1,191 @@
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::{declare_lint, lint_array};
+use rustc_errors::Applicability;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+
+use crate::utils::{in_macro, is_in_test, opt_def_id, snippet_opt, span_lint_node_and_then};
+
+/// **What it does:** Checks for wildcard imports `use _::*`.
+///
+/// **Why is this bad?** wildcard imports can pollute the namespace. This is especially bad if
+/// you try to import something through a wildcard, that already has been imported by name from
+/// a different source:
+///
+/// ```rust,ignore
+/// use crate1::foo; // Imports a function named foo
+/// use crate2::*; // Has a function named foo
+///
+/// foo(); // Calls crate1::foo
+/// ```
+///
+/// This can lead to confusing error messages at best and to unexpected behavior at worst.
+///
+/// Wildcard imports of a `prelude` module and `use super::*` in test modules are allowed, unless
+/// the `warn-on-all-wildcard-imports` configuration option is set. Imports of enum variants are
+/// handled by the `enum_glob_use` lint and re-exports are not linted.
+///
+/// **Known problems:** Macros, traits that are only used through their methods and names that
+/// are used through a re-export are not taken into account when suggesting the list of imported
+/// names, so the suggestion may not compile.
+///
+/// **Example:**
+///
+/// ```rust,ignore
+/// use crate1::*;
+///
+/// foo();
+/// ```
+///
+/// Use instead:
+///
+/// ```rust,ignore
+/// use crate1::foo;
+///
+/// foo();
+/// ```
+declare_clippy_lint! {
+    pub WILDCARD_IMPORTS,
+    pedantic,
+    "lint `use _::*` statements"
+}
+
+struct GlobImport {
+    id: NodeId,
+    span: Span,
+    /// the module containing the `use` item
+    module: DefId,
+    /// the module the names are imported from
+    source: DefId,
+}
+
+pub struct WildcardImports {
+    warn_on_all: bool,
+    globs: Vec<GlobImport>,
+    /// the names used in a module that resolve to items of another module
+    used_names: HashMap<(DefId, DefId), BTreeSet<String>>,
+    /// the names imported by name into a module
+    single_imports: HashSet<(DefId, String)>,
+}
+
+impl WildcardImports {
+    pub fn new(warn_on_all: bool) -> Self {
+        Self {
+            warn_on_all,
+            globs: Vec::new(),
+            used_names: HashMap::new(),
+            single_imports: HashSet::new(),
+        }
+    }
+
+    fn is_exception(&self, cx: &LateContext, item: &Item, path: &Path) -> bool {
+        if self.warn_on_all {
+            return false;
+        }
+        if path.segments.iter().any(|segment| segment.ident.name == "prelude") {
+            return true;
+        }
+        // `use super::*` in test modules
+        path.segments.len() == 1 && path.segments[0].ident.name == "super" && is_in_test(cx, item.id)
+    }
+}
+
+impl LintPass for WildcardImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(WILDCARD_IMPORTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for WildcardImports {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || item.vis.node.is_pub() {
+            return; // re-exports are fine
+        }
+        match item.node {
+            ItemKind::Use(ref path, UseKind::Glob) => {
+                // enum variants are handled by `enum_glob_use`
+                if let Def::Mod(source) = path.def {
+                    if !self.is_exception(cx, item, path) {
+                        self.globs.push(GlobImport {
+                            id: item.id,
+                            span: item.span,
+                            module: cx.tcx.hir.get_module_parent(item.id),
+                            source,
+                        });
+                    }
+                }
+            },
+            ItemKind::Use(_, UseKind::Single) => {
+                let module = cx.tcx.hir.get_module_parent(item.id);
+                self.single_imports.insert((module, item.name.to_string()));
+            },
+            _ => (),
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, id: NodeId) {
+        let def_id = match path.def {
+            // the constructor of a unit or tuple struct is a child of the struct
+            Def::StructCtor(ctor_id, _) => cx.tcx.parent_def_id(ctor_id),
+            def => opt_def_id(def),
+        };
+        let def_id = match def_id {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        // find the item named by the first segment by going up from the item named by the
+        // last one
+        let mut first = Some(def_id);
+        for _ in 1..path.segments.len() {
+            first = first.and_then(|def_id| cx.tcx.parent_def_id(def_id));
+        }
+        if let (Some(first), Some(segment)) = (first, path.segments.first()) {
+            if let Some(source) = cx.tcx.parent_def_id(first) {
+                let module = cx.tcx.hir.get_module_parent(id);
+                self.used_names
+                    .entry((module, source))
+                    .or_insert_with(BTreeSet::new)
+                    .insert(segment.ident.to_string());
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for glob in &self.globs {
+            let used_names = match self.used_names.get(&(glob.module, glob.source)) {
+                Some(names) => names
+                    .iter()
+                    .filter(|name| !self.single_imports.contains(&(glob.module, name.to_string())))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                None => continue,
+            };
+            // unused imports are already linted by rustc
+            if used_names.is_empty() {
+                continue;
+            }
+            span_lint_node_and_then(cx, WILDCARD_IMPORTS, glob.id, glob.span, "usage of wildcard import", |db| {
+                if let Some(snippet) = snippet_opt(cx, glob.span) {
+                    if snippet.ends_with("*;") {
+                        let imports = if used_names.len() == 1 {
+                            used_names[0].clone()
+                        } else {
+                            format!("{{{}}}", used_names.join(", "))
+                        };
+                        // the list misses macros, traits only used through methods and re-exports
+                        db.span_suggestion_with_applicability(
+                            glob.span,
+                            "try",
+                            format!("{}{};", &snippet[..snippet.len() - 2], imports),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                }
+            });
+        }
+    }
+}
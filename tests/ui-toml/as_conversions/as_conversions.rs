@@ -0,0 +1,13 @@
+#![warn(as_conversions)]
+#![allow(cast_lossless, cast_possible_truncation)]
+
+fn main() {
+    let i = 0u32 as u64;
+
+    // allowed with `as-conversions-exempt-ptr-and-index`
+    let _ = &i as *const u64 as *mut u64;
+
+    let v = vec![1, 2, 3];
+    let index = 1u8;
+    let _ = v[index as usize];
+}
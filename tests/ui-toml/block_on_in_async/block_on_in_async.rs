@@ -0,0 +1,38 @@
+#![feature(async_await, await_macro, futures_api)]
+#![warn(block_on_in_async)]
+#![allow(dead_code)]
+
+mod executor {
+    use std::future::Future;
+
+    pub fn block_on<F: Future>(_future: F) -> F::Output {
+        unimplemented!()
+    }
+
+    pub struct Runtime;
+
+    impl Runtime {
+        pub fn block_on<F: Future>(&mut self, _future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+}
+
+async fn foo() -> u32 {
+    42
+}
+
+async fn bar() -> u32 {
+    executor::block_on(foo())
+}
+
+async fn baz() -> u32 {
+    let mut rt = executor::Runtime;
+    executor::Runtime::block_on(&mut rt, foo())
+}
+
+fn not_async() -> u32 {
+    executor::block_on(foo())
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![warn(drain_collect)]
+
+// `mem::take` is only available since Rust 1.40
+fn drain(v: &mut Vec<u32>) -> Vec<u32> {
+    v.drain(..).collect()
+}
+
+fn main() {
+    drain(&mut vec![1, 2, 3]);
+}
@@ -0,0 +1,12 @@
+#![warn(enum_glob_use)]
+#![allow(unused_imports, dead_code)]
+
+use std::cmp::Ordering::*; // ok, whitelisted
+
+enum Enum {
+    _Foo,
+}
+
+use self::Enum::*;
+
+fn main() {}
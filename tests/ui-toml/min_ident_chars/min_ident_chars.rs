@@ -0,0 +1,9 @@
+#![warn(min_ident_chars)]
+#![allow(unused_variables)]
+
+fn main() {
+    let id = 1;
+    let ab = 2;
+    let i = 3;
+    let abc = 4;
+}
@@ -0,0 +1,24 @@
+#![warn(cast_abs_to_unsigned, borrow_as_ptr, manual_bits)]
+
+// `unsigned_abs` is only available since Rust 1.51
+fn cast_abs_to_unsigned() {
+    let x: i32 = 10;
+    assert_eq!(10u32, x.abs() as u32);
+}
+
+// `ptr::addr_of!` is only available since Rust 1.51
+fn borrow_as_ptr() {
+    let val = 1;
+    let _p = &val as *const i32;
+}
+
+// `BITS` is only available since Rust 1.53
+fn manual_bits() {
+    let _ = std::mem::size_of::<u32>() * 8;
+}
+
+fn main() {
+    cast_abs_to_unsigned();
+    borrow_as_ptr();
+    manual_bits();
+}
@@ -0,0 +1,18 @@
+#![warn(manual_clamp, manual_rem_euclid)]
+
+// `clamp` is only available since Rust 1.50
+fn manual_clamp() {
+    let input: u32 = 5;
+    let _ = input.max(2).min(8);
+}
+
+// `rem_euclid` is only available since Rust 1.38
+fn manual_rem_euclid() {
+    let value: i32 = 5;
+    let _ = ((value % 4) + 4) % 4;
+}
+
+fn main() {
+    manual_clamp();
+    manual_rem_euclid();
+}
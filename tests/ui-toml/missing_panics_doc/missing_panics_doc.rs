@@ -0,0 +1,11 @@
+#![warn(missing_panics_doc)]
+
+/// `debug_assert!`s are ignored with `missing-panics-doc-ignore-debug-assert`
+pub fn debug_assert(x: u32) {
+    debug_assert!(x > 0);
+    debug_assert_eq!(x, 1);
+}
+
+fn main() {
+    debug_assert(1);
+}
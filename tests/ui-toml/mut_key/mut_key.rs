@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+// the counter is not part of `Hash` and `Eq`, so changing it doesn't move the key
+struct Counted {
+    id: u32,
+    lookups: Cell<u32>,
+}
+
+impl PartialEq for Counted {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Counted {}
+
+impl Hash for Counted {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.id.hash(h);
+    }
+}
+
+fn ignored(_: &HashSet<Counted>) {}
+
+fn main() {}
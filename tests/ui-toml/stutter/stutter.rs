@@ -0,0 +1,11 @@
+#![warn(stutter)]
+#![allow(dead_code)]
+
+mod token {
+    pub struct TokenStream;
+    pub struct IntoToken;
+    pub struct TokenTree;
+    pub struct FromToken;
+}
+
+fn main() {}
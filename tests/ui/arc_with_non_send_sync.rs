@@ -0,0 +1,16 @@
+#![warn(arc_with_non_send_sync)]
+#![allow(unused_variables)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+fn generic<T>(x: T) -> Arc<T> {
+    Arc::new(x)
+}
+
+fn main() {
+    let ok = Arc::new(Mutex::new(42));
+    let not_sync = Arc::new(RefCell::new(42));
+    let neither = Arc::new(Rc::new(42));
+}
@@ -2,7 +2,7 @@
 
 
 #![warn(integer_arithmetic, float_arithmetic)]
-#![allow(unused, shadow_reuse, shadow_unrelated, no_effect, unnecessary_operation)]
+#![allow(unused, const_err, shadow_reuse, shadow_unrelated, no_effect, unnecessary_operation)]
 fn main() {
     let i = 1i32;
     1 + i;
@@ -27,4 +27,19 @@ fn main() {
     f / 2.0;
     f - 2.0 * 4.2;
     -f;
+
+    // compile-time arithmetic that can't overflow
+    1 + 2;
+    -1i32;
+    std::i32::MAX - 1;
+    -128i8;
+    // overflows the type, even though it fits the 128 bits of the evaluation
+    255u8 + 1;
+    127i8 + 1;
+
+    let mut j = 1i32;
+    j += 1;
+    j *= 2;
+    let mut g = 1.0f32;
+    g -= 1.0;
 }
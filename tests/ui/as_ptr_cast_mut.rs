@@ -0,0 +1,35 @@
+#![warn(as_ptr_cast_mut)]
+#![allow(cast_ptr_alignment)]
+
+struct MutPtrWrapper(Vec<u8>);
+impl MutPtrWrapper {
+    fn as_ptr(&mut self) -> *const u8 {
+        self.0.as_mut_ptr() as *const u8
+    }
+}
+
+struct Covariant<T>(*const T);
+impl<T> Covariant<T> {
+    fn as_ptr(self) -> *const T {
+        self.0
+    }
+}
+
+fn main() {
+    let string = String::new();
+    let _ = string.as_ptr() as *mut u8;
+    let _: *mut i8 = string.as_ptr() as *mut _;
+    let _ = string.as_ptr() as *const i8;
+
+    let mut v = vec![1_u32, 2];
+    let _ = v.as_ptr() as *mut u32;
+    let _ = v[..].as_ptr() as *mut u32;
+    let _ = v.as_mut_ptr() as *mut u32;
+
+    let mut wrap = MutPtrWrapper(Vec::new());
+    let _ = wrap.as_ptr() as *mut u8;
+
+    let local = 0u8;
+    let covariant = Covariant(&local as *const u8);
+    let _ = covariant.as_ptr() as *mut u8;
+}
@@ -0,0 +1,30 @@
+#![feature(async_await, await_macro, futures_api, generators)]
+#![warn(async_yields_async)]
+#![allow(unused_variables)]
+
+async fn foo() -> u32 {
+    42
+}
+
+async fn bar() -> u32 {
+    await!(foo())
+}
+
+fn main() {
+    let not_awaited = async {
+        println!("inside");
+        foo()
+    };
+    let closure = async || foo();
+    let awaited = async {
+        await!(foo())
+    };
+    let value = async {
+        1
+    };
+    // not `async`
+    let generator = || {
+        yield 1;
+        foo()
+    };
+}
@@ -0,0 +1,38 @@
+#![feature(async_await, await_macro, futures_api)]
+#![warn(await_holding_lock)]
+#![allow(dead_code, unused_variables)]
+
+use std::sync::{Mutex, RwLock};
+
+async fn baz() -> u32 {
+    42
+}
+
+async fn bad(x: &Mutex<u32>) -> u32 {
+    let guard = x.lock().unwrap();
+    await!(baz())
+}
+
+async fn bad_rw(x: &RwLock<u32>) -> u32 {
+    let guard = x.write().unwrap();
+    let y = await!(baz());
+    *guard + y
+}
+
+async fn good(x: &Mutex<u32>) -> u32 {
+    {
+        let guard = x.lock().unwrap();
+        let y = *guard + 1;
+    }
+    await!(baz());
+    let guard = x.lock().unwrap();
+    47
+}
+
+async fn dropped(x: &Mutex<u32>) -> u32 {
+    let guard = x.lock().unwrap();
+    drop(guard);
+    await!(baz())
+}
+
+fn main() {}
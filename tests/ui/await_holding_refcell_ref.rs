@@ -0,0 +1,36 @@
+#![feature(async_await, await_macro, futures_api)]
+#![warn(await_holding_refcell_ref)]
+#![allow(dead_code, unused_variables)]
+
+use std::cell::RefCell;
+
+async fn baz() -> u32 {
+    42
+}
+
+async fn bad(x: &RefCell<u32>) -> u32 {
+    let b = x.borrow();
+    await!(baz())
+}
+
+async fn bad_mut(x: &RefCell<u32>) -> u32 {
+    let mut b = x.borrow_mut();
+    *b += await!(baz());
+    *b
+}
+
+async fn good(x: &RefCell<u32>) -> u32 {
+    {
+        let b = x.borrow_mut();
+        let y = *b + 1;
+    }
+    await!(baz())
+}
+
+async fn dropped(x: &RefCell<u32>) -> u32 {
+    let b = x.borrow_mut();
+    drop(b);
+    await!(baz())
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+#![feature(async_await, await_macro, futures_api, generators)]
+#![warn(blocking_in_async)]
+#![allow(dead_code, unused_must_use, unused_variables)]
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+async fn sleeping() {
+    thread::sleep(Duration::from_millis(1));
+}
+
+async fn file() {
+    File::open("foo.txt");
+}
+
+async fn read_file(mut file: File) {
+    let mut buf = [0; 4];
+    file.read(&mut buf);
+}
+
+async fn write_stream(mut stream: TcpStream) {
+    stream.write_all(b"hello");
+}
+
+// these don't do any I/O
+async fn no_io(addr: SocketAddr) {
+    Ipv4Addr::new(127, 0, 0, 1);
+    addr.port();
+    let mut buf = [0; 4];
+    (&b"abcd"[..]).read(&mut buf);
+}
+
+fn not_async() {
+    thread::sleep(Duration::from_millis(1));
+}
+
+fn main() {
+    let block = async {
+        std::fs::read_to_string("foo.txt");
+    };
+    let closure_in_async = async {
+        let f = || thread::sleep(Duration::from_millis(1));
+    };
+    // not `async`
+    let generator = || {
+        thread::sleep(Duration::from_millis(1));
+        yield;
+    };
+}
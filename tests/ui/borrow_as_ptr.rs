@@ -0,0 +1,22 @@
+#![warn(borrow_as_ptr)]
+
+fn get() -> i32 {
+    1
+}
+
+fn main() {
+    let val = 1;
+    let _p = &val as *const i32;
+
+    let mut val_mut = 1;
+    let _p_mut = &mut val_mut as *mut i32;
+
+    let p = &val as *const i32;
+    // borrows of temporaries are fine
+    let _ = &1 as *const i32;
+    let _ = &get() as *const i32;
+    // casting an existing raw pointer is fine
+    let _ = p as *const u8;
+
+    let _p_const = &mut val_mut as *const i32;
+}
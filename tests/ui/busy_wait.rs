@@ -0,0 +1,56 @@
+#![warn(busy_wait)]
+
+use std::sync::atomic::{spin_loop_hint, AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::Mutex;
+use std::thread;
+
+fn spinning(flag: &AtomicBool, m: &Mutex<u32>, rx: &Receiver<u32>) {
+    while !flag.load(Ordering::Acquire) {}
+
+    while m.try_lock().is_err() {}
+
+    loop {
+        if rx.try_recv().is_ok() {
+            break;
+        }
+    }
+}
+
+fn backing_off(flag: &AtomicBool, m: &Mutex<u32>, rx: &Receiver<u32>) {
+    while !flag.load(Ordering::Acquire) {
+        spin_loop_hint();
+    }
+
+    while m.try_lock().is_err() {
+        thread::yield_now();
+    }
+
+    while let Ok(x) = rx.try_recv() {
+        println!("{}", x);
+    }
+}
+
+struct Lock;
+
+impl Lock {
+    fn try_lock(&self) -> Result<(), ()> {
+        Ok(())
+    }
+}
+
+fn not_waiting(m: &Mutex<u32>, rx: &Receiver<u32>, lock: &Lock) {
+    // these go on while the poll succeeds
+    while rx.try_recv().is_ok() {}
+    while let Ok(_) = rx.try_recv() {}
+    loop {
+        if m.try_lock().is_err() {
+            break;
+        }
+    }
+
+    // not a `Mutex`
+    while lock.try_lock().is_err() {}
+}
+
+fn main() {}
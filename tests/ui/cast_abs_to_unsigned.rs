@@ -0,0 +1,16 @@
+#![warn(cast_abs_to_unsigned)]
+#![allow(cast_lossless)]
+
+fn main() {
+    let x: i32 = -42;
+    let _: u32 = x.abs() as u32;
+    let _: u64 = x.abs() as u64;
+    let _: u8 = (x - 1).abs() as u8;
+
+    // not a signed integer receiver
+    let y: f32 = -1.5;
+    let _ = y.abs() as u32;
+
+    // not cast to an unsigned integer
+    let _: i64 = x.abs() as i64;
+}
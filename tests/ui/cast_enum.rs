@@ -0,0 +1,43 @@
+#![warn(cast_enum_truncation, cast_enum_constructor)]
+#![allow(dead_code, fn_to_numeric_cast)]
+
+enum Small {
+    A,
+    B,
+}
+
+enum Large {
+    A = 255,
+    B = 256,
+}
+
+#[repr(i16)]
+enum Signed {
+    A = -129,
+    B = 127,
+}
+
+#[repr(u64)]
+enum Wide {
+    A = 0x1_0000_0000,
+}
+
+enum Tuple {
+    A(u32),
+    B,
+}
+
+fn main() {
+    let _ = Small::A as u8;
+    let _ = Large::A as u8;
+    let _ = Large::B as u8;
+    let _ = Large::B as u16;
+    let _ = Signed::A as i8;
+    let _ = Signed::B as i8;
+    let _ = Wide::A as usize;
+
+    let large = Large::A;
+    let _ = large as u8;
+
+    let _ = Tuple::A as usize;
+}
@@ -0,0 +1,26 @@
+#![allow(cast_ptr_alignment)]
+
+fn main() {
+    let x: [i32; 3] = [1_i32, 2, 3];
+    let r_x = &x;
+    // Check casting through multiple bindings
+    // Because it's separate, it does not check the cast back to something of the same size
+    let a = r_x as *const [i32];
+    let b = a as *const [u8];
+    let c = b as *const [u32];
+
+    // loses data
+    let loss = r_x as *const [i32] as *const [u8];
+
+    // not linted: cast back to the previous size after going through a differently sized type
+    let restore = r_x as *const [i32] as *const [u8] as *const [i32];
+
+    // mutable pointers
+    let mut y = [1_u16, 2];
+    let _ = &mut y as *mut [u16] as *mut [u64];
+
+    // same size, different types are fine
+    let _ = r_x as *const [i32] as *const [u32];
+    let _ = r_x as *const [i32] as *const [f32];
+    let _ = (a, b, c, loss, restore);
+}
@@ -1,7 +1,7 @@
 #![feature(tool_attributes)]
 
 #![allow(clippy)]
-#![warn(cyclomatic_complexity)]
+#![warn(cognitive_complexity)]
 #![allow(unused)]
 
 fn main() {
@@ -88,7 +88,7 @@ fn main() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn kaboom() {
     let n = 0;
     'a: for i in 0..20 {
@@ -134,17 +134,17 @@ fn bloo() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn lots_of_short_circuits() -> bool {
     true && false && true && false && true && false && true
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn lots_of_short_circuits2() -> bool {
     true || false || true || false || true || false || true
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn baa() {
     let x = || match 99 {
         0 => 0,
@@ -162,7 +162,7 @@ fn baa() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn bar() {
     match 99 {
         0 => println!("hi"),
@@ -171,8 +171,8 @@ fn bar() {
 }
 
 #[test]
-#[clippy::cyclomatic_complexity = "0"]
-/// Tests are usually complex but simple at the same time. `cyclomatic_complexity` used to give
+#[clippy::cognitive_complexity = "0"]
+/// Tests are usually complex but simple at the same time. `cognitive_complexity` used to give
 /// lots of false-positives in tests.
 fn dont_warn_on_tests() {
     match 99 {
@@ -181,7 +181,7 @@ fn dont_warn_on_tests() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barr() {
     match 99 {
         0 => println!("hi"),
@@ -191,7 +191,7 @@ fn barr() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barr2() {
     match 99 {
         0 => println!("hi"),
@@ -207,7 +207,7 @@ fn barr2() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barrr() {
     match 99 {
         0 => println!("hi"),
@@ -217,7 +217,7 @@ fn barrr() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barrr2() {
     match 99 {
         0 => println!("hi"),
@@ -233,7 +233,7 @@ fn barrr2() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barrrr() {
     match 99 {
         0 => println!("hi"),
@@ -243,7 +243,7 @@ fn barrrr() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn barrrr2() {
     match 99 {
         0 => println!("hi"),
@@ -259,7 +259,7 @@ fn barrrr2() {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn cake() {
     if 4 == 5 {
         println!("yea");
@@ -270,7 +270,7 @@ fn cake() {
 }
 
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 pub fn read_file(input_path: &str) -> String {
     use std::fs::File;
     use std::io::{Read, Write};
@@ -301,7 +301,7 @@ pub fn read_file(input_path: &str) -> String {
 
 enum Void {}
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn void(void: Void) {
     if true {
         match void {
@@ -309,13 +309,13 @@ fn void(void: Void) {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn mcarton_sees_all() {
     panic!("meh");
     panic!("möh");
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn try() -> Result<i32, &'static str> {
     match 5 {
         5 => Ok(5),
@@ -323,7 +323,7 @@ fn try() -> Result<i32, &'static str> {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn try_again() -> Result<i32, &'static str> {
     let _ = try!(Ok(42));
     let _ = try!(Ok(43));
@@ -339,7 +339,7 @@ fn try_again() -> Result<i32, &'static str> {
     }
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn early() -> Result<i32, &'static str> {
     return Ok(5);
     return Ok(5);
@@ -352,7 +352,7 @@ fn early() -> Result<i32, &'static str> {
     return Ok(5);
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn early_ret() -> i32 {
     let a = if true { 42 } else { return 0; };
     let a = if a < 99 { 42 } else { return 0; };
@@ -371,3 +371,48 @@ fn early_ret() -> i32 {
         _ => return 6,
     }
 }
+
+#[clippy::cognitive_complexity = "0"]
+fn nested_ifs() {
+    if true {
+        if true {
+            if true {
+                println!("deep");
+            }
+        }
+    }
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn else_if_chain(x: u8) {
+    if x == 0 {
+        println!("zero");
+    } else if x == 1 {
+        println!("one");
+    } else {
+        println!("many");
+    }
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn mixed_short_circuits(a: bool, b: bool) -> bool {
+    a && b || !a && !b
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn if_let_in_while_let(mut v: Vec<Option<u8>>) {
+    while let Some(x) = v.pop() {
+        if let Some(y) = x {
+            println!("{}", y);
+        } else {
+            break;
+        }
+    }
+}
+
+#[clippy::cyclomatic_complexity = "0"]
+fn deprecated_attribute() {
+    if true {
+        println!("a");
+    }
+}
@@ -1,13 +1,13 @@
 #![feature(tool_attributes)]
 
-#![warn(cyclomatic_complexity)]
+#![warn(cognitive_complexity)]
 #![warn(unused)]
 
 fn main() {
     kaboom();
 }
 
-#[clippy::cyclomatic_complexity = "0"]
+#[clippy::cognitive_complexity = "0"]
 fn kaboom() {
     if 42 == 43 {
         panic!();
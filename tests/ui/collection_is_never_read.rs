@@ -0,0 +1,40 @@
+#![warn(collection_is_never_read)]
+
+use std::collections::{HashMap, HashSet};
+
+fn consume(_: &[u32]) {}
+
+fn main() {
+    let mut v = Vec::new();
+    v.push(1);
+    v.extend(vec![2, 3]);
+
+    let mut m = HashMap::new();
+    m.insert(1, 2);
+
+    let mut s = String::new();
+    s.push_str("foo");
+
+    // read after writing
+    let mut w = Vec::new();
+    w.push(1);
+    consume(&w);
+
+    let mut x = Vec::new();
+    x.push(1);
+    println!("{}", x.len());
+
+    // read in a closure
+    let mut y = Vec::new();
+    y.push(1);
+    let _ = || y.len();
+
+    // the result of the write is read
+    let mut seen = HashSet::new();
+    if seen.insert(1) {
+        println!("new");
+    }
+
+    // never written to either
+    let _set: HashSet<u32> = HashSet::new();
+}
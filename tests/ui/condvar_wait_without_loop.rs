@@ -0,0 +1,28 @@
+#![warn(condvar_wait_without_loop)]
+
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+fn not_in_loop(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut started = lock.lock().unwrap();
+    if !*started {
+        started = cvar.wait(started).unwrap();
+    }
+    let _ = cvar.wait_timeout(started, Duration::from_millis(10)).unwrap();
+}
+
+fn in_loop(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut started = lock.lock().unwrap();
+    while !*started {
+        started = cvar.wait(started).unwrap();
+    }
+    loop {
+        let (guard, timeout) = cvar.wait_timeout(started, Duration::from_millis(10)).unwrap();
+        started = guard;
+        if *started || timeout.timed_out() {
+            break;
+        }
+    }
+}
+
+fn main() {}
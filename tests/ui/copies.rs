@@ -1,4 +1,4 @@
-#![allow(blacklisted_name, collapsible_if, cyclomatic_complexity, eq_op, needless_continue,
+#![allow(blacklisted_name, collapsible_if, cognitive_complexity, eq_op, needless_continue,
          needless_return, never_loop, no_effect, zero_divided_by_zero)]
 
 fn bar<T>(_: T) {}
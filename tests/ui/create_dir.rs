@@ -0,0 +1,16 @@
+#![allow(unused_must_use)]
+#![warn(create_dir)]
+
+use std::fs::create_dir;
+
+fn create_dir_wrapper() {}
+
+fn main() {
+    // Should be warned
+    create_dir("foo");
+    std::fs::create_dir("bar").unwrap();
+
+    // Shouldn't be warned
+    create_dir_wrapper();
+    std::fs::create_dir_all("foobar");
+}
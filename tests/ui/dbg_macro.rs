@@ -0,0 +1,34 @@
+#![warn(dbg_macro)]
+
+// `std::dbg!` is newer than the toolchain, this mirrors its expansion
+macro_rules! dbg {
+    ($val:expr) => {
+        match $val {
+            tmp => {
+                eprintln!("[{}:{}] {} = {:#?}", file!(), line!(), stringify!($val), &tmp);
+                tmp
+            },
+        }
+    };
+}
+
+fn foo(n: u32) -> u32 {
+    if let Some(n) = dbg!(n.checked_sub(4)) {
+        n
+    } else {
+        n
+    }
+}
+
+fn factorial(n: u32) -> u32 {
+    if dbg!(n <= 1) {
+        dbg!(1)
+    } else {
+        dbg!(n * factorial(n - 1))
+    }
+}
+
+fn main() {
+    dbg!(42);
+    let _ = foo(3) + dbg!(factorial(4));
+}
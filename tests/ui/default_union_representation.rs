@@ -0,0 +1,65 @@
+#![warn(default_union_representation)]
+#![allow(dead_code)]
+
+union NoAttribute {
+    a: i32,
+    b: u32,
+}
+
+#[repr(C)]
+union ReprC {
+    a: i32,
+    b: u32,
+}
+
+#[repr(packed)]
+union ReprPacked {
+    a: i32,
+    b: u32,
+}
+
+#[repr(C, packed)]
+union ReprCPacked {
+    a: i32,
+    b: u32,
+}
+
+union ZeroSizedWithoutAttribute {
+    a: i32,
+    b: (),
+}
+
+union Generic<T: Copy> {
+    a: T,
+    b: u32,
+}
+
+union Assigned {
+    a: i32,
+    b: u32,
+}
+
+// not linted: only read through the field that is written
+union SameField {
+    a: i32,
+    b: u32,
+}
+
+fn main() {
+    unsafe {
+        let _ = NoAttribute { a: 0 }.b;
+        let _ = ReprC { a: 0 }.b;
+        let _ = ReprPacked { a: 0 }.b;
+        let _ = ReprCPacked { a: 0 }.b;
+        let _ = ZeroSizedWithoutAttribute { a: 0 }.b;
+        let _ = Generic::<u32> { a: 0 }.b;
+
+        let mut assigned = Assigned { a: 0 };
+        assigned.b = 1;
+        let _ = assigned.a;
+
+        let mut same = SameField { a: 0 };
+        same.a = 1;
+        let _ = same.a;
+    }
+}
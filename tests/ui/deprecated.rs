@@ -1,10 +1,6 @@
 
 
 
-#[warn(str_to_string)]
-
-#[warn(string_to_string)]
-
 #[warn(unstable_as_slice)]
 
 #[warn(unstable_as_mut_slice)]
@@ -0,0 +1,13 @@
+#![warn(detached_thread)]
+
+use std::thread;
+
+fn main() {
+    thread::spawn(|| println!("detached"));
+    let _ = thread::spawn(|| println!("detached"));
+    thread::Builder::new().spawn(|| println!("detached")).unwrap();
+
+    let handle = thread::spawn(|| println!("joined"));
+    handle.join().unwrap();
+    let _detached = thread::spawn(|| println!("documented"));
+}
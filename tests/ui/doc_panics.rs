@@ -0,0 +1,68 @@
+#![warn(missing_panics_doc)]
+#![allow(dead_code)]
+
+/// This needs to be documented
+pub fn unwrap() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This needs to be documented
+pub fn panic() {
+    panic!("This function panics")
+}
+
+/// This needs to be documented
+pub fn assert_eq() {
+    let x = 0;
+    assert_eq!(x, 0);
+}
+
+/// This needs to be documented
+pub fn index(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This needs to be documented, too
+pub fn debug_assert() {
+    debug_assert!(false);
+}
+
+/// # Panics
+///
+/// This function panics if the result is an error
+pub fn unwrap_documented() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because it is private
+fn unwrap_private() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because it can't panic
+pub fn no_panic(v: &[u32]) -> Option<&u32> {
+    v.get(0)
+}
+
+pub struct Struct;
+
+impl Struct {
+    /// This needs to be documented
+    pub fn expect(&self) {
+        let result = Err("Hi");
+        result.expect("hi")
+    }
+
+    /// # Panics
+    ///
+    /// This method panics if the result is an error
+    pub fn expect_documented(&self) {
+        let result = Err("Hi");
+        result.expect("hi")
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![warn(missing_safety_doc, unnecessary_safety_doc)]
+#![allow(dead_code)]
+
+/// This is not sufficiently documented
+pub unsafe fn destroy_the_planet() {
+    unimplemented!();
+}
+
+/// This one is
+///
+/// # Safety
+///
+/// This function shouldn't be called unless the horsemen are ready
+pub unsafe fn apocalypse(_universe: &mut ()) {
+    unimplemented!();
+}
+
+/// This is a private function, so docs aren't necessary
+unsafe fn you_dont_see_me() {
+    unimplemented!();
+}
+
+/// # Safety
+///
+/// This function is safe, so this section is stale
+pub fn already_safe() {}
+
+/// This trait is not sufficiently documented
+pub unsafe trait DestroyTheUniverse {}
+
+/// # Safety
+///
+/// This trait must only be implemented by the horsemen
+pub unsafe trait Apocalypse {}
+
+/// # Safety
+///
+/// Nothing unsafe about this trait
+pub trait SafeTrait {}
+
+pub struct Struct;
+
+impl Struct {
+    /// This method is not sufficiently documented
+    pub unsafe fn more_undocumented_unsafe() -> Self {
+        unimplemented!();
+    }
+
+    /// # Safety
+    ///
+    /// This method is documented
+    pub unsafe fn documented() -> Self {
+        unimplemented!();
+    }
+
+    /// # Safety
+    ///
+    /// This method is safe
+    pub fn safe_method() {}
+}
+
+fn main() {}
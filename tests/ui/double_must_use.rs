@@ -0,0 +1,32 @@
+#![warn(double_must_use)]
+
+#[must_use]
+pub fn must_use_result() -> Result<(), ()> {
+    unimplemented!();
+}
+
+#[must_use]
+pub fn must_use_tuple() -> (Result<(), ()>, u8) {
+    unimplemented!();
+}
+
+#[must_use]
+pub fn must_use_array() -> [Result<(), ()>; 1] {
+    unimplemented!();
+}
+
+#[must_use = "With note"]
+pub fn must_use_with_note() -> Result<(), ()> {
+    unimplemented!();
+}
+
+pub struct S;
+
+impl S {
+    #[must_use]
+    pub fn must_use_method(&self) -> Result<u8, ()> {
+        unimplemented!();
+    }
+}
+
+fn main() {}
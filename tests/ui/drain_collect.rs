@@ -0,0 +1,47 @@
+#![warn(drain_collect)]
+
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+fn vec(v: &mut Vec<u32>) -> Vec<u32> {
+    v.drain(..).collect()
+}
+
+fn vec_from_zero(v: &mut Vec<u32>) -> Vec<u32> {
+    v.drain(0..).collect()
+}
+
+fn vec_deque(v: &mut VecDeque<u32>) -> VecDeque<u32> {
+    v.drain(..).collect()
+}
+
+fn string(s: &mut String) -> String {
+    s.drain(..).collect()
+}
+
+fn hash_map(m: &mut HashMap<u32, u32>) -> HashMap<u32, u32> {
+    m.drain().collect()
+}
+
+fn hash_set(s: &mut HashSet<u32>) -> HashSet<u32> {
+    s.drain().collect()
+}
+
+fn binary_heap(h: &mut BinaryHeap<u32>) -> BinaryHeap<u32> {
+    h.drain().collect()
+}
+
+// not the whole collection
+fn partial(v: &mut Vec<u32>) -> Vec<u32> {
+    v.drain(1..).collect()
+}
+
+fn up_to(v: &mut Vec<u32>, n: usize) -> Vec<u32> {
+    v.drain(..n).collect()
+}
+
+// a different collection type
+fn other_collection(v: &mut Vec<u32>) -> VecDeque<u32> {
+    v.drain(..).collect()
+}
+
+fn main() {}
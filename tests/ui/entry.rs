@@ -37,6 +37,14 @@ fn insert_in_btreemap<K: Ord, V>(m: &mut BTreeMap<K, V>, k: K, v: V) {
     if !m.contains_key(&k) { foo(); m.insert(k, v) } else { None };
 }
 
+fn insert_with_if_absent<K: Eq + Hash, V: Default>(m: &mut HashMap<K, V>, k: K) {
+    if !m.contains_key(&k) { m.insert(k, V::default()); }
+}
+
+fn insert_if_absent_nested<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
+    if !m.contains_key(&k) { foo(); if true { m.insert(k, v); } }
+}
+
 fn insert_other_if_absent<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, o: K, v: V) {
     if !m.contains_key(&k) { m.insert(o, v); }
 }
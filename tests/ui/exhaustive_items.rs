@@ -0,0 +1,51 @@
+#![feature(non_exhaustive)]
+#![warn(exhaustive_enums, exhaustive_structs)]
+#![allow(dead_code)]
+
+fn main() {}
+
+pub mod enums {
+    pub enum Exhaustive {
+        Foo,
+        Bar,
+    }
+
+    #[non_exhaustive]
+    pub enum NonExhaustive {
+        Foo,
+        Bar,
+    }
+
+    pub enum WithHidden {
+        Foo,
+        #[doc(hidden)]
+        __Nonexhaustive,
+    }
+
+    enum NotExported {
+        Foo,
+        Bar,
+    }
+}
+
+pub mod structs {
+    pub struct Exhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    #[non_exhaustive]
+    pub struct NonExhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    pub struct WithPrivateField {
+        pub foo: u8,
+        bar: String,
+    }
+
+    struct NotExported {
+        pub foo: u8,
+    }
+}
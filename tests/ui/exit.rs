@@ -0,0 +1,20 @@
+#![warn(exit)]
+
+fn not_main() {
+    if true {
+        std::process::exit(4);
+    }
+}
+
+mod inner {
+    // not the entry point
+    pub fn main() {
+        std::process::exit(3);
+    }
+}
+
+fn main() {
+    not_main();
+    inner::main();
+    std::process::exit(0);
+}
@@ -0,0 +1,29 @@
+#![warn(extend_with_single_element)]
+
+use std::collections::{HashSet, VecDeque};
+use std::iter;
+
+fn main() {
+    let mut v = vec![1];
+    v.extend(iter::once(2));
+    v.extend(Some(3));
+    v.extend(std::iter::once(4 + 1));
+
+    let mut d = VecDeque::new();
+    d.extend(Some(1));
+
+    // not a single element
+    v.extend(vec![5, 6]);
+    v.extend(iter::repeat(7).take(2));
+    let opt = Some(8);
+    v.extend(opt);
+
+    // the item is a reference to the element
+    let x = 9;
+    v.extend(Some(&x));
+    v.extend(iter::once(&x));
+
+    // no `push`
+    let mut set = HashSet::new();
+    set.extend(Some(1));
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+const MY_EPSILON: f64 = 1e-6;
+
+fn is_roughly_equal(a: f32, b: f32) -> bool {
+    (a - b) < std::f32::EPSILON
+}
+
+fn main() {
+    let a = 0.05_f64;
+    let b = 0.0500001_f64;
+
+    let _ = (a - b) < std::f64::EPSILON;
+    let _ = a - b < std::f64::EPSILON;
+    let _ = std::f64::EPSILON > (a - b);
+    let _ = (a - b) < MY_EPSILON;
+
+    // fine
+    let _ = (a - b).abs() < std::f64::EPSILON;
+    let _ = std::f64::EPSILON > (a - b).abs();
+    let _ = (a - b) > std::f64::EPSILON;
+    let _ = (a - b) < 0.001;
+    let _ = (a + b) < std::f64::EPSILON;
+}
@@ -0,0 +1,32 @@
+#![warn(fn_params_excessive_bools)]
+#![allow(unused)]
+
+extern "C" {
+    fn f(_: bool, _: bool, _: bool, _: bool);
+}
+
+fn g(_: bool, _: bool, _: bool, _: bool) {}
+fn h(_: bool, _: Option<bool>, _: bool, _: Option<bool>) {}
+fn e(_: S, _: bool, _: bool, _: bool) {}
+fn o(_: Option<Option<bool>>, _: bool, _: bool, _: bool) {}
+
+struct S {}
+trait Trait {
+    fn f(_: bool, _: bool, _: bool, _: bool);
+    fn g(_: bool, _: bool, _: bool, _: Vec<u32>);
+}
+
+impl S {
+    fn f(&self, _: bool, _: bool, _: bool, _: bool) {}
+    fn g(&self, _: bool, _: bool, _: bool) {}
+}
+
+impl Trait for S {
+    // trait impls can't change their signature
+    fn f(_: bool, _: bool, _: bool, _: bool) {}
+    fn g(_: bool, _: bool, _: bool, _: Vec<u32>) {}
+}
+
+fn main() {
+    fn n(_: bool, _: u32, _: bool, _: Box<u32>, _: bool, _: bool) {}
+}
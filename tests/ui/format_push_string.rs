@@ -0,0 +1,17 @@
+#![warn(format_push_string)]
+
+fn foo(string: &mut String) {
+    string.push_str(&format!("{:?}", 1234));
+}
+
+fn main() {
+    let mut string = String::new();
+    string += &format!("{:?}", 1234);
+    string.push_str(&format!("{:?}", 5678));
+    foo(&mut string);
+
+    // fine
+    string.push_str("abc");
+    string += "def";
+    string.push_str(&1234.to_string());
+}
@@ -0,0 +1,29 @@
+#![feature(async_await, await_macro, futures_api)]
+#![warn(future_not_send)]
+#![allow(dead_code)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+async fn baz() -> u32 {
+    42
+}
+
+pub async fn captured(rc: Rc<u32>) -> u32 {
+    await!(baz()) + *rc
+}
+
+pub async fn held() -> u32 {
+    let rc = Rc::new(1);
+    await!(baz()) + *rc
+}
+
+pub async fn send(arc: Arc<u32>) -> u32 {
+    await!(baz()) + *arc
+}
+
+async fn private(rc: Rc<u32>) -> u32 {
+    await!(baz()) + *rc
+}
+
+fn main() {}
@@ -33,4 +33,21 @@ impl fmt::Debug for MyStruct {
     }
 }
 
+macro_rules! impl_from_macro {
+    ($t:ident) => {
+        impl $t {
+            fn from_macro() {}
+        }
+    };
+}
+
+struct WithMacroImpl;
+
+impl WithMacroImpl {
+    fn first() {}
+}
+
+// ok, generated by a macro
+impl_from_macro!(WithMacroImpl);
+
 fn main() {}
@@ -0,0 +1,17 @@
+#![warn(impl_trait_in_params)]
+#![allow(dead_code)]
+
+pub trait Trait {}
+
+pub fn a(_: impl Trait) {}
+pub fn c<C: Trait>(_: C, _: impl Trait) {}
+fn d(_: impl Trait) {} // ok, not public
+
+pub struct S;
+
+impl S {
+    pub fn h(_: impl Trait) {}
+    fn i(_: impl Trait) {} // ok, not public
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+#![warn(implicit_return)]
+#![allow(needless_return)]
+
+fn test_end_of_fn() -> bool {
+    if true {
+        // no error!
+        return true;
+    }
+    true
+}
+
+#[allow(needless_bool)]
+fn test_if_block() -> bool {
+    if true {
+        true
+    } else {
+        false
+    }
+}
+
+#[allow(match_bool)]
+fn test_match(x: bool) -> bool {
+    match x {
+        true => false,
+        false => {
+            true
+        },
+    }
+}
+
+#[allow(never_loop)]
+fn test_loop() -> bool {
+    loop {
+        break true;
+    }
+}
+
+fn test_closure() {
+    let _ = || {
+        true
+    };
+}
+
+fn test_return() -> bool {
+    return true;
+}
+
+fn test_unit() {
+    let _ = 1;
+}
+
+fn main() {
+    let _ = test_end_of_fn();
+    let _ = test_if_block();
+    let _ = test_match(true);
+    let _ = test_loop();
+    test_closure();
+    let _ = test_return();
+    test_unit();
+}
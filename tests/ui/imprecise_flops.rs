@@ -0,0 +1,29 @@
+#![warn(imprecise_flops)]
+
+struct Angle(f32);
+
+impl Angle {
+    fn exp(&self) -> f32 {
+        self.0.exp()
+    }
+}
+
+fn main() {
+    let x = 3f32;
+    let y = 4f64;
+
+    let _ = (1.0 + x).ln();
+    let _ = (x + 1.0).ln();
+    let _ = (x * 2.0 + 1.0).ln();
+    let _ = (x + 2.0).ln();
+
+    let _ = x.exp() - 1.0;
+    let _ = y.exp() - 1.0;
+    let _ = x.exp() - 2.0;
+
+    let _ = x.powf(0.5);
+    let _ = y.powf(1.0 / 2.0);
+    let _ = y.powf(0.25);
+
+    let _ = Angle(x).exp() - 1.0;
+}
@@ -0,0 +1,29 @@
+#![allow(unused)]
+
+const INVALID: &[u8] = b"cl\x82ippy";
+
+fn main() {
+    // valid
+    unsafe {
+        std::str::from_utf8_unchecked(&[99, 108, 105, 112, 112, 121]);
+        std::str::from_utf8_unchecked(&[b'c', b'l', b'i', b'p', b'p', b'y']);
+        std::str::from_utf8_unchecked(b"clippy");
+        String::from_utf8_unchecked(vec![b'c', b'l', b'i', b'p', b'p', b'y']);
+        String::from_utf8_unchecked(vec![b'a'; 4]);
+        String::from_utf8_unchecked(vec![0xff; 0]);
+
+        let x = 0xA0;
+        std::str::from_utf8_unchecked(&[0xC0, x]);
+    }
+
+    // invalid
+    unsafe {
+        std::str::from_utf8_unchecked(&[99, 108, 130, 105, 112, 112, 121]);
+        std::str::from_utf8_unchecked(&[b'c', b'l', b'\x82', b'i', b'p', b'p', b'y']);
+        std::str::from_utf8_unchecked(b"cl\x82ippy");
+        std::str::from_utf8_unchecked(INVALID);
+        String::from_utf8_unchecked(vec![0xff; 4]);
+        String::from_utf8_unchecked(b"cl\x82ippy".to_vec());
+        String::from_utf8_unchecked(vec![0xff; 1 << 40]);
+    }
+}
@@ -0,0 +1,19 @@
+#![feature(async_await, await_macro, futures_api, pin)]
+#![warn(large_futures)]
+#![allow(dead_code)]
+
+async fn big_fn(_x: [u8; 16 * 1024]) {
+    println!("{}", _x.len());
+}
+
+async fn small_fn(_x: [u8; 16]) {
+    println!("{}", _x.len());
+}
+
+pub async fn test() {
+    await!(big_fn([0u8; 16 * 1024]));
+    await!(small_fn([0u8; 16]));
+    await!(std::boxed::PinBox::new(big_fn([0u8; 16 * 1024])));
+}
+
+fn main() {}
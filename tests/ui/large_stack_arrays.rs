@@ -0,0 +1,27 @@
+#![warn(large_stack_arrays)]
+
+static STATIC_ARR: [u8; 1_000_000] = [0; 1_000_000];
+const CONST_ARR: [u8; 1_000_000] = [0; 1_000_000];
+
+#[derive(Clone, Copy)]
+struct S {
+    pub data: [u64; 32],
+}
+
+fn main() {
+    let bad = (
+        [0u32; 20_000_000],
+        [S { data: [0; 32] }; 5000],
+        [[0u8; 300_000], [0u8; 300_000]],
+        [0u8; 512_001],
+    );
+
+    let good = (
+        [0u32; 1000],
+        [S { data: [0; 32] }; 1000],
+        [[0u8; 100_000], [0u8; 100_000]],
+        [0u8; 512_000],
+        vec![0u8; 1_000_000],
+    );
+    let _ = (bad, good, STATIC_ARR, CONST_ARR);
+}
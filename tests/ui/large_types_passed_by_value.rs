@@ -0,0 +1,33 @@
+#![warn(large_types_passed_by_value)]
+#![allow(dead_code, unused_variables)]
+
+#[derive(Clone, Copy)]
+pub struct Large([u8; 2048]);
+
+#[derive(Clone, Copy)]
+pub struct Small([u8; 4]);
+
+#[derive(Clone)]
+pub struct NotCopy([u8; 2048]);
+
+fn large(a: Large, b: [u8; 1024], c: (Large, u8)) {}
+fn small(a: Small, b: [u8; 4], c: &Large) {}
+fn not_copy(a: NotCopy) {}
+fn mutable(mut a: Large) {
+    a.0[0] = 1;
+}
+
+struct S;
+impl S {
+    fn method(&self, a: Large) {}
+}
+
+trait LargeTypeParam {
+    fn other(a: Large) {}
+}
+
+impl LargeTypeParam for S {
+    fn other(a: Large) {}
+}
+
+fn main() {}
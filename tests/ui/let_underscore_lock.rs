@@ -0,0 +1,18 @@
+#![warn(let_underscore_lock)]
+
+use std::sync::{Mutex, RwLock};
+
+fn main() {
+    let m = Mutex::new(1);
+    let rw = RwLock::new(1);
+
+    let _ = m.lock();
+    let _ = rw.read();
+    let _ = rw.write().unwrap();
+
+    let guard = m.lock().unwrap();
+    let _ = guard;
+
+    let _lock = rw.read();
+    let _ = 1;
+}
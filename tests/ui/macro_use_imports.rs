@@ -0,0 +1,12 @@
+// aux-build:macro_use_helper.rs
+// compile-flags: --edition 2018
+#![feature(rust_2018_preview)]
+#![warn(macro_use_imports)]
+
+#[macro_use]
+extern crate macro_use_helper as mac;
+
+fn main() {
+    pub_macro!();
+    function_macro!();
+}
@@ -0,0 +1,19 @@
+#![warn(manual_bits)]
+
+use std::mem::size_of;
+
+fn main() {
+    let _ = size_of::<i8>() * 8;
+    let _ = size_of::<u32>() * 8;
+    let _ = 8 * size_of::<usize>();
+    let _ = std::mem::size_of::<i64>() * 8;
+    let _ = (size_of::<u32>() * 8) as u32;
+    let _ = (size_of::<u16>() * 8) as u64;
+    let n = 4;
+    let _ = size_of::<u32>() * 8 < n;
+
+    // fine
+    let _ = size_of::<[u8; 4]>() * 8;
+    let _ = size_of::<bool>() * 8;
+    let _ = size_of::<u32>() * 4;
+}
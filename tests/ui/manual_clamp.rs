@@ -0,0 +1,55 @@
+#![warn(manual_clamp)]
+
+const LO: i32 = 2;
+const HI: i32 = 8;
+
+fn main() {
+    let input: i32 = 5;
+    let fl = 0.5_f64;
+
+    let _ = input.max(2).min(8);
+    let _ = input.min(HI).max(LO);
+    let _ = fl.max(0.0).min(1.0);
+    let _ = if input > 8 {
+        8
+    } else if input < 2 {
+        2
+    } else {
+        input
+    };
+    let _ = if 2 >= input {
+        2
+    } else if 8 <= input {
+        8
+    } else {
+        input
+    };
+
+    // reversed bounds
+    let _ = input.max(8).min(2);
+    let _ = if input < 8 {
+        8
+    } else if input > 2 {
+        2
+    } else {
+        input
+    };
+
+    // fine
+    let _ = input.max(input / 2).min(8);
+    let _ = input.max(2).max(8);
+    let _ = if input > 8 {
+        8
+    } else if input < 2 {
+        3
+    } else {
+        input
+    };
+    let _ = if input > 8 {
+        8
+    } else if input > 2 {
+        2
+    } else {
+        input
+    };
+}
@@ -0,0 +1,36 @@
+#![warn(manual_recv_loop)]
+#![allow(dead_code)]
+
+use std::sync::mpsc::Receiver;
+
+fn by_ref(rx: &Receiver<u32>) {
+    while let Ok(msg) = rx.recv() {
+        println!("{}", msg);
+    }
+
+    loop {
+        let msg = rx.recv().unwrap();
+        println!("{}", msg);
+    }
+}
+
+fn owned(rx: Receiver<(u32, u32)>) {
+    loop {
+        let (a, b) = rx.recv().expect("sender disconnected");
+        println!("{} {}", a, b);
+    }
+}
+
+fn not_linted(rx: &Receiver<Option<u32>>) {
+    while let Ok(Some(msg)) = rx.recv() {
+        println!("{}", msg);
+    }
+
+    loop {
+        println!("waiting");
+        let msg = rx.recv().unwrap();
+        println!("{:?}", msg);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(manual_rem_euclid)]
+
+fn main() {
+    let value: i32 = 5;
+    let m: i32 = 4;
+    let fl: f64 = -2.5;
+
+    let _ = ((value % 4) + 4) % 4;
+    let _ = (4 + (value % 4)) % 4;
+    let _ = (value % 4 + 4) % 4;
+    let _ = ((value % m) + m) % m;
+    let _ = ((fl % 3.0) + 3.0) % 3.0;
+    let _ = (((value - 1) % 4) + 4) % 4;
+    let _ = ((value) * (m) % 4 + 4) % 4;
+
+    // fine
+    let _ = ((value % 4) + 5) % 4;
+    let _ = ((value % 4) + 4) % 5;
+    let _ = ((value % -4) + -4) % -4;
+    let _ = ((value % 4) - 4) % 4;
+}
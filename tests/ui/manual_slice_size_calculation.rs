@@ -0,0 +1,22 @@
+#![warn(manual_slice_size_calculation)]
+
+use std::mem::size_of;
+
+fn main() {
+    let v_i32 = Vec::<i32>::new();
+    let s_i32 = v_i32.as_slice();
+
+    // True positives:
+    let _ = s_i32.len() * size_of::<i32>();
+    let _ = size_of::<i32>() * s_i32.len();
+    let _ = s_i32.len() * std::mem::size_of::<i32>();
+    let _ = v_i32[..].len() * 4 * size_of::<i32>() + s_i32.len() * size_of::<i32>();
+    let _ = v_i32[..].len() * size_of::<i32>();
+
+    // True negatives:
+    let _ = v_i32.len() * size_of::<i32>();
+    let _ = s_i32.len() * size_of::<u32>();
+    let _ = s_i32.len() * 4;
+    let _ = size_of::<i32>() * 4;
+    let _ = std::mem::size_of_val(s_i32);
+}
@@ -0,0 +1,29 @@
+#![warn(min_ident_chars)]
+#![allow(dead_code, unused_variables)]
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+struct A<'a> {
+    slice: &'a [u32],
+}
+
+fn f(a: u32, value: u32) -> u32 {
+    let b = a + value;
+    let i = b;
+    let x = i;
+    b
+}
+
+fn lifetimes<'b, 'long>(slice: &'b [u32], other: &'long [u32]) {}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    let Point { x, y } = p;
+    let Point { x: c, y: width } = Point { x: 1, y: 2 };
+    let closure = |q: u32| q + 1;
+    for (k, v) in vec![(1, 2)] {}
+    let ok = closure(x + y + c + width);
+}
@@ -0,0 +1,108 @@
+#![feature(const_fn)]
+#![warn(missing_const_for_fn)]
+#![allow(dead_code)]
+
+struct Game {
+    guess: i32,
+}
+
+impl Game {
+    // Could be const
+    pub fn new() -> Self {
+        Self { guess: 42 }
+    }
+
+    // Could be const
+    fn guess(&self) -> i32 {
+        self.guess
+    }
+}
+
+// Could be const
+fn one() -> i32 {
+    1
+}
+
+// Could be const
+fn add(a: u32, b: u32) -> u32 {
+    a * 2 + b
+}
+
+// Could be const
+fn get_tuple(pair: &(u8, u8)) -> u8 {
+    pair.0
+}
+
+// Already const
+const fn already_const() -> u8 {
+    0
+}
+
+// Could be const, calls another `const fn`
+fn call_const() -> u8 {
+    already_const()
+}
+
+// `let` bindings are not allowed in const fns
+fn two() -> i32 {
+    let abc = 2;
+    abc
+}
+
+// Returns nothing, so there is no point in making it const
+fn unit() {}
+
+// Generic functions are not linted
+fn generic<T>(t: T) -> T {
+    t
+}
+
+// Mutable references are not allowed in const fns
+fn mut_ref(x: &mut u8) -> u8 {
+    *x
+}
+
+// Calls a function that is not const
+fn not_const() -> Vec<u8> {
+    vec![1]
+}
+
+// Control flow is not allowed in const fns
+fn control_flow(x: bool) -> u8 {
+    if x {
+        1
+    } else {
+        0
+    }
+}
+
+// `&&` is control flow, too
+fn and(a: bool, b: bool) -> bool {
+    a && b
+}
+
+// Unsafe const fns aren't stable
+unsafe fn unsafe_fn() -> u8 {
+    0
+}
+
+trait Foo {
+    fn foo(&self) -> u8 {
+        1
+    }
+}
+
+// Trait methods can't be const
+impl Foo for Game {
+    fn foo(&self) -> u8 {
+        2
+    }
+}
+
+// Arguments that need to be dropped are not allowed in const fns
+fn drop_arg(pair: (String, u8)) -> u8 {
+    pair.1
+}
+
+// Entry points can't be const
+fn main() {}
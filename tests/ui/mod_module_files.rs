@@ -0,0 +1,7 @@
+#![feature(non_modrs_mods)]
+#![warn(mod_module_files)]
+#![allow(dead_code)]
+
+mod module_style;
+
+fn main() {}
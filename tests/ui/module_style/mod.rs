@@ -0,0 +1,2 @@
+pub mod mod_style;
+pub mod self_named;
@@ -0,0 +1,41 @@
+#![warn(multiple_unsafe_ops_per_block)]
+#![allow(dead_code)]
+
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+static mut COUNTER: u32 = 0;
+
+unsafe fn read(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+fn main() {
+    let x = 5u32;
+    let ptr = &x as *const u32;
+
+    unsafe {
+        let y = *ptr;
+        read(ptr.offset(y as isize));
+    }
+
+    let mut bits = Bits { int: 1 };
+    unsafe {
+        COUNTER += bits.int;
+        bits.float = 1.0;
+        let _ = ptr.read();
+    }
+
+    // one operation each
+    let _ = unsafe { *ptr };
+    unsafe {
+        bits.int = 2;
+        let _ = bits.float;
+    }
+    unsafe {
+        let f = || read(ptr);
+        f();
+    }
+}
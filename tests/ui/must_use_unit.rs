@@ -0,0 +1,29 @@
+#![warn(must_use_unit)]
+#![allow(unreachable_code)]
+
+#[must_use]
+pub fn must_use_default() {}
+
+#[must_use]
+pub fn must_use_unit() -> () {}
+
+#[must_use = "With note"]
+pub fn must_use_with_note() {}
+
+pub struct S;
+
+impl S {
+    #[must_use]
+    pub fn must_use_method(&self) {}
+}
+
+#[must_use]
+pub fn must_use_value() -> u8 {
+    0
+}
+
+fn main() {
+    must_use_default();
+    must_use_unit();
+    must_use_with_note();
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::atomic::AtomicUsize;
+
+struct Key(AtomicUsize);
+
+impl PartialEq for Key {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Key {}
+
+impl std::hash::Hash for Key {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+
+fn should_not_take_this_arg(m: &mut HashMap<Key, usize>, _n: usize) -> HashSet<Key> {
+    let _other: HashMap<Key, bool> = HashMap::new();
+    m.keys().len();
+    HashSet::new()
+}
+
+fn rc_refcell(_: &BTreeSet<Rc<RefCell<String>>>) {}
+
+fn tuple(_: &HashSet<(u32, Cell<u32>)>) {}
+
+fn this_is_ok(_m: &mut HashMap<usize, Key>) {}
+
+fn plain_rc(_: &HashSet<Rc<String>>) {}
+
+fn main() {
+    should_not_take_this_arg(&mut HashMap::new(), 1);
+    this_is_ok(&mut HashMap::new());
+}
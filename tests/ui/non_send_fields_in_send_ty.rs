@@ -0,0 +1,28 @@
+#![warn(non_send_fields_in_send_ty)]
+#![allow(dead_code)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct RawPtr {
+    ptr: *mut u8,
+    len: usize,
+}
+
+unsafe impl Send for RawPtr {}
+
+struct Generic<T> {
+    rc: Rc<String>,
+    value: T,
+}
+
+unsafe impl<T> Send for Generic<T> {}
+
+struct Bounded<T> {
+    arc: Arc<String>,
+    value: T,
+}
+
+unsafe impl<T: Send> Send for Bounded<T> {}
+
+fn main() {}
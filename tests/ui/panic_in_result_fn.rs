@@ -0,0 +1,61 @@
+#![warn(panic_in_result_fn)]
+#![allow(dead_code)]
+
+// `std::todo!` is newer than the toolchain, this mirrors its expansion
+macro_rules! todo {
+    ($msg:expr) => {
+        panic!(concat!("not yet implemented: ", $msg))
+    };
+}
+
+struct A;
+
+impl A {
+    fn result_with_panic() -> Result<bool, String> {
+        panic!("error");
+    }
+
+    fn result_with_unimplemented() -> Result<bool, String> {
+        unimplemented!();
+    }
+
+    fn result_with_unreachable() -> Result<bool, String> {
+        unreachable!();
+    }
+
+    fn result_with_todo() -> Result<bool, String> {
+        todo!("Finish this");
+    }
+
+    fn result_with_assert(x: i32) -> Result<bool, String> {
+        assert_eq!(x, 5);
+        Ok(true)
+    }
+
+    // Don't lint these
+    fn other_with_panic() {
+        panic!("");
+    }
+
+    fn result_without_banned_functions() -> Result<bool, String> {
+        Ok(true)
+    }
+}
+
+fn function_result_with_panic() -> Result<bool, String> {
+    if true {
+        panic!("error");
+    }
+    unreachable!()
+}
+
+fn immediately_called_closure() -> Result<bool, String> {
+    (|| panic!("error"))()
+}
+
+// Don't lint: the closure may never be called
+fn lazy_closure(x: Option<bool>) -> Result<bool, String> {
+    Ok(x.unwrap_or_else(|| panic!("error")))
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 
 
 
-#![warn(panic_params, unimplemented)]
+#![warn(panic_params, unimplemented, todo)]
 
 fn missing() {
     if true {
@@ -59,6 +59,24 @@ fn unimplemented() {
     let b = a + 2;
 }
 
+fn unimplemented_with_message() {
+    let a = 2;
+    unimplemented!("not yet: {}", a);
+}
+
+// `std::todo!` is newer than the toolchain, this mirrors its expansion
+macro_rules! todo {
+    () => {
+        panic!("not yet implemented")
+    };
+}
+
+fn todo() {
+    let a = 2;
+    todo!();
+    let b = a + 2;
+}
+
 fn main() {
     missing();
     ok_single();
@@ -68,4 +86,6 @@ fn main() {
     ok_nomsg();
     ok_escaped();
     unimplemented();
+    unimplemented_with_message();
+    todo();
 }
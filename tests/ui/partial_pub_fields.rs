@@ -0,0 +1,27 @@
+#![warn(partial_pub_fields)]
+#![allow(dead_code)]
+
+fn main() {
+    pub struct FileSet {
+        files: Vec<String>,
+        pub paths: Vec<String>,
+    }
+
+    pub struct Color {
+        pub r: u8,
+        pub g: u8,
+        b: u8,
+    }
+
+    pub struct Point(i32, pub i32);
+
+    pub struct AllPublic {
+        pub foo: u8,
+        pub bar: u8,
+    }
+
+    pub struct AllPrivate {
+        foo: u8,
+        bar: u8,
+    }
+}
@@ -0,0 +1,30 @@
+#![warn(ptr_eq)]
+
+macro_rules! mac {
+    ($a:expr, $b:expr) => {
+        $a as *const _ as usize == $b as *const _ as usize
+    };
+}
+
+static FOO: u32 = 1;
+static BAR: u32 = 1;
+
+fn main() {
+    let a = &[1, 2, 3];
+    let b = &[1, 2, 3];
+
+    let _ = a as *const _ as usize == b as *const _ as usize;
+    let _ = a as *const _ == b as *const _;
+    let _ = a as *const [i32; 3] != b as *const [i32; 3];
+    let _ = &FOO as *const u32 == &BAR as *const u32;
+
+    // not linted
+    let _ = mac!(a, b);
+    let _ = a.as_ptr() == b as *const _ as *const i32;
+    let _ = a == b;
+    let c = &1u8;
+    let _ = a as *const _ as usize == c as *const _ as usize;
+    let s: &[i32] = &[1, 2, 3];
+    let t: &[i32] = &[1, 2, 3];
+    let _ = s as *const _ == t as *const _;
+}
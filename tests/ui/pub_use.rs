@@ -0,0 +1,22 @@
+#![warn(pub_use)]
+#![allow(dead_code, unused_imports)]
+
+pub mod outer {
+    mod inner {
+        pub struct Test {}
+    }
+    // should be linted
+    pub use self::inner::Test;
+}
+
+pub mod prelude {
+    // ok, prelude module
+    pub use super::outer::Test;
+}
+
+// should be linted
+pub use std::mem::swap;
+
+use outer::Test as _Test; // ok, not `pub`
+
+fn main() {}
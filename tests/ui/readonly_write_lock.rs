@@ -0,0 +1,37 @@
+#![warn(readonly_write_lock)]
+#![allow(unused_variables)]
+
+use std::sync::RwLock;
+
+fn accept_i32(_: i32) {}
+
+fn main() {
+    let lock = RwLock::new(42);
+    let lock2 = RwLock::new(vec![1]);
+
+    {
+        let writer = lock.write().unwrap();
+        println!("{}", *writer);
+    }
+
+    {
+        let writer = lock2.write().expect("poisoned");
+        println!("{}", writer.len());
+    }
+
+    {
+        let mut writer = lock.write().unwrap();
+        *writer += 1;
+    }
+
+    {
+        let mut writer = lock2.write().unwrap();
+        writer.push(2);
+    }
+
+    {
+        let writer = lock.write().unwrap();
+        accept_i32(*writer);
+        drop(writer);
+    }
+}
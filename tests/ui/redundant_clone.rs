@@ -0,0 +1,47 @@
+#![warn(redundant_clone)]
+#![allow(dead_code)]
+
+use std::ffi::OsString;
+use std::path::Path;
+
+fn main() {
+    let _ = ["lorem", "ipsum"].join(" ").to_string();
+
+    let s = String::from("foo");
+    let _ = s.clone();
+
+    let s = String::from("foo");
+    let _ = s.to_string();
+
+    let s = String::from("foo");
+    let _ = s.to_owned();
+
+    let _ = Path::new("/a/b/").join("c").to_owned();
+
+    let _ = Path::new("/a/b/").join("c").to_path_buf();
+
+    let _ = OsString::new().to_owned();
+
+    let _ = OsString::new().to_os_string();
+
+    // Check that lint level works
+    #[allow(redundant_clone)]
+    let _ = String::new().to_string();
+
+    let tup_ref = &(String::from("foo"),);
+    let _s = tup_ref.0.clone(); // this `.clone()` cannot be removed
+
+    let s = String::from("foo");
+    let _ = s.clone();
+    let _ = s.len(); // `s` is used later
+}
+
+#[derive(Clone)]
+struct Alpha;
+fn double(a: Alpha) -> (Alpha, Alpha) {
+    if true {
+        (a.clone(), a.clone())
+    } else {
+        (Alpha, a)
+    }
+}
@@ -0,0 +1,27 @@
+#![warn(redundant_pub_crate)]
+#![allow(dead_code)]
+
+mod m1 {
+    fn f() {}
+    pub(crate) fn g() {} // private due to m1
+    pub fn h() {}
+
+    mod m1_1 {
+        fn f() {}
+        pub(crate) fn g() {} // private due to m1_1 and m1
+        pub fn h() {}
+    }
+
+    pub mod m1_2 {
+        pub(crate) fn g() {} // private due to m1
+    }
+}
+
+pub mod m2 {
+    pub(crate) fn g() {} // ok, crate visible
+    pub fn h() {}
+}
+
+pub(crate) fn top_level() {} // ok
+
+fn main() {}
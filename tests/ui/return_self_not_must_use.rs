@@ -0,0 +1,69 @@
+#![warn(return_self_not_must_use)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+pub struct Bar {
+    x: u8,
+}
+
+impl Bar {
+    pub fn new() -> Self {
+        Self { x: 0 }
+    }
+
+    pub fn with_x(mut self, x: u8) -> Self {
+        self.x = x;
+        self
+    }
+
+    pub fn copied(&self) -> Self {
+        Self { x: self.x }
+    }
+
+    #[must_use]
+    pub fn good(self) -> Self {
+        self
+    }
+
+    fn private(self) -> Self {
+        self
+    }
+
+    pub fn other(self) -> u8 {
+        self.x
+    }
+}
+
+pub trait Whatever {
+    fn what(self) -> Self;
+}
+
+impl Whatever for Bar {
+    // trait impls are not linted
+    fn what(self) -> Self {
+        self
+    }
+}
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    pub fn map_nothing(self) -> Self {
+        self
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[must_use]
+pub struct Baz;
+
+impl Baz {
+    pub fn baz(self) -> Self {
+        self
+    }
+}
+
+fn main() {}
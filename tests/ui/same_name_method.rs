@@ -0,0 +1,44 @@
+#![warn(same_name_method)]
+#![allow(dead_code)]
+
+trait T1 {
+    fn foo() {}
+}
+
+trait T2 {
+    fn bar(&self);
+}
+
+mod should_lint {
+    use super::{T1, T2};
+
+    struct S;
+
+    impl S {
+        fn foo() {}
+    }
+
+    impl T1 for S {}
+
+    struct S2;
+
+    impl S2 {
+        fn bar(&self) {}
+    }
+
+    impl T2 for S2 { fn bar(&self) {} }
+}
+
+mod should_not_lint {
+    use super::T1;
+
+    struct S;
+
+    impl S {
+        fn baz() {}
+    }
+
+    impl T1 for S {}
+}
+
+fn main() {}
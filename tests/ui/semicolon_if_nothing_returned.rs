@@ -0,0 +1,42 @@
+#![warn(semicolon_if_nothing_returned)]
+
+fn get_unit() {}
+
+fn basic101(x: i32) {
+    let y = x + 1;
+    let _ = y;
+    get_unit()
+}
+
+fn with_block() {
+    {
+        get_unit()
+    };
+}
+
+fn ok_with_semicolon() {
+    get_unit();
+}
+
+fn ok_block_like(x: bool) {
+    if x {
+        get_unit();
+    }
+}
+
+fn ok_macro() {
+    println!("Hello")
+}
+
+fn ok_value() -> i32 {
+    42
+}
+
+fn main() {
+    basic101(1);
+    with_block();
+    ok_with_semicolon();
+    ok_block_like(true);
+    ok_macro();
+    let _ = ok_value();
+}
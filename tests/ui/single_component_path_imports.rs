@@ -0,0 +1,19 @@
+// compile-flags: --edition 2018
+#![feature(rust_2018_preview)]
+#![warn(single_component_path_imports)]
+#![allow(unused_imports, dead_code)]
+
+mod inner {
+    use std;
+    use std as renamed; // ok, renamed
+
+    fn foo() {
+        let _ = std::mem::size_of::<u8>();
+    }
+}
+
+mod reexport {
+    pub use std; // ok, re-export
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(stable_sort_primitive)]
+
+fn main() {
+    // positive examples
+    let mut vec = vec![1, 3, 2];
+    vec.sort();
+    let mut vec = vec![false, false, true];
+    vec.sort();
+    let mut vec = vec!['a', 'A', 'c'];
+    vec.sort();
+    let mut arr = [1u64, 3, 2];
+    arr.sort();
+    let slice = &mut [1i8, 3, 2][..];
+    slice.sort();
+    // negative examples
+    let mut vec = vec!["a", "A", "c"];
+    vec.sort();
+    let mut vec = vec![(2, 1), (1, 2), (2, 5)];
+    vec.sort();
+    let mut vec = vec![1, 3, 2];
+    vec.sort_unstable();
+    vec.sort_by_key(|x| *x);
+}
@@ -0,0 +1,7 @@
+#![warn(str_to_string)]
+
+fn main() {
+    let hello = "hello world".to_string();
+    let msg = &hello[..];
+    let _ = msg.to_string();
+}
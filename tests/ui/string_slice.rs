@@ -0,0 +1,15 @@
+#![warn(string_slice)]
+#![allow(no_effect, unnecessary_operation)]
+
+fn main() {
+    &"Ölkanne"[1..];
+    let m = "Mötörhead";
+    &m[2..5];
+    let s = String::from(m);
+    &s[0..2];
+    // slicing the full string can't panic
+    &s[..];
+    // not a string
+    let v = vec![1, 2, 3];
+    &v[1..];
+}
@@ -0,0 +1,6 @@
+#![warn(string_to_string)]
+
+fn main() {
+    let message = String::from("Hello");
+    let _ = message.to_string();
+}
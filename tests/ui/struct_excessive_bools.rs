@@ -0,0 +1,54 @@
+#![warn(struct_excessive_bools)]
+#![allow(dead_code)]
+
+macro_rules! foo {
+    () => {
+        struct MacroFoo {
+            a: bool,
+            b: bool,
+            c: bool,
+            d: bool,
+        }
+    };
+}
+
+foo!();
+
+struct Foo {
+    a: bool,
+    b: bool,
+    c: bool,
+}
+
+struct BadFoo {
+    a: bool,
+    b: bool,
+    c: bool,
+    d: bool,
+}
+
+struct TupleFoo(bool, bool, bool, bool, u8);
+
+struct OptionFoo {
+    a: Option<bool>,
+    b: Option<bool>,
+    c: Option<bool>,
+    d: Option<bool>,
+}
+
+#[repr(C)]
+struct Bar {
+    a: bool,
+    b: bool,
+    c: bool,
+    d: bool,
+}
+
+fn main() {
+    struct FooFoo {
+        a: bool,
+        b: bool,
+        c: bool,
+        d: bool,
+    }
+}
@@ -0,0 +1,37 @@
+#![warn(suboptimal_flops)]
+
+use std::f32::consts::E;
+
+const C: f64 = 2.0 * 3.0 + 1.0;
+
+fn main() {
+    let x = 3f32;
+    let y = 4f64;
+    let z = 5f32;
+
+    let _ = x.log(2.0);
+    let _ = x.log(10.0);
+    let _ = x.log(E);
+    let _ = x.log(3.0);
+
+    let _ = 2f32.powf(x);
+    let _ = E.powf(x);
+    let _ = x.powf(3.0);
+    let _ = x.powf(1.5);
+
+    let _ = x.powi(2);
+    let _ = z / x.powi(2);
+    let _ = -x.powi(2);
+    let _ = (x + 1.0).powi(2);
+    let _ = x.powi(3);
+    let _ = 1.0 / x.sqrt();
+
+    let _ = x * z + 1.0;
+    let _ = 1.0 + x * z;
+    let _ = y * 2.0 + C;
+    let _ = 2 * 3 + 1;
+
+    let _ = 2f32.powf(3.0);
+    let _ = 2.0 * x + 1.0;
+    let _ = 2.0 * 3.0 + x;
+}
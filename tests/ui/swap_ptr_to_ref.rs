@@ -0,0 +1,26 @@
+#![warn(swap_ptr_to_ref)]
+
+use std::mem::swap;
+
+macro_rules! addr_of_mut_deref {
+    ($e:expr) => {
+        &mut *$e
+    };
+}
+
+fn main() {
+    let mut x = 0;
+    let mut y = 0;
+    let z = &mut x as *mut i32;
+    let w = &mut y as *mut i32;
+
+    unsafe {
+        swap(&mut *z, &mut *w);
+        swap(&mut *z, &mut y);
+        swap(&mut x, &mut *w);
+        std::mem::swap(&mut *z, &mut *z);
+
+        swap(&mut x, &mut y);
+        swap(addr_of_mut_deref!(z), addr_of_mut_deref!(w));
+    }
+}
@@ -0,0 +1,54 @@
+#![warn(transmute_undefined_repr)]
+#![allow(dead_code)]
+
+use std::mem::transmute;
+
+struct Rust1 {
+    a: u32,
+    b: u16,
+}
+
+struct Rust2 {
+    a: u32,
+    b: u16,
+}
+
+#[repr(C)]
+struct C1 {
+    a: u32,
+    b: u16,
+}
+
+#[repr(C)]
+struct C2 {
+    a: u32,
+    b: u16,
+}
+
+struct Single(u64);
+
+#[repr(transparent)]
+struct Transparent(u64);
+
+struct Borrowed<'a> {
+    a: &'a u32,
+    b: u16,
+}
+
+// the same type with another lifetime
+fn shorten<'a>(x: Borrowed<'static>) -> Borrowed<'a> {
+    unsafe { transmute(x) }
+}
+
+fn main() {
+    unsafe {
+        let _: Rust2 = transmute(Rust1 { a: 0, b: 0 });
+        let _: C1 = transmute(Rust1 { a: 0, b: 0 });
+        let _: Rust1 = transmute(C1 { a: 0, b: 0 });
+
+        // both layouts are defined
+        let _: C2 = transmute(C1 { a: 0, b: 0 });
+        let _: Transparent = transmute(Single(0));
+        let _: Single = transmute(0u64);
+    }
+}
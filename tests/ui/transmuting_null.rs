@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+#![allow(transmute_ptr_to_ref)]
+
+use std::mem;
+use std::ptr;
+
+const ZERO: usize = 0;
+
+fn one_liners() {
+    unsafe {
+        let _: &u64 = mem::transmute(ptr::null::<u64>());
+        let _: &u64 = mem::transmute(0 as *const u64);
+        let _: &mut u64 = mem::transmute(ptr::null_mut::<u64>());
+        let _: &u64 = mem::transmute(ZERO);
+    }
+}
+
+fn not_null(x: *const u64, y: usize) {
+    unsafe {
+        let _: &u64 = mem::transmute(x);
+        let _: &u64 = mem::transmute(1usize);
+        let _: &u64 = mem::transmute(y);
+    }
+}
+
+fn main() {
+    one_liners();
+}
@@ -0,0 +1,31 @@
+#![warn(undocumented_unsafe_blocks)]
+
+fn undocumented(a: &[u32]) -> u32 {
+    let first = unsafe { *a.as_ptr() };
+
+    // the pointer is valid
+    let second = unsafe { *a.as_ptr().offset(1) };
+
+    unsafe {
+        first + second
+    }
+}
+
+fn documented(a: &[u32]) -> u32 {
+    // SAFETY: `a` is not empty
+    let first = unsafe { *a.as_ptr() };
+
+    // Safety: `a` has at least two elements,
+    // so the offset stays in bounds
+    let second = unsafe { *a.as_ptr().offset(1) };
+
+    // the sum can't overflow for the values we use
+    // SAFETY: `a` has at least three elements
+    unsafe { first + second + *a.as_ptr().offset(2) }
+}
+
+fn main() {
+    let a = [1, 2, 3];
+    undocumented(&a);
+    documented(&a);
+}
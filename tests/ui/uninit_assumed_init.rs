@@ -0,0 +1,34 @@
+#![allow(invalid_ref, dead_code)]
+
+use std::mem;
+
+struct Wrapper {
+    len: usize,
+    flag: bool,
+}
+
+union Bits {
+    flag: bool,
+    bits: u8,
+}
+
+fn generic<T>() -> T {
+    unsafe { mem::uninitialized() }
+}
+
+fn main() {
+    unsafe {
+        let _: bool = mem::uninitialized();
+        let _: [char; 4] = mem::uninitialized();
+        let _: Option<u32> = mem::uninitialized();
+        let _: Wrapper = mem::uninitialized();
+        let _: (u8, Box<u8>) = mem::uninitialized();
+
+        // not linted
+        let _: [u8; 32] = mem::uninitialized();
+        let _: (u32, *const u8) = mem::uninitialized();
+        let _: Bits = mem::uninitialized();
+        let _: &u8 = mem::uninitialized();
+    }
+    let _: u32 = generic();
+}
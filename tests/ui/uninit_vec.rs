@@ -0,0 +1,29 @@
+#![warn(uninit_vec)]
+
+fn main() {
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    unsafe {
+        vec.set_len(200);
+    }
+
+    vec.reserve(1000);
+    unsafe {
+        vec.set_len(1000);
+    }
+
+    let mut vec2: Vec<String> = Vec::with_capacity(10);
+    unsafe { vec2.set_len(10) };
+
+    // not linted: the buffer is initialized first
+    let mut vec3: Vec<u8> = Vec::with_capacity(1000);
+    vec3.resize(1000, 0);
+    unsafe {
+        vec3.set_len(500);
+    }
+
+    // not linted: no value is made uninitialized
+    let mut vec4: Vec<u8> = Vec::with_capacity(1000);
+    unsafe {
+        vec4.set_len(0);
+    }
+}
@@ -0,0 +1,49 @@
+#![warn(unnecessary_box_returns)]
+#![allow(dead_code)]
+
+struct Foo;
+
+impl Foo {
+    // lint
+    fn boxed_usize(&self) -> Box<usize> {
+        Box::new(5)
+    }
+
+    // don't lint: the box is used
+    fn boxed_string(&self) -> Box<String> {
+        Box::new(String::new())
+    }
+}
+
+// lint
+fn boxed_foo() -> Box<Foo> {
+    Box::new(Foo)
+}
+
+// lint, no callers
+fn unused() -> Box<u32> {
+    Box::new(1)
+}
+
+// don't lint: unsized
+fn boxed_str() -> Box<str> {
+    "Hello".into()
+}
+
+// don't lint: used as a value
+fn as_value() -> Box<u32> {
+    Box::new(2)
+}
+
+// don't lint: public
+pub fn public() -> Box<u8> {
+    Box::new(3)
+}
+
+fn main() {
+    let foo = *boxed_foo();
+    let _ = *foo.boxed_usize();
+    let _ = foo.boxed_string();
+    let _ = boxed_str();
+    let _: fn() -> Box<u32> = as_value;
+}
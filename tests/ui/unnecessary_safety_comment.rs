@@ -0,0 +1,37 @@
+#![warn(unnecessary_safety_comment)]
+#![allow(dead_code)]
+
+// SAFETY: there is nothing unsafe here
+struct Foo;
+
+// SAFETY: `Foo` holds no data
+unsafe impl Send for Foo {}
+
+fn unnecessary(a: &[u32]) -> u32 {
+    // SAFETY: `a` is not empty
+    let first = a[0];
+
+    // SAFETY: the slice isn't too long
+    let second = a.len() as u32;
+
+    // SAFETY: can't overflow
+    first + second
+}
+
+fn necessary(a: &[u32]) -> u32 {
+    // SAFETY: `a` is not empty
+    let first = unsafe { *a.as_ptr() };
+
+    // SAFETY: `a` has at least two elements
+    let get = || unsafe { *a.as_ptr().offset(1) };
+
+    // SAFETY: `a` has at least three elements
+    first + get() + unsafe { *a.as_ptr().offset(2) }
+}
+
+unsafe fn in_unsafe_fn(a: *const u32) -> u32 {
+    // SAFETY: the caller guarantees that `a` is valid
+    *a
+}
+
+fn main() {}
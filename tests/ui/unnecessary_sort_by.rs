@@ -0,0 +1,37 @@
+#![warn(unnecessary_sort_by)]
+
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn id(x: isize) -> isize {
+    x
+}
+
+fn main() {
+    let mut vec: Vec<isize> = vec![3, 6, 1, 2, 5];
+    // Forward examples
+    vec.sort_by(|a, b| a.cmp(b));
+    vec.sort_unstable_by(|a, b| a.cmp(b));
+    vec.sort_by(|a, b| (a + 5).abs().cmp(&(b + 5).abs()));
+    vec.sort_unstable_by(|a, b| id(-a).cmp(&id(-b)));
+    // Reverse examples
+    vec.sort_by(|a, b| b.cmp(a));
+    vec.sort_by(|a, b| (b + 5).abs().cmp(&(a + 5).abs()));
+    vec.sort_unstable_by(|a, b| id(-b).cmp(&id(-a)));
+    // Negative examples (shouldn't be changed)
+    let c = &7;
+    vec.sort_by(|a, b| (b - a).cmp(&(a - b)));
+    vec.sort_by(|_, b| b.cmp(&5));
+    vec.sort_by(|_, b| b.cmp(c));
+    vec.sort_unstable_by(|a, _| a.cmp(c));
+    vec.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut people = vec![Person { name: String::from("a"), age: 2 }];
+    people.sort_by(|a, b| a.age.cmp(&b.age));
+    // keys borrowing from or moving out of the element
+    people.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+    people.sort_by(|a, b| a.name.cmp(&b.name));
+    people.sort_by(|a, b| a.name.len().cmp(&b.name.len()));
+}
@@ -0,0 +1,80 @@
+#![warn(unnecessary_wraps)]
+#![allow(dead_code)]
+
+// should be linted
+fn func1(a: bool, b: bool) -> Option<i32> {
+    if a && b {
+        return Some(42);
+    }
+    if a { Some(-1) } else { Some(2) }
+}
+
+// should be linted
+fn func2(a: bool) -> Result<i32, ()> {
+    match a {
+        true => Ok(1),
+        false => Ok(2),
+    }
+}
+
+// should not be linted, it's public
+pub fn func3(a: bool) -> Option<i32> {
+    if a { Some(1) } else { Some(2) }
+}
+
+// should not be linted, returns `None`
+fn func4(a: bool) -> Option<i32> {
+    if a { Some(1) } else { None }
+}
+
+// should not be linted, `?` may return the error
+fn func5(a: Result<i32, ()>) -> Result<i32, ()> {
+    Ok(a? + 1)
+}
+
+// should not be linted, used as a value
+fn func6(a: i32) -> Option<i32> {
+    Some(a)
+}
+
+// should not be linted, only diverges
+fn func7() -> Option<i32> {
+    unimplemented!()
+}
+
+struct A;
+
+impl A {
+    // should be linted
+    fn assoc(&self) -> Option<u8> {
+        Some(1)
+    }
+}
+
+trait B {
+    fn b() -> Option<u8>;
+}
+
+impl B for A {
+    // trait impls can't change their signature
+    fn b() -> Option<u8> {
+        Some(1)
+    }
+}
+
+// should be linted, the suggestion keeps the type as written
+fn func8() -> Option<String> {
+    Some(String::new())
+}
+
+fn main() {
+    let _ = func1(true, true);
+    let _ = func1(false, true);
+    let _ = func2(true);
+    let _ = func4(true);
+    let _ = func5(Ok(1));
+    let _ = Some(1).and_then(func6);
+    let _ = func7();
+    let _ = A.assoc();
+    let _ = func8();
+}
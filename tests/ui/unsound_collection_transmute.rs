@@ -0,0 +1,18 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::mem::transmute;
+
+fn main() {
+    unsafe {
+        // wrong size
+        let _ = transmute::<_, Vec<u32>>(vec![0u8]);
+        // wrong layout
+        let _ = transmute::<_, Vec<[u8; 4]>>(vec![1234u32]);
+        // same size and alignment, still wrong
+        let _ = transmute::<_, Vec<i32>>(vec![1u32]);
+
+        let _ = transmute::<_, VecDeque<u32>>(VecDeque::<u8>::new());
+        let _ = transmute::<_, HashSet<u64>>(HashSet::<i64>::new());
+        let _ = transmute::<_, BTreeMap<u8, u32>>(BTreeMap::<u8, i32>::new());
+        let _ = transmute::<_, HashMap<u32, u8>>(HashMap::<u8, u8>::new());
+    }
+}
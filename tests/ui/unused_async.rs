@@ -0,0 +1,30 @@
+#![feature(async_await, await_macro, futures_api)]
+#![warn(unused_async)]
+#![allow(dead_code)]
+
+async fn foo() -> i32 {
+    4
+}
+
+async fn bar() -> i32 {
+    await!(foo())
+}
+
+async fn nested_await() -> i32 {
+    let _fut = async { await!(foo()) };
+    2
+}
+
+struct S;
+
+impl S {
+    async fn method() -> i32 {
+        1
+    }
+
+    async fn awaiting_method() -> i32 {
+        await!(Self::method())
+    }
+}
+
+fn main() {}
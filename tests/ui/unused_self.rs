@@ -0,0 +1,62 @@
+#![warn(unused_self)]
+#![allow(dead_code)]
+
+mod unused_self {
+    pub struct A;
+
+    impl A {
+        fn unused_self_move(self) {}
+        fn unused_self_ref(&self) {}
+        fn unused_self_mut_ref(&mut self) {}
+        fn unused_self_class_method(&self) {
+            Self::static_method();
+        }
+        fn static_method() {}
+    }
+}
+
+mod used_self {
+    struct A {
+        x: u8,
+    }
+
+    impl A {
+        fn used_self_move(self) -> u8 {
+            self.x
+        }
+        fn used_self_ref(&self) -> u8 {
+            self.x
+        }
+        fn used_self_mut_ref(&mut self) {
+            self.x += 1
+        }
+        fn used_self_in_closure(&self) -> u8 {
+            (|| self.x)()
+        }
+        fn used_self_in_macro(&self) {
+            println!("{}", self.x);
+        }
+    }
+}
+
+mod not_applicable {
+    use std::fmt;
+
+    struct A;
+
+    impl fmt::Debug for A {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "A")
+        }
+    }
+
+    trait B {
+        fn unused_self_in_trait_method(&self) {}
+    }
+
+    impl B for A {
+        fn unused_self_in_trait_method(&self) {}
+    }
+}
+
+fn main() {}
@@ -2,7 +2,7 @@
 
 
 #![warn(while_let_loop, empty_loop, while_let_on_iterator)]
-#![allow(dead_code, never_loop, unused, cyclomatic_complexity)]
+#![allow(dead_code, never_loop, unused, cognitive_complexity)]
 
 fn main() {
     let y = Some(true);
@@ -0,0 +1,52 @@
+#![warn(wildcard_imports)]
+#![allow(dead_code)]
+
+mod fn_mod {
+    pub fn foo() {}
+    pub fn bar() {}
+}
+
+mod struct_mod {
+    pub struct A;
+    pub struct B;
+
+    pub mod inner {
+        pub fn inner_foo() {}
+    }
+}
+
+mod enum_mod {
+    pub enum E {
+        V,
+    }
+}
+
+mod prelude {
+    pub fn prelude_fn() {}
+}
+
+use fn_mod::*;
+use struct_mod::*;
+use enum_mod::E::*;
+use prelude::*;
+
+mod reexport {
+    pub use super::fn_mod::*; // ok, re-export
+}
+
+fn main() {
+    foo();
+    let _ = A;
+    inner::inner_foo();
+    let _ = V;
+    prelude_fn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; // ok, test module
+
+    fn test() {
+        foo();
+    }
+}
@@ -0,0 +1,32 @@
+#![warn(zero_sized_map_values)]
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+struct Foo {
+    map: HashMap<u32, ()>,
+    counts: HashMap<u32, usize>,
+}
+
+fn takes(_: BTreeMap<String, ()>) {}
+
+fn returns() -> HashMap<u32, ()> {
+    HashMap::new()
+}
+
+fn nested(_: Vec<HashMap<u32, ()>>) {}
+
+trait Trait {
+    type Map;
+}
+
+impl Trait for Foo {
+    type Map = HashMap<u32, ()>;
+}
+
+fn main() {
+    let _annotated: HashMap<u32, ()> = HashMap::new();
+    let _inferred = HashMap::<u32, ()>::new();
+    let _set: HashSet<u32> = HashSet::new();
+    let _values: BTreeMap<u32, u32> = BTreeMap::new();
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+struct Empty;
+
+fn main() {
+    unsafe {
+        let unit = &() as *const ();
+        unit.offset(1);
+        unit.wrapping_add(1);
+        unit.sub(1);
+
+        let mut empty = Empty;
+        let ptr = &mut empty as *mut Empty;
+        ptr.add(1);
+        ptr.wrapping_offset(1);
+
+        // not linted
+        let x = &1u8 as *const u8;
+        x.offset(1);
+        x.wrapping_sub(1);
+    }
+}